@@ -43,6 +43,14 @@ typedef struct WuiArray {
  */
 #define LIVE_PHOTO 2
 
+/**
+ * Version of the C ABI described by this header.
+ *
+ * Bumped whenever a declaration changes incompatibly. Hosts compare it with
+ * `waterui_abi_version()` of the loaded library before calling anything else.
+ */
+#define WATERUI_ABI_VERSION 1
+
 /**
  * FFI representation of StretchAxis enum.
  *
@@ -91,6 +99,67 @@ typedef enum WuiEvent {
   WuiEvent_HoverExit,
 } WuiEvent;
 
+/**
+ * Phase of a pointer event delivered to an `on_pointer` handler.
+ */
+typedef enum WuiPointerPhase {
+  /**
+   * A pointer went down inside the view.
+   */
+  WuiPointerPhase_Down,
+  /**
+   * A pressed pointer moved.
+   */
+  WuiPointerPhase_Move,
+  /**
+   * A pressed pointer was released.
+   */
+  WuiPointerPhase_Up,
+  /**
+   * The system took over the pointer, e.g. for a scroll.
+   */
+  WuiPointerPhase_Cancel,
+  /**
+   * A hovering pointer moved without being pressed.
+   */
+  WuiPointerPhase_Hover,
+} WuiPointerPhase;
+
+/**
+ * The device that produced a pointer event.
+ */
+typedef enum WuiPointerKind {
+  WuiPointerKind_Touch,
+  WuiPointerKind_Mouse,
+  WuiPointerKind_Pen,
+} WuiPointerKind;
+
+/**
+ * Whether a key delivered to a GpuSurface was pressed or released.
+ */
+typedef enum WuiKeyPhase {
+  WuiKeyPhase_Down,
+  WuiKeyPhase_Up,
+} WuiKeyPhase;
+
+/**
+ * How a GpuSurface presents frames.
+ */
+typedef enum WuiPresentMode {
+  /**
+   * Wait for vertical blank; never tears. Supported everywhere.
+   */
+  WuiPresentMode_Fifo,
+  /**
+   * Replace the queued frame with the newest one; falls back to `Fifo`.
+   */
+  WuiPresentMode_Mailbox,
+  /**
+   * Present immediately, possibly tearing; falls back to `Fifo`.
+   */
+  WuiPresentMode_Immediate,
+} WuiPresentMode;
+
 /**
  * FFI-safe representation of a material blur style.
  *
@@ -146,12 +215,40 @@ typedef enum WuiFontWeight {
   WuiFontWeight_Black,
 } WuiFontWeight;
 
+/**
+ * Horizontal width of a font face.
+ */
+typedef enum WuiFontWidth {
+  WuiFontWidth_Condensed,
+  WuiFontWidth_Normal,
+  WuiFontWidth_Expanded,
+} WuiFontWidth;
+
+/**
+ * Where text is elided when it exceeds its line limit.
+ */
+typedef enum WuiTruncationMode {
+  WuiTruncationMode_Head,
+  WuiTruncationMode_Middle,
+  WuiTruncationMode_Tail,
+} WuiTruncationMode;
+
+/**
+ * Horizontal alignment of the lines of multiline text.
+ */
+typedef enum WuiTextAlignment {
+  WuiTextAlignment_Leading,
+  WuiTextAlignment_Center,
+  WuiTextAlignment_Trailing,
+} WuiTextAlignment;
+
 typedef enum WuiKeyboardType {
   WuiKeyboardType_Text,
   WuiKeyboardType_Email,
   WuiKeyboardType_URL,
   WuiKeyboardType_Number,
   WuiKeyboardType_PhoneNumber,
+  WuiKeyboardType_Search,
 } WuiKeyboardType;
 
 typedef enum WuiToggleStyle {
@@ -254,6 +351,205 @@ typedef enum WuiMediaFilterType {
   WuiMediaFilterType_All = 3,
 } WuiMediaFilterType;
 
+/**
+ * What a camera capture records.
+ */
+typedef enum WuiCaptureMode {
+  /**
+   * A still photo.
+   */
+  WuiCaptureMode_Photo = 0,
+  /**
+   * A video clip.
+   */
+  WuiCaptureMode_Video = 1,
+} WuiCaptureMode;
+
+/**
+ * A system permission that can be checked and requested through `Permissions`.
+ */
+typedef enum WuiPermission {
+  /**
+   * Access to the camera.
+   */
+  WuiPermission_Camera = 0,
+  /**
+   * Access to the microphone.
+   */
+  WuiPermission_Microphone = 1,
+  /**
+   * Read access to the user's photos and videos.
+   */
+  WuiPermission_PhotoLibrary = 2,
+  /**
+   * Access to the device location while the app is in use.
+   */
+  WuiPermission_Location = 3,
+  /**
+   * Posting user-visible notifications.
+   */
+  WuiPermission_Notifications = 4,
+} WuiPermission;
+
+/**
+ * Authorization status of a `WuiPermission`.
+ */
+typedef enum WuiPermissionStatus {
+  /**
+   * The user has not been asked yet, or may be asked again.
+   */
+  WuiPermissionStatus_NotDetermined = 0,
+  /**
+   * The user denied access and will not be prompted again.
+   */
+  WuiPermissionStatus_Denied = 1,
+  /**
+   * Access is granted.
+   */
+  WuiPermissionStatus_Granted = 2,
+} WuiPermissionStatus;
+
+/**
+ * Strength of an impact played through `Feedback::impact`.
+ */
+typedef enum WuiImpactStyle {
+  /**
+   * A light tap, such as a key press.
+   */
+  WuiImpactStyle_Light = 0,
+  /**
+   * A moderate tap, such as a toggle snapping into place.
+   */
+  WuiImpactStyle_Medium = 1,
+  /**
+   * A heavy tap, such as a long press being recognized.
+   */
+  WuiImpactStyle_Heavy = 2,
+} WuiImpactStyle;
+
+/**
+ * Phase of a system back gesture delivered to `BackNavigation`.
+ */
+typedef enum WuiBackPhase {
+  /**
+   * A predictive back gesture started.
+   */
+  WuiBackPhase_Started = 0,
+  /**
+   * A predictive back gesture moved; `progress` runs from 0 to 1.
+   */
+  WuiBackPhase_Progressed = 1,
+  /**
+   * The gesture was cancelled and the current screen stays.
+   */
+  WuiBackPhase_Cancelled = 2,
+  /**
+   * Back was committed, by completing the gesture or pressing the back button.
+   */
+  WuiBackPhase_Invoked = 3,
+} WuiBackPhase;
+
+/**
+ * Severity of a message passed to the log sink.
+ */
+typedef enum WuiLogLevel {
+  WuiLogLevel_Error = 0,
+  WuiLogLevel_Warn = 1,
+  WuiLogLevel_Info = 2,
+  WuiLogLevel_Debug = 3,
+  WuiLogLevel_Trace = 4,
+} WuiLogLevel;
+
+/**
+ * Separator visibility between list rows.
+ */
+typedef enum WuiListSeparator {
+  /**
+   * Use the platform default.
+   */
+  WuiListSeparator_Automatic,
+  /**
+   * Always draw separators between rows.
+   */
+  WuiListSeparator_Visible,
+  /**
+   * Never draw separators.
+   */
+  WuiListSeparator_Hidden,
+} WuiListSeparator;
+
+/**
+ * Semantic role of a swipe action.
+ */
+typedef enum WuiSwipeActionRole {
+  WuiSwipeActionRole_Normal,
+  WuiSwipeActionRole_Destructive,
+} WuiSwipeActionRole;
+
+/**
+ * The kind of entry in a menu.
+ */
+typedef enum WuiMenuItemKind {
+  /**
+   * A plain item that runs its action.
+   */
+  WuiMenuItemKind_Action,
+  /**
+   * A checkable item; its action toggles the bound state.
+   */
+  WuiMenuItemKind_Toggle,
+  /**
+   * An item that opens the nested `submenu` items.
+   */
+  WuiMenuItemKind_Submenu,
+  /**
+   * A separator between groups of items. Label and action are unused.
+   */
+  WuiMenuItemKind_Divider,
+} WuiMenuItemKind;
+
+/**
+ * Two-dimensional alignment used to position layered views.
+ */
+typedef enum WuiAlignment {
+  WuiAlignment_TopLeading,
+  WuiAlignment_Top,
+  WuiAlignment_TopTrailing,
+  WuiAlignment_Leading,
+  WuiAlignment_Center,
+  WuiAlignment_Trailing,
+  WuiAlignment_BottomLeading,
+  WuiAlignment_Bottom,
+  WuiAlignment_BottomTrailing,
+} WuiAlignment;
+
+/**
+ * Sizing rule for a lazy grid column (or row, for horizontal grids).
+ */
+typedef enum WuiGridItemSize {
+  /**
+   * A single track of exactly `minimum` points.
+   */
+  WuiGridItemSize_Fixed,
+  /**
+   * A single track sharing the remaining space, clamped to `minimum..maximum`.
+   */
+  WuiGridItemSize_Flexible,
+  /**
+   * As many tracks as fit, each at least `minimum` and at most `maximum` points.
+   */
+  WuiGridItemSize_Adaptive,
+} WuiGridItemSize;
+
+/**
+ * Alignment of a cell within its grid track.
+ */
+typedef enum WuiGridItemAlignment {
+  WuiGridItemAlignment_Leading,
+  WuiGridItemAlignment_Center,
+  WuiGridItemAlignment_Trailing,
+} WuiGridItemAlignment;
+
 typedef enum WuiProgressStyle {
   WuiProgressStyle_Linear,
   WuiProgressStyle_Circular,
@@ -437,6 +733,43 @@ typedef enum WuiColorScheme {
   WuiColorScheme_Dark = 1,
 } WuiColorScheme;
 
+/**
+ * Size class enum for FFI.
+ *
+ * Maps directly to `waterui::layout::SizeClass`.
+ */
+typedef enum WuiSizeClass {
+  /**
+   * Constrained space, such as a phone in portrait.
+   */
+  WuiSizeClass_Compact = 0,
+  /**
+   * Expansive space, such as a tablet or desktop window.
+   */
+  WuiSizeClass_Regular = 1,
+} WuiSizeClass;
+
+/**
+ * App lifecycle phase enum for FFI.
+ *
+ * Maps directly to `waterui::AppPhase`.
+ */
+typedef enum WuiAppPhase {
+  /**
+   * The app is in the foreground and receiving input.
+   */
+  WuiAppPhase_Active = 0,
+  /**
+   * The app is visible but not receiving input, such as behind a system dialog
+   * or in multi-window mode while another window has focus.
+   */
+  WuiAppPhase_Inactive = 1,
+  /**
+   * The app is not visible and may be terminated without further notice.
+   */
+  WuiAppPhase_Background = 2,
+} WuiAppPhase;
+
 /**
  * Color slot enum for FFI.
  *
@@ -918,6 +1251,11 @@ typedef struct WuiEnv WuiEnv;
 
 typedef struct WuiFont WuiFont;
 
+/**
+ * Geometry signals shared between a `GeometryReader` and its content.
+ */
+typedef struct WuiGeometryProxy WuiGeometryProxy;
+
 /**
  * Opaque state held by the native backend after initialization.
  *
@@ -943,6 +1281,32 @@ typedef struct WuiMoveAction WuiMoveAction;
  */
 typedef struct WuiOnEventHandler WuiOnEventHandler;
 
+/**
+ * Wrapper for OnPaste to avoid orphan rule issues.
+ */
+typedef struct WuiOnPasteHandler WuiOnPasteHandler;
+
+/**
+ * Wrapper for OnPointer to avoid orphan rule issues.
+ */
+typedef struct WuiOnPointerHandler WuiOnPointerHandler;
+
+/**
+ * Wrapper for OnSizeChange to avoid orphan rule issues.
+ */
+typedef struct WuiOnSizeChangeHandler WuiOnSizeChangeHandler;
+
+/**
+ * Wrapper for Refreshable to avoid orphan rule issues.
+ */
+typedef struct WuiRefreshHandler WuiRefreshHandler;
+
+/**
+ * Connects a `ScrollView` to its `ScrollPosition` binding, `ScrollViewReader`
+ * proxies and `on_scroll` callbacks.
+ */
+typedef struct WuiScrollProxy WuiScrollProxy;
+
 typedef struct WuiSharedAction WuiSharedAction;
 
 typedef struct WuiTabContent WuiTabContent;
@@ -1215,6 +1579,103 @@ typedef struct WuiMetadata_WuiOnEvent {
  */
 typedef struct WuiMetadata_WuiOnEvent WuiMetadataOnEvent;
 
+/**
+ * FFI-safe representation of a paste handler.
+ */
+typedef struct WuiOnPaste {
+  /**
+   * Opaque pointer to the OnPaste (owns the handler).
+   */
+  struct WuiOnPasteHandler *handler;
+} WuiOnPaste;
+
+typedef struct WuiMetadata_WuiOnPaste {
+  struct WuiAnyView *content;
+  struct WuiOnPaste value;
+} WuiMetadata_WuiOnPaste;
+
+/**
+ * Type alias for Metadata<OnPaste> FFI struct
+ */
+typedef struct WuiMetadata_WuiOnPaste WuiMetadataOnPaste;
+
+/**
+ * FFI-safe representation of a pointer handler.
+ */
+typedef struct WuiOnPointer {
+  /**
+   * Opaque pointer to the OnPointer (owns the handler).
+   */
+  struct WuiOnPointerHandler *handler;
+} WuiOnPointer;
+
+typedef struct WuiMetadata_WuiOnPointer {
+  struct WuiAnyView *content;
+  struct WuiOnPointer value;
+} WuiMetadata_WuiOnPointer;
+
+/**
+ * Type alias for Metadata<OnPointer> FFI struct
+ */
+typedef struct WuiMetadata_WuiOnPointer WuiMetadataOnPointer;
+
+/**
+ * FFI-safe representation of a size-change handler.
+ */
+typedef struct WuiOnSizeChange {
+  /**
+   * Opaque pointer to the OnSizeChange (owns the bound size).
+   */
+  struct WuiOnSizeChangeHandler *handler;
+} WuiOnSizeChange;
+
+typedef struct WuiMetadata_WuiOnSizeChange {
+  struct WuiAnyView *content;
+  struct WuiOnSizeChange value;
+} WuiMetadata_WuiOnSizeChange;
+
+/**
+ * Type alias for Metadata<OnSizeChange> FFI struct
+ */
+typedef struct WuiMetadata_WuiOnSizeChange WuiMetadataOnSizeChange;
+
+/**
+ * FFI-safe representation of a pull-to-refresh handler.
+ */
+typedef struct WuiRefreshable {
+  /**
+   * Opaque pointer to the Refreshable (owns the async refresh closure).
+   */
+  struct WuiRefreshHandler *handler;
+} WuiRefreshable;
+
+typedef struct WuiMetadata_WuiRefreshable {
+  struct WuiAnyView *content;
+  struct WuiRefreshable value;
+} WuiMetadata_WuiRefreshable;
+
+/**
+ * Type alias for Metadata<Refreshable> FFI struct
+ */
+typedef struct WuiMetadata_WuiRefreshable WuiMetadataRefreshable;
+
+/**
+ * A callback signalling that a refresh has finished.
+ *
+ * Rust calls this exactly once, on the main thread, after the refresh future
+ * completes. The native backend hides its refresh indicator in response.
+ */
+typedef struct WuiRefreshCompletion {
+  /**
+   * Opaque pointer to the native completion state.
+   */
+  void *data;
+  /**
+   * Function to call when the refresh finishes. This consumes the callback.
+   */
+  void (*call)(void*);
+} WuiRefreshCompletion;
+
 typedef struct Computed_CursorStyle WuiComputed_CursorStyle;
 
 /**
@@ -1269,6 +1730,43 @@ typedef struct WuiMetadata_WuiShadow {
  */
 typedef struct WuiMetadata_WuiShadow WuiMetadataShadow;
 
+/**
+ * FFI-safe representation of a view layered above or below its content.
+ *
+ * The layer is proposed the content's size and positioned by `alignment`;
+ * it never affects the size of the content.
+ */
+typedef struct WuiLayer {
+  /**
+   * The layered view.
+   */
+  struct WuiAnyView *view;
+  /**
+   * Alignment of the layer relative to the content.
+   */
+  enum WuiAlignment alignment;
+} WuiLayer;
+
+typedef struct WuiMetadata_WuiOverlay {
+  struct WuiAnyView *content;
+  struct WuiLayer value;
+} WuiMetadata_WuiOverlay;
+
+/**
+ * Type alias for Metadata<Overlay> FFI struct
+ */
+typedef struct WuiMetadata_WuiOverlay WuiMetadataOverlay;
+
+typedef struct WuiMetadata_WuiBackgroundView {
+  struct WuiAnyView *content;
+  struct WuiLayer value;
+} WuiMetadata_WuiBackgroundView;
+
+/**
+ * Type alias for Metadata<BackgroundView> FFI struct
+ */
+typedef struct WuiMetadata_WuiBackgroundView WuiMetadataBackgroundView;
+
 /**
  * FFI-safe representation of edge set for safe area.
  */
@@ -1588,6 +2086,16 @@ typedef struct WuiMetadata_WuiFocused {
  */
 typedef struct WuiMetadata_WuiFocused WuiMetadataFocused;
 
+/**
+ * Safe-area insets reported by the backend, in points.
+ */
+typedef struct WuiSafeAreaInsets {
+  float top;
+  float bottom;
+  float leading;
+  float trailing;
+} WuiSafeAreaInsets;
+
 /**
  * FFI-safe representation of IgnoreSafeArea.
  */
@@ -1776,6 +2284,30 @@ typedef struct Computed_StyledStr WuiComputed_StyledStr;
 
 typedef struct WuiText {
   WuiComputed_StyledStr *content;
+  /**
+   * Maximum number of lines, or 0 for no limit.
+   */
+  uint32_t line_limit;
+  /**
+   * Where text is elided when it exceeds `line_limit`.
+   */
+  enum WuiTruncationMode truncation_mode;
+  /**
+   * Alignment of lines relative to each other.
+   */
+  enum WuiTextAlignment multiline_alignment;
+  /**
+   * Extra space between lines, in points.
+   */
+  float line_spacing;
+  /**
+   * Extra space between characters, in points.
+   */
+  float kerning;
+  /**
+   * Whether digits use uniform (tabular) widths.
+   */
+  bool monospaced_digits;
 } WuiText;
 
 /**
@@ -1790,6 +2322,19 @@ typedef struct WuiMenuItem {
    * The action handler pointer (SharedHandler wrapped for FFI).
    */
   struct WuiSharedAction *action;
+  /**
+   * What kind of entry this is.
+   */
+  enum WuiMenuItemKind kind;
+  /**
+   * Current state of a Toggle item.
+   */
+  bool checked;
+  /**
+   * Nested items of a Submenu item (null for other kinds).
+   * Borrowed from the item; read with `waterui_read_computed_menu_items`.
+   */
+  WuiComputed_MenuItems *submenu;
 } WuiMenuItem;
 
 typedef struct WuiArraySlice_WuiMenuItem {
@@ -2160,7 +2705,19 @@ typedef struct WuiArray_WuiRect {
 typedef struct WuiScrollView {
   enum WuiAxis axis;
   struct WuiAnyView *content;
-} WuiScrollView;
+  /**
+   * Programmatic control and scroll reporting; null when unused.
+   */
+  struct WuiScrollProxy *proxy;
+  /**
+   * Whether scrolling settles on multiples of the viewport size.
+   */
+  bool paging;
+  /**
+   * Whether a drag on a two-axis scroll view is locked to its dominant axis.
+   */
+  bool lock_axis;
+} WuiScrollView;
 
 typedef struct WuiButton {
   struct WuiAnyView *label;
@@ -2175,11 +2732,20 @@ typedef struct WuiTextStyle {
   bool strikethrough;
   struct WuiColor *foreground;
   struct WuiColor *background;
+  /**
+   * Action fired when the range is tapped, or null.
+   */
+  struct WuiSharedAction *tap;
 } WuiTextStyle;
 
 typedef struct WuiStyledChunk {
   struct WuiStr text;
   struct WuiTextStyle style;
+  /**
+   * Inline view rendered in place of the text, or null for plain text runs.
+   * When set, `text` holds a single U+FFFC placeholder character.
+   */
+  struct WuiAnyView *attachment;
 } WuiStyledChunk;
 
 typedef struct WuiArraySlice_WuiStyledChunk {
@@ -2228,8 +2794,38 @@ typedef struct WuiResolvedFont {
    * Font family name (empty string means system default).
    */
   struct WuiStr family;
+  /**
+   * Font width.
+   */
+  enum WuiFontWidth width;
+  /**
+   * Whether the italic face is requested.
+   */
+  bool italic;
 } WuiResolvedFont;
 
+/**
+ * Describes a font face registered in the `FontRegistry`.
+ */
+typedef struct WuiFontDescriptor {
+  /**
+   * Family name the face is registered under.
+   */
+  struct WuiStr family;
+  /**
+   * Font weight.
+   */
+  enum WuiFontWeight weight;
+  /**
+   * Font width.
+   */
+  enum WuiFontWidth width;
+  /**
+   * Whether the face is italic.
+   */
+  bool italic;
+} WuiFontDescriptor;
+
 typedef struct Computed_ResolvedFont WuiComputed_ResolvedFont;
 
 typedef struct Binding_Str WuiBinding_Str;
@@ -2239,6 +2835,10 @@ typedef struct WuiTextField {
   WuiBinding_Str *value;
   struct WuiText prompt;
   enum WuiKeyboardType keyboard;
+  /**
+   * Action run when the user submits from the keyboard (null if none).
+   */
+  struct WuiAction *on_submit;
 } WuiTextField;
 
 typedef struct WuiToggle {
@@ -2486,6 +3086,26 @@ typedef struct WuiFn_WuiPhotoEvent {
 typedef struct WuiPhoto {
   struct WuiStr source;
   struct WuiFn_WuiPhotoEvent on_event;
+  /**
+   * Whether the image scales to its frame instead of keeping its natural size.
+   */
+  bool resizable;
+  /**
+   * How a resizable image fills its frame.
+   */
+  enum WuiAspectRatio content_mode;
+  /**
+   * Whether drawing is clipped to the frame.
+   */
+  bool clipped;
+  /**
+   * Corner radius in points (0 = square corners). Rounded images are always clipped.
+   */
+  float corner_radius;
+  /**
+   * Tint applied to the image as a template, or null to keep its colors.
+   */
+  struct WuiColor *tint;
 } WuiPhoto;
 
 typedef struct Computed_Str WuiComputed_Str;
@@ -2539,6 +3159,8 @@ typedef struct WuiVideo {
   struct WuiFn_WuiVideoEvent on_event;
 } WuiVideo;
 
+typedef struct Computed_f64 WuiComputed_f64;
+
 /**
  * FFI representation of the VideoPlayer component (with native controls).
  */
@@ -2560,6 +3182,28 @@ typedef struct WuiVideoPlayer {
    * Whether to show native playback controls.
    */
   bool show_controls;
+  /**
+   * Whether the video is playing (nullable, plays automatically when null).
+   * The backend writes it back when playback starts or stops on its own.
+   */
+  WuiBinding_bool *playing;
+  /**
+   * The playback position in seconds (nullable).
+   * Updated by the backend while playing; writing it seeks.
+   */
+  WuiBinding_f64 *current_time;
+  /**
+   * The duration of the current video in seconds (nullable), written by the backend once known.
+   */
+  WuiBinding_f64 *duration;
+  /**
+   * The playback rate, where 1.0 is normal speed (nullable).
+   */
+  WuiComputed_f64 *rate;
+  /**
+   * Whether the video should loop when it ends.
+   */
+  bool loops;
   /**
    * The event handler for the video player.
    */
@@ -2601,7 +3245,8 @@ typedef struct MediaPickerPresentCallback {
    */
   void *data;
   /**
-   * Function to call with the selected media. This consumes the callback.
+   * Function to call with the selected media, or 0 if the user cancelled or
+   * nothing could be presented. This consumes the callback.
    */
   void (*call)(void*, SelectedId);
 } MediaPickerPresentCallback;
@@ -2611,6 +3256,249 @@ typedef struct MediaPickerPresentCallback {
  */
 typedef void (*MediaPickerPresentFn)(enum WuiMediaFilterType, struct MediaPickerPresentCallback);
 
+/**
+ * Type alias for the native camera capture function.
+ *
+ * The captured media is reported through the same callback as picked media,
+ * so it loads into `Media` like any other selection.
+ */
+typedef void (*CameraCaptureFn)(enum WuiCaptureMode, struct MediaPickerPresentCallback);
+
+/**
+ * A callback for receiving the outcome of a permission request.
+ *
+ * This is a C-compatible closure that native code calls once the user has responded.
+ */
+typedef struct PermissionRequestCallback {
+  /**
+   * Opaque pointer to the callback data.
+   */
+  void *data;
+  /**
+   * Function to call with the resulting status. This consumes the callback.
+   */
+  void (*call)(void*, enum WuiPermissionStatus);
+} PermissionRequestCallback;
+
+/**
+ * A unit of work posted to the main thread.
+ *
+ * Native code must call `call` exactly once, on the main thread.
+ */
+typedef struct WuiMainTask {
+  /**
+   * Opaque pointer to the task state.
+   */
+  void *data;
+  /**
+   * Runs the task and releases `data`. This consumes the task.
+   */
+  void (*call)(void*);
+} WuiMainTask;
+
+/**
+ * Type alias for the native function that schedules a task on the main thread.
+ * It may be called from any thread.
+ */
+typedef void (*MainExecutorPostFn)(struct WuiMainTask);
+
+/**
+ * Type alias for the native check whether the caller runs on the main thread.
+ */
+typedef bool (*MainExecutorIsMainFn)(void);
+
+/**
+ * Type alias for the native permission status query.
+ */
+typedef enum WuiPermissionStatus (*PermissionStatusFn)(enum WuiPermission);
+
+/**
+ * Type alias for the native permission prompt.
+ */
+typedef void (*PermissionRequestFn)(enum WuiPermission, struct PermissionRequestCallback);
+
+/**
+ * A callback for receiving the clipboard text read by the native backend.
+ *
+ * This is a C-compatible closure that native code calls once the clipboard has been read.
+ */
+typedef struct ClipboardReadCallback {
+  /**
+   * Opaque pointer to the callback data.
+   */
+  void *data;
+  /**
+   * Function to call with the clipboard text, or null if the clipboard holds no text.
+   * This consumes the callback.
+   */
+  void (*call)(void*, const char*);
+} ClipboardReadCallback;
+
+/**
+ * Type alias for the native clipboard text writer.
+ */
+typedef void (*ClipboardWriteTextFn)(const char*);
+
+/**
+ * Type alias for the native clipboard text reader.
+ */
+typedef void (*ClipboardReadTextFn)(struct ClipboardReadCallback);
+
+/**
+ * Type alias for the native clipboard image writer. The image is passed as encoded PNG bytes.
+ */
+typedef void (*ClipboardWriteImageFn)(const uint8_t*, uintptr_t);
+
+/**
+ * Type alias for the native bundled-asset reader.
+ *
+ * Reads the asset at the given path, relative to the asset root, into the
+ * output array and returns `true`, or returns `false` if there is no such asset.
+ */
+typedef bool (*AssetReadFn)(const char*, struct WuiArray_u8*);
+
+/**
+ * Type alias for the native settings reader.
+ *
+ * Reads the value stored under the given key into the output array and
+ * returns `true`, or returns `false` if the key has no value.
+ */
+typedef bool (*SettingsReadFn)(const char*, struct WuiArray_u8*);
+
+/**
+ * Type alias for the native settings writer. Values are opaque bytes
+ * serialized by the `Settings` plugin.
+ */
+typedef void (*SettingsWriteFn)(const char*, const uint8_t*, uintptr_t);
+
+/**
+ * Type alias for the native settings remover.
+ */
+typedef void (*SettingsRemoveFn)(const char*);
+
+/**
+ * Type alias for the native impact haptic function.
+ */
+typedef void (*FeedbackImpactFn)(enum WuiImpactStyle);
+
+/**
+ * Type alias for the native selection-changed haptic function.
+ */
+typedef void (*FeedbackSelectionFn)(void);
+
+/**
+ * Type alias for the native sound-effect function. The argument is the name
+ * of a bundled asset.
+ */
+typedef void (*FeedbackPlaySoundFn)(const char*);
+
+/**
+ * Type alias for the native handler notified when an FFI call panics.
+ *
+ * The arguments are the name of the panicking function and the panic message,
+ * both null-terminated UTF-8 strings valid only for the duration of the call.
+ */
+typedef void (*WuiPanicHandlerFn)(const char*, const char*);
+
+/**
+ * Type alias for the native handler asked to swap in a rebuilt library.
+ * The argument is the null-terminated path of the rebuilt library.
+ */
+typedef void (*WuiReloadLibraryFn)(const char*);
+
+/**
+ * Type alias for the native sink that receives log messages.
+ *
+ * The arguments are the level, the target (the module that logged, followed
+ * by the `debug_label` of the enclosing view region when there is one) and the
+ * message with the fields of its enclosing spans. Both strings are
+ * null-terminated UTF-8 and valid only for the duration of the call.
+ */
+typedef void (*WuiLogSinkFn)(enum WuiLogLevel, const char*, const char*);
+
+/**
+ * A button shown on a local notification.
+ */
+typedef struct WuiNotificationAction {
+  /**
+   * Identifier reported back when the button is tapped.
+   */
+  struct WuiStr id;
+  /**
+   * The button title.
+   */
+  struct WuiStr title;
+} WuiNotificationAction;
+
+typedef struct WuiArraySlice_WuiNotificationAction {
+  struct WuiNotificationAction *head;
+  uintptr_t len;
+} WuiArraySlice_WuiNotificationAction;
+
+typedef struct WuiArrayVTable_WuiNotificationAction {
+  void (*drop)(void*);
+  struct WuiArraySlice_WuiNotificationAction (*slice)(const void*);
+} WuiArrayVTable_WuiNotificationAction;
+
+/**
+ * A generic array structure for FFI, representing a contiguous sequence of elements.
+ * `WuiArray` can represent multiple types of arrays, for instance, a `&[T]` (in this case, the lifetime of WuiArray is bound to the caller's scope),
+ * or a value type having a static lifetime like `Vec<T>`, `Box<[T]>`, `Bytes`, or even a foreign allocated array.
+ * For a value type, `WuiArray` contains a destructor function pointer to free the array buffer, whatever it is allocated by Rust side or foreign side.
+ * We assume `T` does not contain any non-trivial drop logic, and `WuiArray` will not call `drop` on each element when it is dropped.
+ */
+typedef struct WuiArray_WuiNotificationAction {
+  NonNull data;
+  struct WuiArrayVTable_WuiNotificationAction vtable;
+} WuiArray_WuiNotificationAction;
+
+/**
+ * A local notification scheduled through `Notifications::schedule`.
+ */
+typedef struct WuiNotificationRequest {
+  /**
+   * Identifier of the notification. Scheduling a notification with the same
+   * identifier replaces the previous one.
+   */
+  struct WuiStr id;
+  /**
+   * The notification title.
+   */
+  struct WuiStr title;
+  /**
+   * The notification body.
+   */
+  struct WuiStr body;
+  /**
+   * When to deliver the notification, in milliseconds since the Unix epoch.
+   * A time in the past delivers it immediately.
+   */
+  int64_t trigger_time_ms;
+  /**
+   * Buttons shown on the notification.
+   */
+  struct WuiArray_WuiNotificationAction actions;
+} WuiNotificationRequest;
+
+/**
+ * Type alias for the native notification scheduler.
+ * The request is owned by the callee.
+ */
+typedef void (*NotificationScheduleFn)(struct WuiNotificationRequest);
+
+/**
+ * Type alias for the native function that cancels a scheduled or delivered
+ * notification by identifier. The identifier is owned by the callee.
+ */
+typedef void (*NotificationCancelFn)(struct WuiStr);
+
+/**
+ * Type alias for the native function told whether the app wants to handle
+ * back itself. While disabled, back falls through to the system and leaves
+ * the activity. It may be called from any thread.
+ */
+typedef void (*BackNavigationSetEnabledFn)(bool);
+
 /**
  * FFI representation of the result from loading media.
  *
@@ -2662,55 +3550,104 @@ typedef struct MediaLoadCallback {
 typedef void (*MediaLoadFn)(uint32_t, struct MediaLoadCallback);
 
 /**
- * FFI representation of a list item.
+ * FFI representation of a `GridItem` track definition.
  */
-typedef struct WuiListItem {
+typedef struct WuiGridItem {
   /**
-   * The content view for this item.
+   * How the track is sized.
    */
-  struct WuiAnyView *content;
+  enum WuiGridItemSize size;
   /**
-   * Read-only signal indicating whether this item can be deleted.
+   * Minimum track size in points (the exact size for `Fixed`).
    */
-  WuiComputed_bool *deletable;
-} WuiListItem;
+  float minimum;
+  /**
+   * Maximum track size in points (infinity if unbounded).
+   */
+  float maximum;
+  /**
+   * Spacing after this track, in points.
+   */
+  float spacing;
+  /**
+   * Alignment of cells within the track.
+   */
+  enum WuiGridItemAlignment alignment;
+} WuiGridItem;
+
+typedef struct WuiArraySlice_WuiGridItem {
+  struct WuiGridItem *head;
+  uintptr_t len;
+} WuiArraySlice_WuiGridItem;
+
+typedef struct WuiArrayVTable_WuiGridItem {
+  void (*drop)(void*);
+  struct WuiArraySlice_WuiGridItem (*slice)(const void*);
+} WuiArrayVTable_WuiGridItem;
 
 /**
- * FFI representation of a list.
+ * A generic array structure for FFI, representing a contiguous sequence of elements.
+ * `WuiArray` can represent multiple types of arrays, for instance, a `&[T]` (in this case, the lifetime of WuiArray is bound to the caller's scope),
+ * or a value type having a static lifetime like `Vec<T>`, `Box<[T]>`, `Bytes`, or even a foreign allocated array.
+ * For a value type, `WuiArray` contains a destructor function pointer to free the array buffer, whatever it is allocated by Rust side or foreign side.
+ * We assume `T` does not contain any non-trivial drop logic, and `WuiArray` will not call `drop` on each element when it is dropped.
  */
-typedef struct WuiList {
+typedef struct WuiArray_WuiGridItem {
+  NonNull data;
+  struct WuiArrayVTable_WuiGridItem vtable;
+} WuiArray_WuiGridItem;
+
+/**
+ * FFI representation of a lazy grid (`LazyVGrid` / `LazyHGrid`).
+ *
+ * Cells are materialized from `contents` on demand as they scroll into view.
+ */
+typedef struct WuiLazyGrid {
   /**
-   * The list contents (array of list items).
+   * The scrolling axis. `Vertical` grids define columns, `Horizontal` grids define rows.
    */
-  struct WuiAnyViews *contents;
+  enum WuiAxis axis;
   /**
-   * Read-only signal for edit mode state.
+   * Track definitions across the cross axis.
    */
-  WuiComputed_bool *editing;
+  struct WuiArray_WuiGridItem items;
   /**
-   * Optional delete callback (null if not deletable).
+   * Spacing between cells along the scrolling axis, in points.
    */
-  struct WuiIndexAction *on_delete;
+  float spacing;
   /**
-   * Optional move callback (null if not reorderable).
+   * The grid contents. Views are created lazily by index.
    */
-  struct WuiMoveAction *on_move;
-} WuiList;
+  struct WuiAnyViews *contents;
+} WuiLazyGrid;
 
-typedef struct WuiTableColumn {
+/**
+ * FFI-safe representation of a list row swipe action.
+ */
+typedef struct WuiSwipeAction {
+  /**
+   * The label for the action button.
+   */
   struct WuiText label;
-  struct WuiAnyViews *rows;
-} WuiTableColumn;
+  /**
+   * The semantic role, used to pick the default tint.
+   */
+  enum WuiSwipeActionRole role;
+  /**
+   * The action handler pointer (SharedHandler wrapped for FFI).
+   */
+  struct WuiSharedAction *action;
+} WuiSwipeAction;
 
-typedef struct WuiArraySlice_WuiTableColumn {
-  struct WuiTableColumn *head;
+typedef struct WuiArraySlice_WuiSwipeAction {
+  struct WuiSwipeAction *head;
   uintptr_t len;
-} WuiArraySlice_WuiTableColumn;
+} WuiArraySlice_WuiSwipeAction;
 
-typedef struct WuiArrayVTable_WuiTableColumn {
+typedef struct WuiArrayVTable_WuiSwipeAction {
   void (*drop)(void*);
-  struct WuiArraySlice_WuiTableColumn (*slice)(const void*);
-} WuiArrayVTable_WuiTableColumn;
+  struct WuiArraySlice_WuiSwipeAction (*slice)(const void*);
+} WuiArrayVTable_WuiSwipeAction;
 
 /**
  * A generic array structure for FFI, representing a contiguous sequence of elements.
@@ -2719,24 +3656,183 @@ typedef struct WuiArrayVTable_WuiTableColumn {
  * For a value type, `WuiArray` contains a destructor function pointer to free the array buffer, whatever it is allocated by Rust side or foreign side.
  * We assume `T` does not contain any non-trivial drop logic, and `WuiArray` will not call `drop` on each element when it is dropped.
  */
-typedef struct WuiArray_WuiTableColumn {
+typedef struct WuiArray_WuiSwipeAction {
   NonNull data;
-  struct WuiArrayVTable_WuiTableColumn vtable;
-} WuiArray_WuiTableColumn;
+  struct WuiArrayVTable_WuiSwipeAction vtable;
+} WuiArray_WuiSwipeAction;
 
-typedef struct Computed_Vec_TableColumn WuiComputed_Vec_TableColumn;
-
-typedef struct WuiTable {
-  WuiComputed_Vec_TableColumn *columns;
-} WuiTable;
+/**
+ * FFI representation of a list item.
+ */
+typedef struct WuiListItem {
+  /**
+   * The content view for this item.
+   */
+  struct WuiAnyView *content;
+  /**
+   * Read-only signal indicating whether this item can be deleted.
+   */
+  WuiComputed_bool *deletable;
+  /**
+   * Actions revealed by swiping from the leading edge.
+   */
+  struct WuiArray_WuiSwipeAction leading_actions;
+  /**
+   * Actions revealed by swiping from the trailing edge.
+   */
+  struct WuiArray_WuiSwipeAction trailing_actions;
+} WuiListItem;
 
-typedef struct Computed_f64 WuiComputed_f64;
+/**
+ * FFI representation of a list.
+ */
+typedef struct WuiList {
+  /**
+   * The list contents (array of list items).
+   */
+  struct WuiAnyViews *contents;
+  /**
+   * Read-only signal for edit mode state.
+   */
+  WuiComputed_bool *editing;
+  /**
+   * Optional delete callback for rows placed directly in the list, called with
+   * the row's index in `contents` (null if not deletable).
+   */
+  struct WuiIndexAction *on_delete;
+  /**
+   * Optional move callback for rows placed directly in the list, called with
+   * indices in `contents` (null if not reorderable).
+   */
+  struct WuiMoveAction *on_move;
+  /**
+   * Separator visibility between rows.
+   */
+  enum WuiListSeparator separator;
+  /**
+   * Optional selection binding holding the selected row id (null if not selectable).
+   */
+  WuiBinding_Id *selection;
+} WuiList;
+
+/**
+ * FFI representation of a list section.
+ */
+typedef struct WuiListSection {
+  /**
+   * The section header (null if none). Headers stay pinned while the section scrolls.
+   */
+  struct WuiAnyView *header;
+  /**
+   * The section footer (null if none).
+   */
+  struct WuiAnyView *footer;
+  /**
+   * The section contents (array of list items).
+   */
+  struct WuiAnyViews *contents;
+  /**
+   * Optional delete callback for the section's rows, called with the row's
+   * index in `contents` (null if not deletable).
+   */
+  struct WuiIndexAction *on_delete;
+  /**
+   * Optional move callback for the section's rows, called with indices in
+   * `contents` (null if not reorderable). Rows never move between sections.
+   */
+  struct WuiMoveAction *on_move;
+} WuiListSection;
+
+/**
+ * FFI representation of a lazy stack (`LazyVStack` / `LazyHStack`).
+ *
+ * Unlike regular stacks, items are materialized from `contents` on demand
+ * as they scroll into view.
+ */
+typedef struct WuiLazyStack {
+  /**
+   * The stacking axis (`Horizontal` or `Vertical`).
+   */
+  enum WuiAxis axis;
+  /**
+   * Spacing between items, in points.
+   */
+  float spacing;
+  /**
+   * Estimated main-axis size of a single item, in points (0 if unknown).
+   * Backends use this to size their recycling pools before items are measured.
+   */
+  float estimated_item_size;
+  /**
+   * The stack contents. Views are created lazily by index.
+   */
+  struct WuiAnyViews *contents;
+} WuiLazyStack;
+
+/**
+ * FFI representation of a `GeometryReader`.
+ *
+ * The reader fills the space proposed to it and places `content` at its
+ * top-leading corner. Backends report the resolved frame through `proxy`
+ * after every layout pass.
+ */
+typedef struct WuiGeometryReader {
+  /**
+   * Geometry signals read by the content.
+   */
+  struct WuiGeometryProxy *proxy;
+  /**
+   * The content built from the proxy.
+   */
+  struct WuiAnyView *content;
+} WuiGeometryReader;
+
+typedef struct WuiTableColumn {
+  struct WuiText label;
+  struct WuiAnyViews *rows;
+} WuiTableColumn;
+
+typedef struct WuiArraySlice_WuiTableColumn {
+  struct WuiTableColumn *head;
+  uintptr_t len;
+} WuiArraySlice_WuiTableColumn;
+
+typedef struct WuiArrayVTable_WuiTableColumn {
+  void (*drop)(void*);
+  struct WuiArraySlice_WuiTableColumn (*slice)(const void*);
+} WuiArrayVTable_WuiTableColumn;
+
+/**
+ * A generic array structure for FFI, representing a contiguous sequence of elements.
+ * `WuiArray` can represent multiple types of arrays, for instance, a `&[T]` (in this case, the lifetime of WuiArray is bound to the caller's scope),
+ * or a value type having a static lifetime like `Vec<T>`, `Box<[T]>`, `Bytes`, or even a foreign allocated array.
+ * For a value type, `WuiArray` contains a destructor function pointer to free the array buffer, whatever it is allocated by Rust side or foreign side.
+ * We assume `T` does not contain any non-trivial drop logic, and `WuiArray` will not call `drop` on each element when it is dropped.
+ */
+typedef struct WuiArray_WuiTableColumn {
+  NonNull data;
+  struct WuiArrayVTable_WuiTableColumn vtable;
+} WuiArray_WuiTableColumn;
+
+typedef struct Computed_Vec_TableColumn WuiComputed_Vec_TableColumn;
+
+typedef struct WuiTable {
+  WuiComputed_Vec_TableColumn *columns;
+} WuiTable;
 
 typedef struct WuiProgress {
   struct WuiAnyView *label;
   struct WuiAnyView *value_label;
+  /**
+   * Completed fraction in 0.0..=1.0, already divided by the total.
+   * A non-finite value shows the indeterminate style.
+   */
   WuiComputed_f64 *value;
   enum WuiProgressStyle style;
+  /**
+   * Optional tint color (null uses the theme accent).
+   */
+  struct WuiColor *tint;
 } WuiProgress;
 
 /**
@@ -2752,6 +3848,15 @@ typedef struct WuiGpuSurface {
    * This is consumed during init and should not be used after.
    */
   void *renderer;
+  /**
+   * How frames are presented.
+   */
+  enum WuiPresentMode present_mode;
+  /**
+   * MSAA sample count for the renderer's color target; 1 disables multisampling.
+   * Counts the adapter does not support fall back to the highest one it does.
+   */
+  uint32_t sample_count;
 } WuiGpuSurface;
 
 /**
@@ -3050,6 +4155,45 @@ typedef struct WuiId {
   int32_t inner;
 } WuiId;
 
+/**
+ * Backend callbacks used by a scroll proxy to drive its scroll view.
+ *
+ * Commands may be issued from any thread; backends are expected to hop to
+ * their UI thread before scrolling.
+ */
+typedef struct WuiScrollCommandSink {
+  void *data;
+  /**
+   * Scrolls to an absolute content offset, in points.
+   */
+  void (*scroll_to_offset)(void *data, struct WuiPoint offset, bool animated);
+  /**
+   * Scrolls until the view tagged with `id` is positioned at `anchor`.
+   */
+  void (*scroll_to_id)(void *data, struct WuiId id, enum WuiAlignment anchor, bool animated);
+  /**
+   * Releases `data`. Called when the sink is replaced or the proxy is dropped.
+   */
+  void (*drop)(void *data);
+} WuiScrollCommandSink;
+
+/**
+ * FFI-safe representation of a view identity used as a scroll target.
+ */
+typedef struct WuiViewId {
+  struct WuiId id;
+} WuiViewId;
+
+typedef struct WuiMetadata_WuiViewId {
+  struct WuiAnyView *content;
+  struct WuiViewId value;
+} WuiMetadata_WuiViewId;
+
+/**
+ * Type alias for Metadata<ViewId> FFI struct
+ */
+typedef struct WuiMetadata_WuiViewId WuiMetadataViewId;
+
 typedef struct Computed_Id WuiComputed_Id;
 
 typedef struct Binding_AnyView WuiBinding_AnyView;
@@ -3338,6 +4482,19 @@ struct WuiTypeId waterui_view_id(const struct WuiAnyView *view);
  */
 enum WuiStretchAxis waterui_view_stretch_axis(const struct WuiAnyView *view);
 
+/**
+ * Returns the Rust type name of the view inside `view`, for diagnostics.
+ *
+ * The name comes from `AnyView::type_name` and is not stable across compiler
+ * versions; use `waterui_view_id` to identify views.
+ *
+ * # Safety
+ * The caller must ensure that `view` is a valid pointer to a properly
+ * initialized `WuiAnyView` instance and that it remains valid for the
+ * duration of this function call.
+ */
+struct WuiStr waterui_view_type_name(const struct WuiAnyView *view);
+
 struct WuiAnyView *waterui_empty_anyview(void);
 
 /**
@@ -3445,6 +4602,66 @@ struct WuiTypeId waterui_metadata_on_event_id(void);
  */
 WuiMetadataOnEvent waterui_force_as_metadata_on_event(struct WuiAnyView *view);
 
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_metadata_on_paste_id(void);
+
+/**
+ * Force-casts an AnyView to this metadata type
+ *
+ * # Safety
+ * The caller must ensure that `view` is a valid pointer to an `AnyView`
+ * that contains a `Metadata<$ty>`.
+ */
+WuiMetadataOnPaste waterui_force_as_metadata_on_paste(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_metadata_on_pointer_id(void);
+
+/**
+ * Force-casts an AnyView to this metadata type
+ *
+ * # Safety
+ * The caller must ensure that `view` is a valid pointer to an `AnyView`
+ * that contains a `Metadata<$ty>`.
+ */
+WuiMetadataOnPointer waterui_force_as_metadata_on_pointer(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_metadata_on_size_change_id(void);
+
+/**
+ * Force-casts an AnyView to this metadata type
+ *
+ * # Safety
+ * The caller must ensure that `view` is a valid pointer to an `AnyView`
+ * that contains a `Metadata<$ty>`.
+ */
+WuiMetadataOnSizeChange waterui_force_as_metadata_on_size_change(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_metadata_refreshable_id(void);
+
+/**
+ * Force-casts an AnyView to this metadata type
+ *
+ * # Safety
+ * The caller must ensure that `view` is a valid pointer to an `AnyView`
+ * that contains a `Metadata<$ty>`.
+ */
+WuiMetadataRefreshable waterui_force_as_metadata_refreshable(struct WuiAnyView *view);
+
 /**
  * Returns the type ID as a 128-bit value for O(1) comparison.
  * Uses TypeId in normal builds, type_name hash in hot reload builds.
@@ -3475,6 +4692,51 @@ struct WuiTypeId waterui_metadata_shadow_id(void);
  */
 WuiMetadataShadow waterui_force_as_metadata_shadow(struct WuiAnyView *view);
 
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_metadata_overlay_id(void);
+
+/**
+ * Force-casts an AnyView to this metadata type
+ *
+ * # Safety
+ * The caller must ensure that `view` is a valid pointer to an `AnyView`
+ * that contains a `Metadata<$ty>`.
+ */
+WuiMetadataOverlay waterui_force_as_metadata_overlay(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_metadata_view_id_id(void);
+
+/**
+ * Force-casts an AnyView to this metadata type
+ *
+ * # Safety
+ * The caller must ensure that `view` is a valid pointer to an `AnyView`
+ * that contains a `Metadata<$ty>`.
+ */
+WuiMetadataViewId waterui_force_as_metadata_view_id(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_metadata_background_view_id(void);
+
+/**
+ * Force-casts an AnyView to this metadata type
+ *
+ * # Safety
+ * The caller must ensure that `view` is a valid pointer to an `AnyView`
+ * that contains a `Metadata<$ty>`.
+ */
+WuiMetadataBackgroundView waterui_force_as_metadata_background_view(struct WuiAnyView *view);
+
 /**
  * Returns the type ID as a 128-bit value for O(1) comparison.
  * Uses TypeId in normal builds, type_name hash in hot reload builds.
@@ -4165,17 +5427,51 @@ struct WuiScrollView waterui_force_as_scroll_view(struct WuiAnyView *view);
 struct WuiTypeId waterui_scroll_view_id(void);
 
 /**
+ * Attaches the backend command sink to a scroll proxy, replacing (and
+ * dropping) any previously attached sink.
+ *
  * # Safety
- * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
- * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
+ *
+ * * `proxy` must be a valid pointer to a WuiScrollProxy.
  */
-struct WuiButton waterui_force_as_button(struct WuiAnyView *view);
+void waterui_scroll_proxy_attach(struct WuiScrollProxy *proxy, struct WuiScrollCommandSink sink);
 
 /**
- * Returns the type ID as a 128-bit value for O(1) comparison.
- * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ * Reports the current scroll geometry, in points.
+ *
+ * Updates the `ScrollPosition` binding without echoing a scroll command back
+ * to the sink, then fires `on_scroll` callbacks.
+ *
+ * # Safety
+ *
+ * * `proxy` must be a valid pointer to a WuiScrollProxy.
  */
-struct WuiTypeId waterui_button_id(void);
+void waterui_scroll_proxy_report(struct WuiScrollProxy *proxy,
+                                 struct WuiPoint offset,
+                                 struct WuiSize content_size,
+                                 struct WuiSize viewport_size);
+
+/**
+ * Drops a scroll proxy together with its attached sink.
+ *
+ * # Safety
+ *
+ * * `proxy` must be a valid pointer to a WuiScrollProxy.
+ */
+void waterui_drop_scroll_proxy(struct WuiScrollProxy *proxy);
+
+/**
+ * # Safety
+ * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
+ * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
+ */
+struct WuiButton waterui_force_as_button(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_button_id(void);
 
 /**
  * # Safety
@@ -4368,6 +5664,24 @@ struct WuiResolvedFont waterui_resolved_font_new(float size, enum WuiFontWeight
 WuiComputed_ResolvedFont *waterui_resolve_font(const struct WuiFont *font,
                                                const struct WuiEnv *env);
 
+/**
+ * Looks up the data of an app-provided font in the environment's `FontRegistry`.
+ *
+ * Among the faces registered under `descriptor.family`, the closest match is
+ * chosen by weight, then width, then italic. On success the font file data is
+ * written to `data` (the caller owns it) and `true` is returned; `false` means
+ * no face of that family is available yet, e.g. one registered from a URL that
+ * is still loading. Takes ownership of `descriptor.family`.
+ *
+ * # Safety
+ *
+ * * `env` must be a valid pointer to a `WuiEnv`.
+ * * `data` must be a valid pointer to writable `WuiArray_u8` storage.
+ */
+bool waterui_font_registry_lookup(const struct WuiEnv *env,
+                                  struct WuiFontDescriptor descriptor,
+                                  struct WuiArray_u8 *data);
+
 /**
  * # Safety
  * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
@@ -4527,233 +5841,580 @@ struct WuiTabs waterui_force_as_tabs(struct WuiAnyView *view);
  * Returns the type ID as a 128-bit value for O(1) comparison.
  * Uses TypeId in normal builds, type_name hash in hot reload builds.
  */
-struct WuiTypeId waterui_tabs_id(void);
+struct WuiTypeId waterui_tabs_id(void);
+
+/**
+ * Creates a new navigation controller from native callbacks.
+ *
+ * # Arguments
+ *
+ * * `data` - Opaque pointer passed to all callbacks (typically pointer to native controller)
+ * * `push` - Callback invoked when pushing a view onto the navigation stack
+ * * `pop` - Callback invoked when popping the top view
+ * * `drop` - Callback invoked when the controller is destroyed (for cleanup)
+ *
+ * # Safety
+ *
+ * - `data` must remain valid for the lifetime of the returned controller
+ * - All callback function pointers must be valid and safe to call
+ * - The `drop` callback must properly clean up resources associated with `data`
+ */
+struct WuiNavigationController *waterui_navigation_controller_new(void *data,
+                                                                  void (*push)(void*,
+                                                                               struct WuiNavigationView),
+                                                                  void (*pop)(void*),
+                                                                  void (*drop)(void*));
+
+/**
+ * Installs a navigation controller into the environment.
+ *
+ * After calling this function, views rendered with this environment can extract
+ * the `NavigationController` and use it to push/pop navigation views.
+ *
+ * # Safety
+ *
+ * - `env` must be a valid pointer to a `WuiEnv`
+ * - `controller` must be a valid pointer returned by `waterui_navigation_controller_new`
+ * - `controller` is consumed by this function and must not be used afterward
+ */
+void waterui_env_install_navigation_controller(struct WuiEnv *env,
+                                               struct WuiNavigationController *controller);
+
+/**
+ * Drops a navigation controller.
+ *
+ * # Safety
+ *
+ * - `controller` must be a valid pointer returned by `waterui_navigation_controller_new`
+ * - `controller` must not have been previously dropped or consumed
+ */
+void waterui_drop_navigation_controller(struct WuiNavigationController *controller);
+
+/**
+ * Checks if a navigation controller is installed in the environment.
+ *
+ * Returns true if a NavigationController is available, false otherwise.
+ * Use this to determine whether to show a back button in navigation views.
+ *
+ * # Safety
+ *
+ * - `env` must be a valid pointer to a `WuiEnv`
+ */
+bool waterui_env_has_navigation_controller(const struct WuiEnv *env);
+
+/**
+ * Pops the top view from the navigation stack.
+ *
+ * If no NavigationController is installed in the environment, this function does nothing.
+ *
+ * # Safety
+ *
+ * - `env` must be a valid pointer to a `WuiEnv`
+ */
+void waterui_navigation_pop(const struct WuiEnv *env);
+
+/**
+ * # Safety
+ * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
+ * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
+ */
+struct WuiPhoto waterui_force_as_photo(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_photo_id(void);
+
+/**
+ * # Safety
+ * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
+ * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
+ */
+struct WuiVideo waterui_force_as_video(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_video_id(void);
+
+/**
+ * # Safety
+ * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
+ * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
+ */
+struct WuiVideoPlayer waterui_force_as_video_player(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_video_player_id(void);
+
+/**
+ * # Safety
+ * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
+ * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
+ */
+struct WuiLivePhoto waterui_force_as_live_photo(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_live_photo_id(void);
+
+/**
+ * Reads the current value from a computed
+ * # Safety
+ * The computed pointer must be valid and point to a properly initialized computed object.
+ */
+struct WuiComputedVideo waterui_read_computed_video(const WuiComputed_Video *computed);
+
+/**
+ * Watches for changes in a computed
+ * # Safety
+ * The computed pointer must be valid and point to a properly initialized computed object.
+ */
+struct WuiWatcherGuard *waterui_watch_computed_video(const WuiComputed_Video *computed,
+                                                     struct WuiWatcher_Video *watcher);
+
+/**
+ * Drops a computed
+ * # Safety
+ * The caller must ensure that `computed` is a valid pointer.
+ */
+void waterui_drop_computed_video(WuiComputed_Video *computed);
+
+/**
+ * Clones a computed
+ * # Safety
+ * The caller must ensure that `computed` is a valid pointer.
+ */
+WuiComputed_Video *waterui_clone_computed_video(const WuiComputed_Video *computed);
+
+/**
+ * Creates a watcher from native callbacks.
+ * # Safety
+ * All function pointers must be valid.
+ */
+struct WuiWatcher_Video *waterui_new_watcher_video(void *data,
+                                                   void (*call)(void*,
+                                                                struct WuiComputedVideo,
+                                                                struct WuiWatcherMetadata*),
+                                                   void (*drop)(void*));
+
+/**
+ * Installs a MediaPickerManager into the environment from native function pointers.
+ *
+ * Native backends call this during initialization to register their media picker
+ * implementation. This unified manager handles both presenting the picker and loading media.
+ *
+ * # Safety
+ *
+ * The caller must ensure that:
+ * - `env` is a valid pointer to a `WuiEnv`
+ * - `present_fn` is a valid function pointer to the native media picker presentation
+ * - `load_fn` is a valid function pointer to the native media loader implementation
+ */
+void waterui_env_install_media_picker_manager(struct WuiEnv *env,
+                                              MediaPickerPresentFn present_fn,
+                                              MediaLoadFn load_fn);
+
+/**
+ * Installs camera capture into the environment from a native function pointer.
+ *
+ * Captured photos and videos are loaded through the `MediaLoadFn` of the
+ * installed MediaPickerManager, so this requires one to be installed as well.
+ *
+ * # Safety
+ *
+ * The caller must ensure that:
+ * - `env` is a valid pointer to a `WuiEnv`
+ * - `capture_fn` is a valid function pointer to the native camera capture
+ */
+void waterui_env_install_camera_capture(struct WuiEnv *env, CameraCaptureFn capture_fn);
+
+/**
+ * Installs the platform main-thread executor into the environment.
+ *
+ * `spawn_on_main` and binding notifications that hop to the main thread post
+ * through `post_fn`, and `is_main_thread` is answered by `is_main_fn`.
+ *
+ * # Safety
+ *
+ * The caller must ensure that:
+ * - `env` is a valid pointer to a `WuiEnv`
+ * - `post_fn` and `is_main_fn` are valid function pointers that may be called
+ *   from any thread
+ */
+void waterui_env_install_main_executor(struct WuiEnv *env,
+                                       MainExecutorPostFn post_fn,
+                                       MainExecutorIsMainFn is_main_fn);
+
+/**
+ * Installs a Permissions service into the environment from native function pointers.
+ *
+ * Features that need system permissions, such as camera capture, check and
+ * request them through this service before presenting native UI.
+ *
+ * The status signals query `status_fn` when first observed and again on
+ * `waterui_env_refresh_permissions`, since users can change permissions in
+ * system settings while the app is in the background.
+ *
+ * # Safety
+ *
+ * The caller must ensure that:
+ * - `env` is a valid pointer to a `WuiEnv`
+ * - `status_fn` and `request_fn` are valid function pointers to the native
+ *   permission implementation
+ */
+void waterui_env_install_permissions(struct WuiEnv *env,
+                                     PermissionStatusFn status_fn,
+                                     PermissionRequestFn request_fn);
+
+/**
+ * Installs a Clipboard into the environment from native function pointers.
+ *
+ * Native backends call this during initialization so that `Clipboard` can be
+ * extracted from the environment and used to copy and paste from Rust code.
+ *
+ * # Safety
+ *
+ * The caller must ensure that:
+ * - `env` is a valid pointer to a `WuiEnv`
+ * - `write_text_fn`, `read_text_fn` and `write_image_fn` are valid function pointers
+ *   to the native clipboard implementation
+ */
+void waterui_env_install_clipboard(struct WuiEnv *env,
+                                   ClipboardWriteTextFn write_text_fn,
+                                   ClipboardReadTextFn read_text_fn,
+                                   ClipboardWriteImageFn write_image_fn);
+
+/**
+ * Installs the bundled asset catalog into the environment.
+ *
+ * `Url::from_asset(name)` and `asset!()` resolve asset names against
+ * `base_url`, which must end with a slash, so views that load URLs natively
+ * can open assets directly. Rust code that needs the contents of an asset
+ * reads it through `read_fn`.
+ *
+ * # Safety
+ *
+ * The caller must ensure that:
+ * - `env` is a valid pointer to a `WuiEnv`
+ * - `base_url` is a valid null-terminated C string
+ * - `read_fn` is a valid function pointer to the native asset reader
+ */
+void waterui_env_install_assets(struct WuiEnv *env, const char *base_url, AssetReadFn read_fn);
+
+/**
+ * Installs the platform `StorageBackend` used by the `Settings` plugin.
+ *
+ * Bindings returned by `Settings::bind` read their initial value through
+ * `read_fn` and persist every change through `write_fn`. Storage must be
+ * durable across app launches.
+ *
+ * # Safety
+ *
+ * The caller must ensure that:
+ * - `env` is a valid pointer to a `WuiEnv`
+ * - `read_fn`, `write_fn` and `remove_fn` are valid function pointers to the
+ *   native storage implementation
+ */
+void waterui_env_install_settings_storage(struct WuiEnv *env,
+                                          SettingsReadFn read_fn,
+                                          SettingsWriteFn write_fn,
+                                          SettingsRemoveFn remove_fn);
+
+/**
+ * Installs a `Feedback` service into the environment from native function pointers.
+ *
+ * Controls such as Toggle and Stepper play haptics through this service.
+ * Without it, `Feedback` is a no-op.
+ *
+ * # Safety
+ *
+ * The caller must ensure that:
+ * - `env` is a valid pointer to a `WuiEnv`
+ * - `impact_fn`, `selection_fn` and `play_sound_fn` are valid function pointers
+ *   that may be called from any thread
+ */
+void waterui_env_install_feedback(struct WuiEnv *env,
+                                  FeedbackImpactFn impact_fn,
+                                  FeedbackSelectionFn selection_fn,
+                                  FeedbackPlaySoundFn play_sound_fn);
+
+/**
+ * Installs a `Notifications` service into the environment from native function pointers.
+ *
+ * Authorization goes through the `Permissions` service with
+ * `WuiPermission_Notifications`. Taps are reported back with
+ * `waterui_env_deliver_notification_response`.
+ *
+ * # Safety
+ *
+ * The caller must ensure that:
+ * - `env` is a valid pointer to a `WuiEnv`
+ * - `schedule_fn` and `cancel_fn` are valid function pointers that may be
+ *   called from any thread
+ */
+void waterui_env_install_notifications(struct WuiEnv *env,
+                                       NotificationScheduleFn schedule_fn,
+                                       NotificationCancelFn cancel_fn);
+
+/**
+ * Delivers a tap on a local notification to the callbacks registered through
+ * `Notifications::on_response`.
+ *
+ * `action_id` is the identifier of the tapped button, or null if the user
+ * tapped the notification itself.
+ *
+ * # Safety
+ *
+ * The caller must ensure that:
+ * - `env` is a valid pointer to a `WuiEnv`
+ * - `notification_id` is a valid null-terminated UTF-8 string
+ * - `action_id` is null or a valid null-terminated UTF-8 string
+ */
+void waterui_env_deliver_notification_response(struct WuiEnv *env,
+                                               const char *notification_id,
+                                               const char *action_id);
+
+/**
+ * Installs a `BackNavigation` service into the environment.
+ *
+ * The service enables back handling through `set_enabled_fn` while a
+ * NavigationStack can pop, a sheet can be dismissed, or app code registered
+ * interest. The host then reports back gestures with
+ * `waterui_env_deliver_back_event`.
+ *
+ * # Safety
+ *
+ * The caller must ensure that:
+ * - `env` is a valid pointer to a `WuiEnv`
+ * - `set_enabled_fn` is a valid function pointer
+ */
+void waterui_env_install_back_navigation(struct WuiEnv *env,
+                                         BackNavigationSetEnabledFn set_enabled_fn);
+
+/**
+ * Delivers a back gesture to the `BackNavigation` service.
+ *
+ * Predictive gestures report `Started`, any number of `Progressed` updates and
+ * then `Cancelled` or `Invoked`; the back button reports only `Invoked`.
+ * `progress` feeds the service's progress signal.
+ *
+ * # Safety
+ *
+ * * `env` must be a valid pointer to a `WuiEnv`.
+ */
+void waterui_env_deliver_back_event(struct WuiEnv *env,
+                                    enum WuiBackPhase phase,
+                                    float progress);
 
 /**
- * Creates a new navigation controller from native callbacks.
- *
- * # Arguments
+ * Publishes the current safe-area and keyboard insets, in points.
  *
- * * `data` - Opaque pointer passed to all callbacks (typically pointer to native controller)
- * * `push` - Callback invoked when pushing a view onto the navigation stack
- * * `pop` - Callback invoked when popping the top view
- * * `drop` - Callback invoked when the controller is destroyed (for cleanup)
+ * The first call installs `SafeAreaInsets` and keyboard inset signals into the
+ * environment; later calls on the same environment or any of its clones update
+ * those signals, notifying watchers only when the values change.
+ * `keyboard` is the height of the bottom edge covered by the software keyboard.
  *
  * # Safety
  *
- * - `data` must remain valid for the lifetime of the returned controller
- * - All callback function pointers must be valid and safe to call
- * - The `drop` callback must properly clean up resources associated with `data`
+ * * `env` must be a valid pointer to a `WuiEnv`.
  */
-struct WuiNavigationController *waterui_navigation_controller_new(void *data,
-                                                                  void (*push)(void*,
-                                                                               struct WuiNavigationView),
-                                                                  void (*pop)(void*),
-                                                                  void (*drop)(void*));
+void waterui_env_set_safe_area_insets(struct WuiEnv *env,
+                                      struct WuiSafeAreaInsets insets,
+                                      float keyboard);
 
 /**
- * Installs a navigation controller into the environment.
+ * Publishes the horizontal and vertical size classes of the window.
  *
- * After calling this function, views rendered with this environment can extract
- * the `NavigationController` and use it to push/pop navigation views.
+ * Like `waterui_env_set_safe_area_insets`, the first call installs the
+ * `SizeClass` signals and later calls update them in place.
  *
  * # Safety
  *
- * - `env` must be a valid pointer to a `WuiEnv`
- * - `controller` must be a valid pointer returned by `waterui_navigation_controller_new`
- * - `controller` is consumed by this function and must not be used afterward
+ * * `env` must be a valid pointer to a `WuiEnv`.
  */
-void waterui_env_install_navigation_controller(struct WuiEnv *env,
-                                               struct WuiNavigationController *controller);
+void waterui_env_set_size_classes(struct WuiEnv *env,
+                                  enum WuiSizeClass horizontal,
+                                  enum WuiSizeClass vertical);
 
 /**
- * Drops a navigation controller.
+ * Publishes the user's preferred text size as a dynamic type scale factor,
+ * where `1.0` is the default size.
+ *
+ * Like `waterui_env_set_safe_area_insets`, the first call installs the
+ * signal and later calls update it in place.
  *
  * # Safety
  *
- * - `controller` must be a valid pointer returned by `waterui_navigation_controller_new`
- * - `controller` must not have been previously dropped or consumed
+ * * `env` must be a valid pointer to a `WuiEnv`.
  */
-void waterui_drop_navigation_controller(struct WuiNavigationController *controller);
+void waterui_env_set_dynamic_type_scale(struct WuiEnv *env, float scale);
 
 /**
- * Checks if a navigation controller is installed in the environment.
+ * Publishes the app lifecycle phase backing the `AppLifecycle` signal and
+ * `on_scene_phase_change` handlers.
  *
- * Returns true if a NavigationController is available, false otherwise.
- * Use this to determine whether to show a back button in navigation views.
+ * Like `waterui_env_set_safe_area_insets`, the first call installs the
+ * signal and later calls update it in place.
  *
  * # Safety
  *
- * - `env` must be a valid pointer to a `WuiEnv`
+ * * `env` must be a valid pointer to a `WuiEnv`.
  */
-bool waterui_env_has_navigation_controller(const struct WuiEnv *env);
+void waterui_env_set_app_phase(struct WuiEnv *env, enum WuiAppPhase phase);
 
 /**
- * Pops the top view from the navigation stack.
- *
- * If no NavigationController is installed in the environment, this function does nothing.
+ * Queries the installed `Permissions` service's `status_fn` again and updates
+ * the status signals whose value changed. Call when the app returns to the
+ * foreground. Does nothing if no `Permissions` service is installed.
  *
  * # Safety
  *
- * - `env` must be a valid pointer to a `WuiEnv`
+ * * `env` must be a valid pointer to a `WuiEnv`.
  */
-void waterui_navigation_pop(const struct WuiEnv *env);
+void waterui_env_refresh_permissions(struct WuiEnv *env);
 
 /**
+ * Delivers an activation URL, such as a custom scheme or app link, to the
+ * handlers registered through `OnOpenUrl`.
+ *
+ * URLs that fail to parse are logged and dropped. A URL delivered before any
+ * handler is registered is kept and handed to the first registration.
+ *
  * # Safety
- * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
- * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
+ *
+ * The caller must ensure that:
+ * - `env` is a valid pointer to a `WuiEnv`
+ * - `url` is a valid null-terminated UTF-8 string
  */
-struct WuiPhoto waterui_force_as_photo(struct WuiAnyView *view);
+void waterui_env_open_url(struct WuiEnv *env, const char *url);
 
 /**
- * Returns the type ID as a 128-bit value for O(1) comparison.
- * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ * # Safety
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
  */
-struct WuiTypeId waterui_photo_id(void);
+void waterui_drop_dynamic(struct WuiDynamic *value);
 
 /**
  * # Safety
  * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
  * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
  */
-struct WuiVideo waterui_force_as_video(struct WuiAnyView *view);
+struct WuiDynamic *waterui_force_as_dynamic(struct WuiAnyView *view);
 
 /**
  * Returns the type ID as a 128-bit value for O(1) comparison.
  * Uses TypeId in normal builds, type_name hash in hot reload builds.
  */
-struct WuiTypeId waterui_video_id(void);
+struct WuiTypeId waterui_dynamic_id(void);
+
+void waterui_dynamic_connect(struct WuiDynamic *dynamic, struct WuiWatcher_AnyView *watcher);
 
 /**
  * # Safety
  * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
  * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
  */
-struct WuiVideoPlayer waterui_force_as_video_player(struct WuiAnyView *view);
+struct WuiListItem waterui_force_as_list_item(struct WuiAnyView *view);
 
 /**
  * Returns the type ID as a 128-bit value for O(1) comparison.
  * Uses TypeId in normal builds, type_name hash in hot reload builds.
  */
-struct WuiTypeId waterui_video_player_id(void);
+struct WuiTypeId waterui_list_item_id(void);
 
 /**
  * # Safety
  * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
  * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
  */
-struct WuiLivePhoto waterui_force_as_live_photo(struct WuiAnyView *view);
+struct WuiList waterui_force_as_list(struct WuiAnyView *view);
 
 /**
  * Returns the type ID as a 128-bit value for O(1) comparison.
  * Uses TypeId in normal builds, type_name hash in hot reload builds.
  */
-struct WuiTypeId waterui_live_photo_id(void);
-
-/**
- * Reads the current value from a computed
- * # Safety
- * The computed pointer must be valid and point to a properly initialized computed object.
- */
-struct WuiComputedVideo waterui_read_computed_video(const WuiComputed_Video *computed);
-
-/**
- * Watches for changes in a computed
- * # Safety
- * The computed pointer must be valid and point to a properly initialized computed object.
- */
-struct WuiWatcherGuard *waterui_watch_computed_video(const WuiComputed_Video *computed,
-                                                     struct WuiWatcher_Video *watcher);
-
-/**
- * Drops a computed
- * # Safety
- * The caller must ensure that `computed` is a valid pointer.
- */
-void waterui_drop_computed_video(WuiComputed_Video *computed);
+struct WuiTypeId waterui_list_id(void);
 
 /**
- * Clones a computed
  * # Safety
- * The caller must ensure that `computed` is a valid pointer.
+ * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
+ * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
  */
-WuiComputed_Video *waterui_clone_computed_video(const WuiComputed_Video *computed);
+struct WuiListSection waterui_force_as_list_section(struct WuiAnyView *view);
 
 /**
- * Creates a watcher from native callbacks.
- * # Safety
- * All function pointers must be valid.
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
  */
-struct WuiWatcher_Video *waterui_new_watcher_video(void *data,
-                                                   void (*call)(void*,
-                                                                struct WuiComputedVideo,
-                                                                struct WuiWatcherMetadata*),
-                                                   void (*drop)(void*));
+struct WuiTypeId waterui_list_section_id(void);
 
 /**
- * Installs a MediaPickerManager into the environment from native function pointers.
- *
- * Native backends call this during initialization to register their media picker
- * implementation. This unified manager handles both presenting the picker and loading media.
- *
  * # Safety
- *
- * The caller must ensure that:
- * - `env` is a valid pointer to a `WuiEnv`
- * - `present_fn` is a valid function pointer to the native media picker presentation
- * - `load_fn` is a valid function pointer to the native media loader implementation
+ * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
+ * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
  */
-void waterui_env_install_media_picker_manager(struct WuiEnv *env,
-                                              MediaPickerPresentFn present_fn,
-                                              MediaLoadFn load_fn);
+struct WuiLazyStack waterui_force_as_lazy_stack(struct WuiAnyView *view);
 
 /**
- * # Safety
- * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
  */
-void waterui_drop_dynamic(struct WuiDynamic *value);
+struct WuiTypeId waterui_lazy_stack_id(void);
 
 /**
  * # Safety
  * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
  * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
  */
-struct WuiDynamic *waterui_force_as_dynamic(struct WuiAnyView *view);
+struct WuiGeometryReader waterui_force_as_geometry_reader(struct WuiAnyView *view);
 
 /**
  * Returns the type ID as a 128-bit value for O(1) comparison.
  * Uses TypeId in normal builds, type_name hash in hot reload builds.
  */
-struct WuiTypeId waterui_dynamic_id(void);
-
-void waterui_dynamic_connect(struct WuiDynamic *dynamic, struct WuiWatcher_AnyView *watcher);
+struct WuiTypeId waterui_geometry_reader_id(void);
 
 /**
+ * Updates the frame published by a geometry proxy.
+ *
+ * `frame` is in points, with its origin relative to the window.
+ * Signals are only notified when the frame actually changes.
+ *
  * # Safety
- * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
- * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
+ *
+ * * `proxy` must be a valid pointer to a WuiGeometryProxy.
  */
-struct WuiListItem waterui_force_as_list_item(struct WuiAnyView *view);
+void waterui_geometry_proxy_update(struct WuiGeometryProxy *proxy, struct WuiRect frame);
 
 /**
- * Returns the type ID as a 128-bit value for O(1) comparison.
- * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ * Drops a geometry proxy.
+ *
+ * # Safety
+ *
+ * * `proxy` must be a valid pointer to a WuiGeometryProxy.
  */
-struct WuiTypeId waterui_list_item_id(void);
+void waterui_drop_geometry_proxy(struct WuiGeometryProxy *proxy);
 
 /**
  * # Safety
  * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
  * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
  */
-struct WuiList waterui_force_as_list(struct WuiAnyView *view);
+struct WuiLazyGrid waterui_force_as_lazy_grid(struct WuiAnyView *view);
 
 /**
  * Returns the type ID as a 128-bit value for O(1) comparison.
  * Uses TypeId in normal builds, type_name hash in hot reload builds.
  */
-struct WuiTypeId waterui_list_id(void);
+struct WuiTypeId waterui_lazy_grid_id(void);
 
 /**
  * Reads the current value from a computed
@@ -4883,11 +6544,17 @@ struct WuiGpuSurfaceState *waterui_gpu_surface_init(struct WuiGpuSurface *surfac
  * This function should be called from a display-sync callback (CADisplayLink on Apple,
  * Choreographer on Android) to render at the display's refresh rate.
  *
+ * A size that differs from the previous frame reconfigures the surface and is
+ * reported to the renderer's `resize()` before drawing. The frame time drives
+ * the delta-time clock passed to the renderer.
+ *
  * # Arguments
  *
  * * `state` - Pointer to the initialized state from `waterui_gpu_surface_init`
  * * `width` - Current surface width in pixels (from layout)
  * * `height` - Current surface height in pixels (from layout)
+ * * `frame_time_nanos` - Timestamp of the display frame in nanoseconds on a
+ *   monotonic clock (`CFTimeInterval` converted on Apple, `frameTimeNanos` on Android)
  *
  * # Returns
  *
@@ -4897,7 +6564,10 @@ struct WuiGpuSurfaceState *waterui_gpu_surface_init(struct WuiGpuSurface *surfac
  *
  * `state` must be a valid pointer from `waterui_gpu_surface_init`.
  */
-bool waterui_gpu_surface_render(struct WuiGpuSurfaceState *state, uint32_t width, uint32_t height);
+bool waterui_gpu_surface_render(struct WuiGpuSurfaceState *state,
+                                uint32_t width,
+                                uint32_t height,
+                                uint64_t frame_time_nanos);
 
 /**
  * Clean up GPU resources.
@@ -4911,6 +6581,46 @@ bool waterui_gpu_surface_render(struct WuiGpuSurfaceState *state, uint32_t width
  */
 void waterui_gpu_surface_drop(struct WuiGpuSurfaceState *state);
 
+/**
+ * Queues a pointer event for the renderer.
+ *
+ * Events are handed to the renderer in the frame context of the next
+ * `waterui_gpu_surface_render`, in the order they were queued. `x` and `y` are
+ * in pixels relative to the top-left corner of the surface, matching the
+ * surface size passed to render. `pointer_id` and `pressure` follow
+ * `waterui_call_on_pointer`.
+ *
+ * # Safety
+ *
+ * `state` must be a valid pointer from `waterui_gpu_surface_init`.
+ */
+void waterui_gpu_surface_pointer(struct WuiGpuSurfaceState *state,
+                                 enum WuiPointerPhase phase,
+                                 enum WuiPointerKind kind,
+                                 uint32_t pointer_id,
+                                 float x,
+                                 float y,
+                                 float pressure);
+
+/**
+ * Queues a key event for the renderer, delivered like pointer events.
+ *
+ * `key` is the logical key as a W3C `KeyboardEvent.key` value: the character
+ * produced for printable keys (such as `"a"` or `" "`), otherwise a named key
+ * such as `"Enter"` or `"ArrowLeft"`. `modifiers` is a bit set of Shift (1),
+ * Control (2), Alt (4) and Meta (8). `repeat` is true for auto-repeated presses.
+ *
+ * # Safety
+ *
+ * `state` must be a valid pointer from `waterui_gpu_surface_init`, and `key`
+ * a valid null-terminated UTF-8 string.
+ */
+void waterui_gpu_surface_key(struct WuiGpuSurfaceState *state,
+                             enum WuiKeyPhase phase,
+                             const char *key,
+                             uint32_t modifiers,
+                             bool repeat);
+
 /**
  * # Safety
  * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
@@ -5223,6 +6933,98 @@ void waterui_call_on_event(struct WuiOnEventHandler *handler, const struct WuiEn
  */
 void waterui_drop_on_event(struct WuiOnEventHandler *handler);
 
+/**
+ * Calls an OnPaste handler with the pasted text.
+ * This handler can be called multiple times (repeatable).
+ *
+ * # Safety
+ *
+ * * `handler` must be a valid pointer to a WuiOnPasteHandler.
+ * * `env` must be a valid pointer to a WuiEnv.
+ * * `text` must be a valid null-terminated UTF-8 string.
+ */
+void waterui_call_on_paste(struct WuiOnPasteHandler *handler,
+                           const struct WuiEnv *env,
+                           const char *text);
+
+/**
+ * Drops an OnPaste handler.
+ *
+ * # Safety
+ *
+ * * `handler` must be a valid pointer to a WuiOnPasteHandler.
+ */
+void waterui_drop_on_paste(struct WuiOnPasteHandler *handler);
+
+/**
+ * Delivers a pointer event to an OnPointer handler.
+ *
+ * `x` and `y` are in points relative to the top-left corner of the view,
+ * which for a canvas is canvas space. `pointer_id` stays stable from Down to
+ * Up or Cancel, and `pressure` is 1.0 for devices that do not report it.
+ *
+ * # Safety
+ *
+ * * `handler` must be a valid pointer to a WuiOnPointerHandler.
+ */
+void waterui_call_on_pointer(struct WuiOnPointerHandler *handler,
+                             enum WuiPointerPhase phase,
+                             enum WuiPointerKind kind,
+                             uint32_t pointer_id,
+                             float x,
+                             float y,
+                             float pressure);
+
+/**
+ * Drops an OnPointer handler.
+ *
+ * # Safety
+ *
+ * * `handler` must be a valid pointer to a WuiOnPointerHandler.
+ */
+void waterui_drop_on_pointer(struct WuiOnPointerHandler *handler);
+
+/**
+ * Reports the measured size of a view to an OnSizeChange handler.
+ * The bound size is only updated when it differs from the current value.
+ *
+ * # Safety
+ *
+ * * `handler` must be a valid pointer to a WuiOnSizeChangeHandler.
+ */
+void waterui_call_on_size_change(struct WuiOnSizeChangeHandler *handler, struct WuiSize size);
+
+/**
+ * Drops an OnSizeChange handler.
+ *
+ * # Safety
+ *
+ * * `handler` must be a valid pointer to a WuiOnSizeChangeHandler.
+ */
+void waterui_drop_on_size_change(struct WuiOnSizeChangeHandler *handler);
+
+/**
+ * Starts a refresh by spawning the handler's async closure on the task executor.
+ * `completion` is called once the closure's future resolves.
+ *
+ * # Safety
+ *
+ * * `handler` must be a valid pointer to a WuiRefreshHandler.
+ * * `env` must be a valid pointer to a WuiEnv.
+ */
+void waterui_call_refreshable(struct WuiRefreshHandler *handler,
+                              struct WuiEnv *env,
+                              struct WuiRefreshCompletion completion);
+
+/**
+ * Drops a Refreshable handler. Refreshes that are still running complete normally.
+ *
+ * # Safety
+ *
+ * * `handler` must be a valid pointer to a WuiRefreshHandler.
+ */
+void waterui_drop_refreshable(struct WuiRefreshHandler *handler);
+
 /**
  * Drops a WuiGesture, recursively freeing any Then variants.
  *
@@ -6398,10 +8200,97 @@ struct WuiWatcher_WindowState *waterui_new_watcher_window_state(void *data,
  */
 void waterui_env_install_window_manager(struct WuiEnv *env, WindowShowFn show_fn);
 
+/**
+ * Returns the C ABI version the library was built with.
+ *
+ * Hosts must check it against the `WATERUI_ABI_VERSION` of the header they
+ * were compiled with and refuse to run on a mismatch.
+ */
+uint32_t waterui_abi_version(void);
+
+/**
+ * Registers the handler notified when a panic is caught at an FFI boundary.
+ *
+ * Every exported function catches panics instead of unwinding into the host.
+ * It then returns a null pointer or default value, and handles it was operating
+ * on are poisoned so later calls on them are no-ops.
+ * Without a handler, panics are only printed to stderr.
+ *
+ * # Safety
+ *
+ * `handler` must be a valid function pointer that may be called from any thread.
+ */
+void waterui_set_panic_handler(WuiPanicHandlerFn handler);
+
+/**
+ * Returns a JSON snapshot of the Rust-side UI state for bug reports.
+ *
+ * The snapshot describes the values and plugins installed in each live
+ * environment. Its layout is meant for people reading a report and may change
+ * between releases.
+ */
+struct WuiStr waterui_debug_dump(void);
+
+/**
+ * Routes log and tracing output to the platform's log.
+ *
+ * Messages below `max_level` are discarded before formatting. Without a sink,
+ * output is written to stderr.
+ *
+ * # Safety
+ *
+ * `sink` must be a valid function pointer that may be called from any thread.
+ */
+void waterui_set_log_sink(WuiLogSinkFn sink, enum WuiLogLevel max_level);
+
 WuiEnv* waterui_init(void);
 
 struct WuiApp waterui_app(WuiEnv *env);
 
+/**
+ * Returns the hot reload ABI version of this library.
+ *
+ * A host only swaps in a rebuilt library that reports the same version as the
+ * running one; otherwise the app has to be restarted.
+ */
+uint32_t waterui_reload_abi_version(void);
+
+/**
+ * Serializes the values of bindings registered for hot reload.
+ *
+ * Call on the running library before its view tree is torn down, and pass the
+ * result to `waterui_reload_restore_state` of the rebuilt library.
+ */
+struct WuiArray_u8 waterui_reload_save_state(void);
+
+/**
+ * Restores state saved by `waterui_reload_save_state` of a previous library.
+ *
+ * Call before `waterui_app`. Bindings registered under a saved key start from
+ * the saved value; keys that no longer exist are ignored.
+ */
+void waterui_reload_restore_state(struct WuiArray_u8 state);
+
+/**
+ * Connects to the live reload channel of the CLI at `host:port`.
+ *
+ * The connection runs on a background thread and is re-established when it
+ * drops. Each time the CLI reports a finished rebuild, the rebuilt library is
+ * received into `directory` under a new file name and `on_rebuilt` is called
+ * with its path. Calling again replaces the previous connection.
+ *
+ * # Safety
+ *
+ * `host` and `directory` must be valid null-terminated UTF-8 strings.
+ * `on_rebuilt` must be a valid function pointer that may be called from any
+ * thread, and the library that opened the connection must stay loaded while
+ * it is open.
+ */
+void waterui_reload_connect(const char *host,
+                            uint16_t port,
+                            const char *directory,
+                            WuiReloadLibraryFn on_rebuilt);
+
 #ifdef __cplusplus
 }
 #endif
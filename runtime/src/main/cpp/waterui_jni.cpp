@@ -20,11 +20,13 @@
 #include <dlfcn.h>
 #include <jni.h>
 #include <string>
+#include <unistd.h>
 #include <vector>
 
 namespace {
 
 constexpr char LOG_TAG[] = "WaterUI.JNI";
+constexpr char RUST_LOG_TAG[] = "WaterUI";
 
 // Symbols we need for watcher operations and complex struct handling
 #define WATCHER_SYMBOL_LIST(X)                                                 \
@@ -72,6 +74,7 @@ constexpr char LOG_TAG[] = "WaterUI.JNI";
   X(waterui_layout_place)                                                      \
   X(waterui_view_id)                                                           \
   X(waterui_view_stretch_axis)                                                 \
+  X(waterui_view_type_name)                                                    \
   X(waterui_force_as_plain)                                                    \
   X(waterui_empty_id)                                                          \
   X(waterui_text_id)                                                           \
@@ -91,8 +94,16 @@ constexpr char LOG_TAG[] = "WaterUI.JNI";
   X(waterui_fixed_container_id)                                                \
   X(waterui_picker_id)                                                         \
   X(waterui_layout_container_id)                                               \
+  X(waterui_abi_version)                                                       \
+  X(waterui_set_panic_handler)                                                 \
+  X(waterui_debug_dump)                                                        \
+  X(waterui_set_log_sink)                                                      \
   X(waterui_init)                                                              \
   X(waterui_app)                                                               \
+  X(waterui_reload_abi_version)                                                \
+  X(waterui_reload_save_state)                                                 \
+  X(waterui_reload_restore_state)                                              \
+  X(waterui_reload_connect)                                                    \
   X(waterui_view_body)                                                         \
   X(waterui_clone_env)                                                         \
   X(waterui_drop_env)                                                          \
@@ -108,6 +119,9 @@ constexpr char LOG_TAG[] = "WaterUI.JNI";
   X(waterui_force_as_color_picker)                                             \
   X(waterui_force_as_progress)                                                 \
   X(waterui_force_as_scroll_view)                                              \
+  X(waterui_scroll_proxy_attach)                                               \
+  X(waterui_scroll_proxy_report)                                               \
+  X(waterui_drop_scroll_proxy)                                                 \
   X(waterui_force_as_picker)                                                   \
   X(waterui_force_as_layout_container)                                         \
   X(waterui_force_as_fixed_container)                                          \
@@ -127,6 +141,7 @@ constexpr char LOG_TAG[] = "WaterUI.JNI";
   X(waterui_resolve_color)                                                     \
   X(waterui_resolve_font)                                                      \
   X(waterui_resolved_font_new)                                                 \
+  X(waterui_font_registry_lookup)                                              \
   X(waterui_drop_box_watcher_guard)                                            \
   X(waterui_get_animation)                                                     \
   X(waterui_anyviews_len)                                                      \
@@ -198,12 +213,26 @@ constexpr char LOG_TAG[] = "WaterUI.JNI";
   X(waterui_force_as_metadata_lifecycle_hook)                                  \
   X(waterui_metadata_on_event_id)                                              \
   X(waterui_force_as_metadata_on_event)                                        \
+  X(waterui_metadata_on_paste_id)                                              \
+  X(waterui_force_as_metadata_on_paste)                                        \
+  X(waterui_metadata_on_pointer_id)                                            \
+  X(waterui_force_as_metadata_on_pointer)                                      \
+  X(waterui_metadata_on_size_change_id)                                        \
+  X(waterui_force_as_metadata_on_size_change)                                  \
+  X(waterui_metadata_refreshable_id)                                           \
+  X(waterui_force_as_metadata_refreshable)                                     \
   X(waterui_metadata_cursor_id)                                                \
   X(waterui_force_as_metadata_cursor)                                          \
   X(waterui_metadata_foreground_id)                                            \
   X(waterui_force_as_metadata_foreground)                                      \
   X(waterui_metadata_shadow_id)                                                \
   X(waterui_force_as_metadata_shadow)                                          \
+  X(waterui_metadata_overlay_id)                                               \
+  X(waterui_force_as_metadata_overlay)                                         \
+  X(waterui_metadata_view_id_id)                                               \
+  X(waterui_force_as_metadata_view_id)                                         \
+  X(waterui_metadata_background_view_id)                                       \
+  X(waterui_force_as_metadata_background_view)                                 \
   X(waterui_metadata_border_id)                                                \
   X(waterui_force_as_metadata_border)                                          \
   X(waterui_metadata_focused_id)                                               \
@@ -237,6 +266,14 @@ constexpr char LOG_TAG[] = "WaterUI.JNI";
   X(waterui_drop_lifecycle_hook)                                               \
   X(waterui_call_on_event)                                                     \
   X(waterui_drop_on_event)                                                     \
+  X(waterui_call_on_paste)                                                     \
+  X(waterui_drop_on_paste)                                                     \
+  X(waterui_call_on_pointer)                                                   \
+  X(waterui_drop_on_pointer)                                                   \
+  X(waterui_call_on_size_change)                                               \
+  X(waterui_drop_on_size_change)                                               \
+  X(waterui_call_refreshable)                                                  \
+  X(waterui_drop_refreshable)                                                  \
   X(waterui_read_computed_cursor_style)                                        \
   X(waterui_watch_computed_cursor_style)                                       \
   X(waterui_drop_computed_cursor_style)                                        \
@@ -283,11 +320,41 @@ constexpr char LOG_TAG[] = "WaterUI.JNI";
   X(waterui_gpu_surface_init)                                                  \
   X(waterui_gpu_surface_render)                                                \
   X(waterui_gpu_surface_drop)                                                  \
+  X(waterui_gpu_surface_pointer)                                               \
+  X(waterui_gpu_surface_key)                                                   \
   X(waterui_list_id)                                                           \
   X(waterui_list_item_id)                                                      \
   X(waterui_force_as_list)                                                     \
   X(waterui_force_as_list_item)                                                \
+  X(waterui_list_section_id)                                                   \
+  X(waterui_force_as_list_section)                                             \
+  X(waterui_lazy_stack_id)                                                     \
+  X(waterui_force_as_lazy_stack)                                               \
+  X(waterui_geometry_reader_id)                                                \
+  X(waterui_force_as_geometry_reader)                                          \
+  X(waterui_geometry_proxy_update)                                             \
+  X(waterui_drop_geometry_proxy)                                               \
+  X(waterui_lazy_grid_id)                                                      \
+  X(waterui_force_as_lazy_grid)                                                \
   X(waterui_env_install_media_picker_manager)                                  \
+  X(waterui_env_install_camera_capture)                                        \
+  X(waterui_env_install_permissions)                                           \
+  X(waterui_env_install_main_executor)                                         \
+  X(waterui_env_install_clipboard)                                             \
+  X(waterui_env_install_assets)                                                \
+  X(waterui_env_install_settings_storage)                                      \
+  X(waterui_env_install_feedback)                                              \
+  X(waterui_env_install_notifications)                                         \
+  X(waterui_env_deliver_notification_response)                                 \
+  X(waterui_env_install_back_navigation)                                       \
+  X(waterui_env_deliver_back_event)                                            \
+  X(waterui_env_install_locale_string)                                         \
+  X(waterui_env_set_safe_area_insets)                                          \
+  X(waterui_env_set_size_classes)                                              \
+  X(waterui_env_set_dynamic_type_scale)                                        \
+  X(waterui_env_set_app_phase)                                                 \
+  X(waterui_env_refresh_permissions)                                           \
+  X(waterui_env_open_url)                                                      \
   X(waterui_metadata_clip_shape_id)                                            \
   X(waterui_force_as_metadata_clip_shape)                                      \
   X(waterui_metadata_context_menu_id)                                          \
@@ -788,9 +855,13 @@ jobject new_resolved_color(JNIEnv *env, const WuiResolvedColor &color) {
 jobject new_resolved_font(JNIEnv *env, const WuiResolvedFont &font) {
   jclass cls =
       find_app_class(env, "dev/waterui/android/runtime/ResolvedFontStruct");
-  jmethodID ctor = env->GetMethodID(cls, "<init>", "(FI)V");
-  jobject obj =
-      env->NewObject(cls, ctor, font.size, static_cast<jint>(font.weight));
+  jmethodID ctor =
+      env->GetMethodID(cls, "<init>", "(FILjava/lang/String;IZ)V");
+  jstring family = wui_str_to_jstring(env, font.family);
+  jobject obj = env->NewObject(
+      cls, ctor, font.size, static_cast<jint>(font.weight), family,
+      static_cast<jint>(font.width), font.italic ? JNI_TRUE : JNI_FALSE);
+  env->DeleteLocalRef(family);
   env->DeleteLocalRef(cls);
   return obj;
 }
@@ -801,7 +872,8 @@ jobject new_text_style(JNIEnv *env, const WuiTextStyle &style, jclass cls,
       cls, ctor, ptr_to_jlong(style.font), style.italic ? JNI_TRUE : JNI_FALSE,
       style.underline ? JNI_TRUE : JNI_FALSE,
       style.strikethrough ? JNI_TRUE : JNI_FALSE,
-      ptr_to_jlong(style.foreground), ptr_to_jlong(style.background));
+      ptr_to_jlong(style.foreground), ptr_to_jlong(style.background),
+      ptr_to_jlong(style.tap));
 }
 
 jobject new_styled_chunk(JNIEnv *env, const WuiStyledChunk &chunk,
@@ -809,7 +881,8 @@ jobject new_styled_chunk(JNIEnv *env, const WuiStyledChunk &chunk,
                          jmethodID styleCtor) {
   jstring text = wui_str_to_jstring(env, chunk.text);
   jobject styleObj = new_text_style(env, chunk.style, styleCls, styleCtor);
-  jobject chunkObj = env->NewObject(chunkCls, chunkCtor, text, styleObj);
+  jobject chunkObj = env->NewObject(chunkCls, chunkCtor, text, styleObj,
+                                    ptr_to_jlong(chunk.attachment));
   env->DeleteLocalRef(text);
   env->DeleteLocalRef(styleObj);
   return chunkObj;
@@ -821,12 +894,12 @@ jobject new_styled_str(JNIEnv *env, WuiStyledStr styled) {
 
   jclass styleCls =
       find_app_class(env, "dev/waterui/android/runtime/TextStyleStruct");
-  jmethodID styleCtor = env->GetMethodID(styleCls, "<init>", "(JZZZJJ)V");
+  jmethodID styleCtor = env->GetMethodID(styleCls, "<init>", "(JZZZJJJ)V");
   jclass chunkCls =
       find_app_class(env, "dev/waterui/android/runtime/StyledChunkStruct");
   jmethodID chunkCtor = env->GetMethodID(
       chunkCls, "<init>",
-      "(Ljava/lang/String;Ldev/waterui/android/runtime/TextStyleStruct;)V");
+      "(Ljava/lang/String;Ldev/waterui/android/runtime/TextStyleStruct;J)V");
   jclass strCls =
       find_app_class(env, "dev/waterui/android/runtime/StyledStrStruct");
   jmethodID strCtor = env->GetMethodID(
@@ -1590,8 +1663,126 @@ extern "C" {
 void waterui_present_media_picker(WuiMediaFilterType filter,
                                   MediaPickerPresentCallback callback);
 void waterui_load_media(uint32_t id, MediaLoadCallback callback);
+void waterui_capture_media(WuiCaptureMode mode,
+                           MediaPickerPresentCallback callback);
+// Rust -> Android callbacks for MainExecutorBridge
+void waterui_main_executor_post(WuiMainTask task);
+bool waterui_main_executor_is_main();
+// Rust -> Android callbacks for PermissionsBridge
+WuiPermissionStatus waterui_permission_status(WuiPermission permission);
+void waterui_request_permission(WuiPermission permission,
+                                PermissionRequestCallback callback);
+// Rust -> Android callbacks for ClipboardBridge
+void waterui_clipboard_write_text(const char *text);
+void waterui_clipboard_read_text(ClipboardReadCallback callback);
+void waterui_clipboard_write_image(const uint8_t *data, uintptr_t len);
+// Rust -> Android callback for AssetBridge
+bool waterui_asset_read(const char *name, WuiArray_u8 *data);
+// Rust -> Android callbacks for SettingsBridge
+bool waterui_settings_read(const char *key, WuiArray_u8 *data);
+void waterui_settings_write(const char *key, const uint8_t *data,
+                            uintptr_t len);
+void waterui_settings_remove(const char *key);
+// Rust -> Android callbacks for FeedbackBridge
+void waterui_feedback_impact(WuiImpactStyle style);
+void waterui_feedback_selection(void);
+void waterui_feedback_play_sound(const char *asset);
+// Rust -> Android callbacks for NotificationsBridge
+void waterui_notifications_schedule(WuiNotificationRequest request);
+void waterui_notifications_cancel(WuiStr id);
+// Rust -> Android callback for BackNavigationBridge
+void waterui_back_navigation_set_enabled(bool enabled);
+// Rust -> Android callback for HotReload
+void waterui_hot_reload_library(const char *path);
 static WuiWebViewHandle create_webview_handle();
 
+// Reports panics caught at the FFI boundary. stderr is discarded on Android,
+// so without this they would vanish silently.
+static void waterui_log_panic(const char *function, const char *message) {
+  __android_log_print(ANDROID_LOG_ERROR, LOG_TAG, "Rust panic in %s: %s",
+                      function, message);
+}
+
+// Forwards Rust log and tracing output to logcat.
+static WuiLogLevel g_log_level = WuiLogLevel_Info;
+
+static void waterui_log_message(WuiLogLevel level, const char *target,
+                                const char *message) {
+  int priority;
+  switch (level) {
+  case WuiLogLevel_Error:
+    priority = ANDROID_LOG_ERROR;
+    break;
+  case WuiLogLevel_Warn:
+    priority = ANDROID_LOG_WARN;
+    break;
+  case WuiLogLevel_Info:
+    priority = ANDROID_LOG_INFO;
+    break;
+  case WuiLogLevel_Debug:
+    priority = ANDROID_LOG_DEBUG;
+    break;
+  default:
+    priority = ANDROID_LOG_VERBOSE;
+    break;
+  }
+  __android_log_print(priority, RUST_LOG_TAG, "%s: %s", target, message);
+}
+
+// Installs the handlers the app library reports through; repeated for every
+// library swapped in by hot reload.
+static void install_native_handlers() {
+  g_sym.waterui_set_panic_handler(waterui_log_panic);
+  g_sym.waterui_set_log_sink(waterui_log_message, g_log_level);
+}
+
+// Resolves every symbol in WATCHER_SYMBOL_LIST from `handle` into `out`.
+// On failure, `error` describes the first missing symbol.
+static bool resolve_symbols(void *handle, WatcherSymbols *out,
+                            std::string *error) {
+  dlerror();
+#define LOAD_SYMBOL(name)                                                      \
+  out->name = reinterpret_cast<decltype(&::name)>(dlsym(handle, #name));       \
+  if (out->name == nullptr) {                                                  \
+    *error = "Unable to resolve symbol ";                                      \
+    *error += #name;                                                           \
+    *error += ": ";                                                            \
+    *error += dlerror();                                                       \
+    return false;                                                              \
+  }
+  WATCHER_SYMBOL_LIST(LOAD_SYMBOL)
+#undef LOAD_SYMBOL
+  return true;
+}
+
+// Checks the C ABI version of the library behind `handle` before any other
+// symbol is resolved, so a library built for another ABI is reported as such
+// rather than as a missing symbol. On failure, `error` describes the mismatch.
+static bool check_abi_version(void *handle, const char *name,
+                              std::string *error) {
+  dlerror();
+  auto abi_version = reinterpret_cast<decltype(&::waterui_abi_version)>(
+      dlsym(handle, "waterui_abi_version"));
+  if (abi_version == nullptr) {
+    *error = name;
+    *error += " does not export waterui_abi_version: ";
+    *error += dlerror();
+    *error += "; update the Android backend or rebuild the app";
+    return false;
+  }
+  uint32_t abi = abi_version();
+  if (abi != WATERUI_ABI_VERSION) {
+    *error = name;
+    *error += " was built for ABI version ";
+    *error += std::to_string(abi);
+    *error += ", but this runtime expects version ";
+    *error += std::to_string(WATERUI_ABI_VERSION);
+    *error += "; update the Android backend or rebuild the app";
+    return false;
+  }
+  return true;
+}
+
 // Bootstrap - loads symbols from libwaterui_app.so
 JNIEXPORT void JNICALL
 Java_dev_waterui_android_ffi_WatcherJni_nativeInit(JNIEnv *env, jclass clazz) {
@@ -1608,24 +1799,94 @@ Java_dev_waterui_android_ffi_WatcherJni_nativeInit(JNIEnv *env, jclass clazz) {
     return;
   }
 
-  dlerror();
-#define LOAD_SYMBOL(name)                                                      \
-  g_sym.name = reinterpret_cast<decltype(&::name)>(dlsym(handle, #name));      \
-  if (g_sym.name == nullptr) {                                                 \
-    std::string error = "Unable to resolve symbol ";                           \
-    error += #name;                                                            \
-    error += ": ";                                                             \
-    error += dlerror();                                                        \
-    throw_unsatisfied(env, error);                                             \
-    return;                                                                    \
+  std::string error;
+  if (!check_abi_version(handle, so_name, &error) ||
+      !resolve_symbols(handle, &g_sym, &error)) {
+    throw_unsatisfied(env, error);
+    return;
   }
-  WATCHER_SYMBOL_LIST(LOAD_SYMBOL)
-#undef LOAD_SYMBOL
+  install_native_handlers();
   g_symbols_ready = true;
   __android_log_print(ANDROID_LOG_INFO, LOG_TAG,
                       "Loaded watcher symbols from %s", so_name);
 }
 
+// Hot reload - swaps in the symbols of a rebuilt application library.
+// The previous library stays loaded: closures and statics it owns may still be
+// referenced until every view built from it has been dropped.
+JNIEXPORT jboolean JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_reloadLibrary(JNIEnv *env, jclass,
+                                                      jstring path) {
+  const char *pathCStr = env->GetStringUTFChars(path, nullptr);
+  void *handle = dlopen(pathCStr, RTLD_NOW | RTLD_LOCAL);
+  if (handle == nullptr) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG, "dlopen failed for %s: %s",
+                        pathCStr, dlerror());
+    env->ReleaseStringUTFChars(path, pathCStr);
+    return JNI_FALSE;
+  }
+
+  WatcherSymbols symbols{};
+  std::string error;
+  if (!check_abi_version(handle, pathCStr, &error) ||
+      !resolve_symbols(handle, &symbols, &error)) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG, "Cannot reload %s: %s",
+                        pathCStr, error.c_str());
+    dlclose(handle);
+    env->ReleaseStringUTFChars(path, pathCStr);
+    return JNI_FALSE;
+  }
+
+  uint32_t running = g_sym.waterui_reload_abi_version();
+  uint32_t rebuilt = symbols.waterui_reload_abi_version();
+  if (running != rebuilt) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "Cannot reload %s: ABI version %u does not match %u",
+                        pathCStr, rebuilt, running);
+    dlclose(handle);
+    env->ReleaseStringUTFChars(path, pathCStr);
+    return JNI_FALSE;
+  }
+
+  g_sym = symbols;
+  install_native_handlers();
+  __android_log_print(ANDROID_LOG_INFO, LOG_TAG, "Reloaded symbols from %s",
+                      pathCStr);
+  env->ReleaseStringUTFChars(path, pathCStr);
+  return JNI_TRUE;
+}
+
+JNIEXPORT jbyteArray JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_reloadSaveState(JNIEnv *env, jclass) {
+  WuiStr state{};
+  state._0 = g_sym.waterui_reload_save_state();
+  return wui_str_to_byte_array(env, state);
+}
+
+JNIEXPORT void JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_reloadRestoreState(JNIEnv *env, jclass,
+                                                           jbyteArray state) {
+  g_sym.waterui_reload_restore_state(str_from_byte_array(env, state)._0);
+}
+
+JNIEXPORT void JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_setLogLevel(JNIEnv *, jclass,
+                                                    jint level) {
+  g_log_level = static_cast<WuiLogLevel>(level);
+  if (g_symbols_ready) {
+    g_sym.waterui_set_log_sink(waterui_log_message, g_log_level);
+  }
+}
+
+// Debug dump - null until the app library is loaded, since this may run from
+// a crash handler
+JNIEXPORT jstring JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_debugDump(JNIEnv *env, jclass) {
+  if (!g_symbols_ready)
+    return nullptr;
+  return wui_str_to_jstring(env, g_sym.waterui_debug_dump());
+}
+
 // ========== Watcher Creation ==========
 
 #define DEFINE_WATCHER_CREATOR(JavaName, WatcherType, ValueType)               \
@@ -1961,6 +2222,12 @@ JNIEXPORT jint JNICALL Java_dev_waterui_android_ffi_WatcherJni_viewStretchAxis(
   return static_cast<jint>(g_sym.waterui_view_stretch_axis(view));
 }
 
+JNIEXPORT jstring JNICALL Java_dev_waterui_android_ffi_WatcherJni_viewTypeName(
+    JNIEnv *env, jclass, jlong viewPtr) {
+  auto *view = jlong_to_ptr<WuiAnyView>(viewPtr);
+  return wui_str_to_jstring(env, g_sym.waterui_view_type_name(view));
+}
+
 JNIEXPORT jobject JNICALL Java_dev_waterui_android_ffi_WatcherJni_forceAsPlain(
     JNIEnv *env, jclass, jlong viewPtr) {
   auto *view = jlong_to_ptr<WuiAnyView>(viewPtr);
@@ -2287,9 +2554,17 @@ DEFINE_TYPE_ID_FN(metadataHighDynamicRangeId,
 DEFINE_TYPE_ID_FN(metadataGestureId, waterui_metadata_gesture_id)
 DEFINE_TYPE_ID_FN(metadataLifeCycleHookId, waterui_metadata_lifecycle_hook_id)
 DEFINE_TYPE_ID_FN(metadataOnEventId, waterui_metadata_on_event_id)
+DEFINE_TYPE_ID_FN(metadataOnPasteId, waterui_metadata_on_paste_id)
+DEFINE_TYPE_ID_FN(metadataOnPointerId, waterui_metadata_on_pointer_id)
+DEFINE_TYPE_ID_FN(metadataOnSizeChangeId, waterui_metadata_on_size_change_id)
+DEFINE_TYPE_ID_FN(metadataRefreshableId, waterui_metadata_refreshable_id)
 DEFINE_TYPE_ID_FN(metadataCursorId, waterui_metadata_cursor_id)
 DEFINE_TYPE_ID_FN(metadataForegroundId, waterui_metadata_foreground_id)
 DEFINE_TYPE_ID_FN(metadataShadowId, waterui_metadata_shadow_id)
+DEFINE_TYPE_ID_FN(metadataOverlayId, waterui_metadata_overlay_id)
+DEFINE_TYPE_ID_FN(metadataViewIdId, waterui_metadata_view_id_id)
+DEFINE_TYPE_ID_FN(metadataBackgroundViewId,
+                  waterui_metadata_background_view_id)
 DEFINE_TYPE_ID_FN(metadataFocusedId, waterui_metadata_focused_id)
 DEFINE_TYPE_ID_FN(metadataIgnoreSafeAreaId,
                   waterui_metadata_ignore_safe_area_id)
@@ -2363,6 +2638,144 @@ Java_dev_waterui_android_ffi_WatcherJni_envInstallMediaPickerManager(
   auto *env = jlong_to_ptr<WuiEnv>(envPtr);
   g_sym.waterui_env_install_media_picker_manager(
       env, waterui_present_media_picker, waterui_load_media);
+  g_sym.waterui_env_install_camera_capture(env, waterui_capture_media);
+}
+
+JNIEXPORT void JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_envInstallPermissions(JNIEnv *, jclass,
+                                                              jlong envPtr) {
+  g_sym.waterui_env_install_permissions(jlong_to_ptr<WuiEnv>(envPtr),
+                                        waterui_permission_status,
+                                        waterui_request_permission);
+}
+
+JNIEXPORT void JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_envInstallClipboard(JNIEnv *, jclass,
+                                                            jlong envPtr) {
+  auto *env = jlong_to_ptr<WuiEnv>(envPtr);
+  g_sym.waterui_env_install_clipboard(env, waterui_clipboard_write_text,
+                                      waterui_clipboard_read_text,
+                                      waterui_clipboard_write_image);
+}
+
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_envInstallAssets(
+    JNIEnv *env, jclass, jlong envPtr, jstring baseUrl) {
+  const char *baseUrlCStr = env->GetStringUTFChars(baseUrl, nullptr);
+  g_sym.waterui_env_install_assets(jlong_to_ptr<WuiEnv>(envPtr), baseUrlCStr,
+                                   waterui_asset_read);
+  env->ReleaseStringUTFChars(baseUrl, baseUrlCStr);
+}
+
+JNIEXPORT void JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_envInstallSettingsStorage(JNIEnv *,
+                                                                  jclass,
+                                                                  jlong envPtr) {
+  g_sym.waterui_env_install_settings_storage(
+      jlong_to_ptr<WuiEnv>(envPtr), waterui_settings_read,
+      waterui_settings_write, waterui_settings_remove);
+}
+
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_envInstallFeedback(
+    JNIEnv *, jclass, jlong envPtr) {
+  g_sym.waterui_env_install_feedback(
+      jlong_to_ptr<WuiEnv>(envPtr), waterui_feedback_impact,
+      waterui_feedback_selection, waterui_feedback_play_sound);
+}
+
+JNIEXPORT void JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_envInstallNotifications(JNIEnv *, jclass,
+                                                                jlong envPtr) {
+  g_sym.waterui_env_install_notifications(jlong_to_ptr<WuiEnv>(envPtr),
+                                          waterui_notifications_schedule,
+                                          waterui_notifications_cancel);
+}
+
+JNIEXPORT void JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_envDeliverNotificationResponse(
+    JNIEnv *env, jclass, jlong envPtr, jstring notificationId,
+    jstring actionId) {
+  const char *idCStr = env->GetStringUTFChars(notificationId, nullptr);
+  const char *actionCStr =
+      actionId != nullptr ? env->GetStringUTFChars(actionId, nullptr) : nullptr;
+  g_sym.waterui_env_deliver_notification_response(jlong_to_ptr<WuiEnv>(envPtr),
+                                                  idCStr, actionCStr);
+  if (actionCStr != nullptr) {
+    env->ReleaseStringUTFChars(actionId, actionCStr);
+  }
+  env->ReleaseStringUTFChars(notificationId, idCStr);
+}
+
+JNIEXPORT void JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_envInstallBackNavigation(JNIEnv *,
+                                                                 jclass,
+                                                                 jlong envPtr) {
+  g_sym.waterui_env_install_back_navigation(jlong_to_ptr<WuiEnv>(envPtr),
+                                            waterui_back_navigation_set_enabled);
+}
+
+JNIEXPORT void JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_envDeliverBackEvent(JNIEnv *, jclass,
+                                                            jlong envPtr,
+                                                            jint phase,
+                                                            jfloat progress) {
+  g_sym.waterui_env_deliver_back_event(jlong_to_ptr<WuiEnv>(envPtr),
+                                       static_cast<WuiBackPhase>(phase),
+                                       progress);
+}
+
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_envInstallLocale(
+    JNIEnv *env, jclass, jlong envPtr, jstring languageTag) {
+  const char *tagCStr = env->GetStringUTFChars(languageTag, nullptr);
+  g_sym.waterui_env_install_locale_string(jlong_to_ptr<WuiEnv>(envPtr),
+                                          tagCStr);
+  env->ReleaseStringUTFChars(languageTag, tagCStr);
+}
+
+JNIEXPORT void JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_envSetSafeAreaInsets(
+    JNIEnv *, jclass, jlong envPtr, jfloat top, jfloat bottom, jfloat leading,
+    jfloat trailing, jfloat keyboard) {
+  WuiSafeAreaInsets insets{top, bottom, leading, trailing};
+  g_sym.waterui_env_set_safe_area_insets(jlong_to_ptr<WuiEnv>(envPtr), insets,
+                                         keyboard);
+}
+
+JNIEXPORT void JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_envSetSizeClasses(JNIEnv *, jclass,
+                                                          jlong envPtr,
+                                                          jint horizontal,
+                                                          jint vertical) {
+  g_sym.waterui_env_set_size_classes(jlong_to_ptr<WuiEnv>(envPtr),
+                                     static_cast<WuiSizeClass>(horizontal),
+                                     static_cast<WuiSizeClass>(vertical));
+}
+
+JNIEXPORT void JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_envSetDynamicTypeScale(JNIEnv *, jclass,
+                                                               jlong envPtr,
+                                                               jfloat scale) {
+  g_sym.waterui_env_set_dynamic_type_scale(jlong_to_ptr<WuiEnv>(envPtr), scale);
+}
+
+JNIEXPORT void JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_envSetAppPhase(JNIEnv *, jclass,
+                                                       jlong envPtr,
+                                                       jint phase) {
+  g_sym.waterui_env_set_app_phase(jlong_to_ptr<WuiEnv>(envPtr),
+                                  static_cast<WuiAppPhase>(phase));
+}
+
+JNIEXPORT void JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_envRefreshPermissions(JNIEnv *, jclass,
+                                                              jlong envPtr) {
+  g_sym.waterui_env_refresh_permissions(jlong_to_ptr<WuiEnv>(envPtr));
+}
+
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_envOpenUrl(
+    JNIEnv *env, jclass, jlong envPtr, jstring url) {
+  const char *urlCStr = env->GetStringUTFChars(url, nullptr);
+  g_sym.waterui_env_open_url(jlong_to_ptr<WuiEnv>(envPtr), urlCStr);
+  env->ReleaseStringUTFChars(url, urlCStr);
 }
 
 JNIEXPORT void JNICALL
@@ -2399,35 +2812,39 @@ JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_dropAnyview(
   g_sym.waterui_drop_anyview(jlong_to_ptr<WuiAnyView>(viewPtr));
 }
 
-JNIEXPORT void JNICALL
-Java_dev_waterui_android_ffi_WatcherJni_configureHotReloadEndpoint(JNIEnv *env,
-                                                                   jclass,
-                                                                   jstring host,
-                                                                   jint port) {
-  // TODO: Hot reload not yet implemented for Android FFI
-  (void)env;
-  (void)host;
-  (void)port;
-  __android_log_print(ANDROID_LOG_WARN, LOG_TAG,
-                      "Hot reload endpoint configuration not yet implemented");
-}
+static bool initHotReloadBridgeJni(JNIEnv *env);
 
 JNIEXPORT void JNICALL
-Java_dev_waterui_android_ffi_WatcherJni_configureHotReloadDirectory(
-    JNIEnv *env, jclass, jstring path) {
-  // TODO: Hot reload not yet implemented for Android FFI
-  (void)env;
-  (void)path;
-  __android_log_print(ANDROID_LOG_WARN, LOG_TAG,
-                      "Hot reload directory configuration not yet implemented");
+Java_dev_waterui_android_ffi_WatcherJni_configureHotReloadEndpoint(
+    JNIEnv *env, jclass, jstring host, jint port, jstring directory) {
+  // Resolve the bridge here; the rebuild callback runs on a Rust thread
+  if (!initHotReloadBridgeJni(env)) {
+    return;
+  }
+  const char *hostCStr = env->GetStringUTFChars(host, nullptr);
+  const char *directoryCStr = env->GetStringUTFChars(directory, nullptr);
+  g_sym.waterui_reload_connect(hostCStr, static_cast<uint16_t>(port),
+                               directoryCStr, waterui_hot_reload_library);
+  env->ReleaseStringUTFChars(directory, directoryCStr);
+  env->ReleaseStringUTFChars(host, hostCStr);
 }
 
 // ========== Force-As Functions ==========
 
-JNIEXPORT jlong JNICALL Java_dev_waterui_android_ffi_WatcherJni_forceAsText(
-    JNIEnv *, jclass, jlong viewPtr) {
+JNIEXPORT jobject JNICALL Java_dev_waterui_android_ffi_WatcherJni_forceAsText(
+    JNIEnv *env, jclass, jlong viewPtr) {
   auto text = g_sym.waterui_force_as_text(jlong_to_ptr<WuiAnyView>(viewPtr));
-  return ptr_to_jlong(text.content);
+  jclass cls = find_app_class(env, "dev/waterui/android/runtime/TextStruct");
+  jmethodID ctor = env->GetMethodID(cls, "<init>", "(JIIIFFZ)V");
+  jobject obj = env->NewObject(
+      cls, ctor, ptr_to_jlong(text.content),
+      static_cast<jint>(text.line_limit),
+      static_cast<jint>(text.truncation_mode),
+      static_cast<jint>(text.multiline_alignment),
+      static_cast<jfloat>(text.line_spacing), static_cast<jfloat>(text.kerning),
+      static_cast<jboolean>(text.monospaced_digits));
+  env->DeleteLocalRef(cls);
+  return obj;
 }
 
 JNIEXPORT jobject JNICALL Java_dev_waterui_android_ffi_WatcherJni_forceAsButton(
@@ -2456,10 +2873,11 @@ Java_dev_waterui_android_ffi_WatcherJni_forceAsTextField(JNIEnv *env, jclass,
       g_sym.waterui_force_as_text_field(jlong_to_ptr<WuiAnyView>(viewPtr));
   jclass cls =
       find_app_class(env, "dev/waterui/android/runtime/TextFieldStruct");
-  jmethodID ctor = env->GetMethodID(cls, "<init>", "(JJJI)V");
+  jmethodID ctor = env->GetMethodID(cls, "<init>", "(JJJIJ)V");
   jobject obj = env->NewObject(
       cls, ctor, ptr_to_jlong(field.label), ptr_to_jlong(field.value),
-      ptr_to_jlong(field.prompt.content), static_cast<jint>(field.keyboard));
+      ptr_to_jlong(field.prompt.content), static_cast<jint>(field.keyboard),
+      ptr_to_jlong(field.on_submit));
   env->DeleteLocalRef(cls);
   return obj;
 }
@@ -2594,11 +3012,12 @@ Java_dev_waterui_android_ffi_WatcherJni_forceAsProgress(JNIEnv *env, jclass,
       g_sym.waterui_force_as_progress(jlong_to_ptr<WuiAnyView>(viewPtr));
   jclass cls =
       find_app_class(env, "dev/waterui/android/runtime/ProgressStruct");
-  jmethodID ctor = env->GetMethodID(cls, "<init>", "(JJJI)V");
+  jmethodID ctor = env->GetMethodID(cls, "<init>", "(JJJIJ)V");
   jobject obj = env->NewObject(cls, ctor, ptr_to_jlong(progress.label),
                                ptr_to_jlong(progress.value_label),
                                ptr_to_jlong(progress.value),
-                               static_cast<jint>(progress.style));
+                               static_cast<jint>(progress.style),
+                               ptr_to_jlong(progress.tint));
   env->DeleteLocalRef(cls);
   return obj;
 }
@@ -2609,13 +3028,92 @@ Java_dev_waterui_android_ffi_WatcherJni_forceAsScrollView(JNIEnv *env, jclass,
   auto scroll =
       g_sym.waterui_force_as_scroll_view(jlong_to_ptr<WuiAnyView>(viewPtr));
   jclass cls = find_app_class(env, "dev/waterui/android/runtime/ScrollStruct");
-  jmethodID ctor = env->GetMethodID(cls, "<init>", "(IJ)V");
+  jmethodID ctor = env->GetMethodID(cls, "<init>", "(IJJZZ)V");
   jobject obj = env->NewObject(cls, ctor, static_cast<jint>(scroll.axis),
-                               ptr_to_jlong(scroll.content));
+                               ptr_to_jlong(scroll.content),
+                               ptr_to_jlong(scroll.proxy),
+                               static_cast<jboolean>(scroll.paging),
+                               static_cast<jboolean>(scroll.lock_axis));
   env->DeleteLocalRef(cls);
   return obj;
 }
 
+// ========== Scroll Proxy Functions ==========
+
+struct ScrollSinkState {
+  jobject sink;
+  jmethodID scroll_to_offset;
+  jmethodID scroll_to_id;
+};
+
+static void scroll_sink_to_offset(void *data, WuiPoint offset, bool animated) {
+  ScopedEnv scoped;
+  if (scoped.env == nullptr)
+    return;
+  auto *state = static_cast<ScrollSinkState *>(data);
+  scoped.env->CallVoidMethod(state->sink, state->scroll_to_offset,
+                             static_cast<jfloat>(offset.x),
+                             static_cast<jfloat>(offset.y),
+                             static_cast<jboolean>(animated));
+  clear_jni_exception(scoped.env, "calling ScrollCommandSink.scrollToOffset");
+}
+
+static void scroll_sink_to_id(void *data, WuiId id, WuiAlignment anchor,
+                              bool animated) {
+  ScopedEnv scoped;
+  if (scoped.env == nullptr)
+    return;
+  auto *state = static_cast<ScrollSinkState *>(data);
+  scoped.env->CallVoidMethod(state->sink, state->scroll_to_id,
+                             static_cast<jint>(id.inner),
+                             static_cast<jint>(anchor),
+                             static_cast<jboolean>(animated));
+  clear_jni_exception(scoped.env, "calling ScrollCommandSink.scrollToId");
+}
+
+static void scroll_sink_drop(void *data) {
+  auto *state = static_cast<ScrollSinkState *>(data);
+  ScopedEnv scoped;
+  if (scoped.env != nullptr) {
+    scoped.env->DeleteGlobalRef(state->sink);
+  }
+  delete state;
+}
+
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_scrollProxyAttach(
+    JNIEnv *env, jclass, jlong proxyPtr, jobject sink) {
+  jclass cls = env->GetObjectClass(sink);
+  auto *state = new ScrollSinkState();
+  state->sink = env->NewGlobalRef(sink);
+  state->scroll_to_offset = env->GetMethodID(cls, "scrollToOffset", "(FFZ)V");
+  state->scroll_to_id = env->GetMethodID(cls, "scrollToId", "(IIZ)V");
+  env->DeleteLocalRef(cls);
+
+  WuiScrollCommandSink commandSink{};
+  commandSink.data = state;
+  commandSink.scroll_to_offset = scroll_sink_to_offset;
+  commandSink.scroll_to_id = scroll_sink_to_id;
+  commandSink.drop = scroll_sink_drop;
+  g_sym.waterui_scroll_proxy_attach(jlong_to_ptr<WuiScrollProxy>(proxyPtr),
+                                    commandSink);
+}
+
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_scrollProxyReport(
+    JNIEnv *, jclass, jlong proxyPtr, jfloat offsetX, jfloat offsetY,
+    jfloat contentWidth, jfloat contentHeight, jfloat viewportWidth,
+    jfloat viewportHeight) {
+  WuiPoint offset{offsetX, offsetY};
+  WuiSize contentSize{contentWidth, contentHeight};
+  WuiSize viewportSize{viewportWidth, viewportHeight};
+  g_sym.waterui_scroll_proxy_report(jlong_to_ptr<WuiScrollProxy>(proxyPtr),
+                                    offset, contentSize, viewportSize);
+}
+
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_dropScrollProxy(
+    JNIEnv *, jclass, jlong proxyPtr) {
+  g_sym.waterui_drop_scroll_proxy(jlong_to_ptr<WuiScrollProxy>(proxyPtr));
+}
+
 JNIEXPORT jobject JNICALL Java_dev_waterui_android_ffi_WatcherJni_forceAsPicker(
     JNIEnv *env, jclass, jlong viewPtr) {
   auto picker =
@@ -2827,6 +3325,63 @@ Java_dev_waterui_android_ffi_WatcherJni_forceAsMetadataOnEvent(JNIEnv *env,
   return obj;
 }
 
+JNIEXPORT jobject JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_forceAsMetadataOnPaste(JNIEnv *env,
+                                                               jclass,
+                                                               jlong viewPtr) {
+  auto metadata = g_sym.waterui_force_as_metadata_on_paste(
+      jlong_to_ptr<WuiAnyView>(viewPtr));
+  jclass cls =
+      find_app_class(env, "dev/waterui/android/runtime/MetadataOnPasteStruct");
+  jmethodID ctor = env->GetMethodID(cls, "<init>", "(JJ)V");
+  jobject obj = env->NewObject(cls, ctor, ptr_to_jlong(metadata.content),
+                               ptr_to_jlong(metadata.value.handler));
+  env->DeleteLocalRef(cls);
+  return obj;
+}
+
+JNIEXPORT jobject JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_forceAsMetadataOnPointer(
+    JNIEnv *env, jclass, jlong viewPtr) {
+  auto metadata = g_sym.waterui_force_as_metadata_on_pointer(
+      jlong_to_ptr<WuiAnyView>(viewPtr));
+  jclass cls = find_app_class(
+      env, "dev/waterui/android/runtime/MetadataOnPointerStruct");
+  jmethodID ctor = env->GetMethodID(cls, "<init>", "(JJ)V");
+  jobject obj = env->NewObject(cls, ctor, ptr_to_jlong(metadata.content),
+                               ptr_to_jlong(metadata.value.handler));
+  env->DeleteLocalRef(cls);
+  return obj;
+}
+
+JNIEXPORT jobject JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_forceAsMetadataOnSizeChange(
+    JNIEnv *env, jclass, jlong viewPtr) {
+  auto metadata = g_sym.waterui_force_as_metadata_on_size_change(
+      jlong_to_ptr<WuiAnyView>(viewPtr));
+  jclass cls = find_app_class(
+      env, "dev/waterui/android/runtime/MetadataOnSizeChangeStruct");
+  jmethodID ctor = env->GetMethodID(cls, "<init>", "(JJ)V");
+  jobject obj = env->NewObject(cls, ctor, ptr_to_jlong(metadata.content),
+                               ptr_to_jlong(metadata.value.handler));
+  env->DeleteLocalRef(cls);
+  return obj;
+}
+
+JNIEXPORT jobject JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_forceAsMetadataRefreshable(
+    JNIEnv *env, jclass, jlong viewPtr) {
+  auto metadata = g_sym.waterui_force_as_metadata_refreshable(
+      jlong_to_ptr<WuiAnyView>(viewPtr));
+  jclass cls = find_app_class(
+      env, "dev/waterui/android/runtime/MetadataRefreshableStruct");
+  jmethodID ctor = env->GetMethodID(cls, "<init>", "(JJ)V");
+  jobject obj = env->NewObject(cls, ctor, ptr_to_jlong(metadata.content),
+                               ptr_to_jlong(metadata.value.handler));
+  env->DeleteLocalRef(cls);
+  return obj;
+}
+
 JNIEXPORT jobject JNICALL
 Java_dev_waterui_android_ffi_WatcherJni_forceAsMetadataCursor(JNIEnv *env,
                                                               jclass,
@@ -2859,6 +3414,50 @@ Java_dev_waterui_android_ffi_WatcherJni_forceAsMetadataShadow(JNIEnv *env,
   return obj;
 }
 
+static jobject new_metadata_layer_struct(JNIEnv *env, WuiAnyView *content,
+                                         WuiLayer layer) {
+  jclass cls =
+      find_app_class(env, "dev/waterui/android/runtime/MetadataLayerStruct");
+  jmethodID ctor = env->GetMethodID(cls, "<init>", "(JJI)V");
+  jobject obj = env->NewObject(cls, ctor, ptr_to_jlong(content),
+                               ptr_to_jlong(layer.view),
+                               static_cast<jint>(layer.alignment));
+  env->DeleteLocalRef(cls);
+  return obj;
+}
+
+JNIEXPORT jobject JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_forceAsMetadataOverlay(JNIEnv *env,
+                                                               jclass,
+                                                               jlong viewPtr) {
+  auto metadata = g_sym.waterui_force_as_metadata_overlay(
+      jlong_to_ptr<WuiAnyView>(viewPtr));
+  return new_metadata_layer_struct(env, metadata.content, metadata.value);
+}
+
+JNIEXPORT jobject JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_forceAsMetadataBackgroundView(
+    JNIEnv *env, jclass, jlong viewPtr) {
+  auto metadata = g_sym.waterui_force_as_metadata_background_view(
+      jlong_to_ptr<WuiAnyView>(viewPtr));
+  return new_metadata_layer_struct(env, metadata.content, metadata.value);
+}
+
+JNIEXPORT jobject JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_forceAsMetadataViewId(JNIEnv *env,
+                                                              jclass,
+                                                              jlong viewPtr) {
+  auto metadata = g_sym.waterui_force_as_metadata_view_id(
+      jlong_to_ptr<WuiAnyView>(viewPtr));
+  jclass cls =
+      find_app_class(env, "dev/waterui/android/runtime/MetadataViewIdStruct");
+  jmethodID ctor = env->GetMethodID(cls, "<init>", "(JI)V");
+  jobject obj = env->NewObject(cls, ctor, ptr_to_jlong(metadata.content),
+                               static_cast<jint>(metadata.value.id.inner));
+  env->DeleteLocalRef(cls);
+  return obj;
+}
+
 JNIEXPORT jobject JNICALL
 Java_dev_waterui_android_ffi_WatcherJni_forceAsMetadataBorder(JNIEnv *env,
                                                               jclass,
@@ -3208,15 +3807,16 @@ Java_dev_waterui_android_ffi_WatcherJni_readComputedMenuItems(
   // Create MenuItemStruct class and array
   jclass itemCls =
       find_app_class(env, "dev/waterui/android/runtime/MenuItemStruct");
-  jmethodID itemCtor = env->GetMethodID(itemCls, "<init>", "(JJ)V");
+  jmethodID itemCtor = env->GetMethodID(itemCls, "<init>", "(JJIZJ)V");
   jobjectArray itemArray =
       env->NewObjectArray(static_cast<jsize>(slice.len), itemCls, nullptr);
 
   for (size_t i = 0; i < slice.len; i++) {
     WuiMenuItem item = slice.head[i];
-    jobject itemObj =
-        env->NewObject(itemCls, itemCtor, ptr_to_jlong(item.label.content),
-                       ptr_to_jlong(item.action));
+    jobject itemObj = env->NewObject(
+        itemCls, itemCtor, ptr_to_jlong(item.label.content),
+        ptr_to_jlong(item.action), static_cast<jint>(item.kind),
+        static_cast<jboolean>(item.checked), ptr_to_jlong(item.submenu));
     env->SetObjectArrayElement(itemArray, static_cast<jsize>(i), itemObj);
     env->DeleteLocalRef(itemObj);
   }
@@ -3272,6 +3872,83 @@ JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_dropOnEvent(
   g_sym.waterui_drop_on_event(jlong_to_ptr<WuiOnEventHandler>(handlerPtr));
 }
 
+// ========== OnPaste Handler Functions ==========
+
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_callOnPaste(
+    JNIEnv *env, jclass, jlong handlerPtr, jlong envPtr, jstring text) {
+  const char *textCStr = env->GetStringUTFChars(text, nullptr);
+  g_sym.waterui_call_on_paste(jlong_to_ptr<WuiOnPasteHandler>(handlerPtr),
+                              jlong_to_ptr<WuiEnv>(envPtr), textCStr);
+  env->ReleaseStringUTFChars(text, textCStr);
+}
+
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_dropOnPaste(
+    JNIEnv *, jclass, jlong handlerPtr) {
+  g_sym.waterui_drop_on_paste(jlong_to_ptr<WuiOnPasteHandler>(handlerPtr));
+}
+
+// ========== OnPointer Handler Functions ==========
+
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_callOnPointer(
+    JNIEnv *, jclass, jlong handlerPtr, jint phase, jint kind, jint pointerId,
+    jfloat x, jfloat y, jfloat pressure) {
+  g_sym.waterui_call_on_pointer(jlong_to_ptr<WuiOnPointerHandler>(handlerPtr),
+                                static_cast<WuiPointerPhase>(phase),
+                                static_cast<WuiPointerKind>(kind),
+                                static_cast<uint32_t>(pointerId), x, y,
+                                pressure);
+}
+
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_dropOnPointer(
+    JNIEnv *, jclass, jlong handlerPtr) {
+  g_sym.waterui_drop_on_pointer(jlong_to_ptr<WuiOnPointerHandler>(handlerPtr));
+}
+
+// ========== OnSizeChange Handler Functions ==========
+
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_callOnSizeChange(
+    JNIEnv *, jclass, jlong handlerPtr, jfloat width, jfloat height) {
+  WuiSize size{width, height};
+  g_sym.waterui_call_on_size_change(
+      jlong_to_ptr<WuiOnSizeChangeHandler>(handlerPtr), size);
+}
+
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_dropOnSizeChange(
+    JNIEnv *, jclass, jlong handlerPtr) {
+  g_sym.waterui_drop_on_size_change(
+      jlong_to_ptr<WuiOnSizeChangeHandler>(handlerPtr));
+}
+
+// ========== Refreshable Handler Functions ==========
+
+// Completion state for a running refresh; holds the Kotlin Runnable to invoke
+static void refresh_completion_call(void *data) {
+  auto onDone = static_cast<jobject>(data);
+  ScopedEnv scoped;
+  if (scoped.env == nullptr)
+    return;
+  jclass cls = scoped.env->GetObjectClass(onDone);
+  jmethodID run = scoped.env->GetMethodID(cls, "run", "()V");
+  scoped.env->CallVoidMethod(onDone, run);
+  clear_jni_exception(scoped.env, "calling refresh completion");
+  scoped.env->DeleteLocalRef(cls);
+  scoped.env->DeleteGlobalRef(onDone);
+}
+
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_callRefreshable(
+    JNIEnv *env, jclass, jlong handlerPtr, jlong envPtr, jobject onDone) {
+  WuiRefreshCompletion completion{};
+  completion.data = env->NewGlobalRef(onDone);
+  completion.call = refresh_completion_call;
+  g_sym.waterui_call_refreshable(jlong_to_ptr<WuiRefreshHandler>(handlerPtr),
+                                 jlong_to_ptr<WuiEnv>(envPtr), completion);
+}
+
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_dropRefreshable(
+    JNIEnv *, jclass, jlong handlerPtr) {
+  g_sym.waterui_drop_refreshable(jlong_to_ptr<WuiRefreshHandler>(handlerPtr));
+}
+
 // ========== Cursor Style Computed Functions ==========
 
 JNIEXPORT jint JNICALL
@@ -3601,6 +4278,24 @@ JNIEXPORT jlong JNICALL Java_dev_waterui_android_ffi_WatcherJni_resolveFont(
                                                  jlong_to_ptr<WuiEnv>(envPtr)));
 }
 
+JNIEXPORT jbyteArray JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_fontRegistryLookup(
+    JNIEnv *env, jclass, jlong envPtr, jstring family, jint weight, jint width,
+    jboolean italic) {
+  WuiFontDescriptor descriptor{};
+  descriptor.family = str_from_jstring(env, family);
+  descriptor.weight = static_cast<WuiFontWeight>(weight);
+  descriptor.width = static_cast<WuiFontWidth>(width);
+  descriptor.italic = italic == JNI_TRUE;
+
+  WuiStr data{};
+  if (!g_sym.waterui_font_registry_lookup(jlong_to_ptr<WuiEnv>(envPtr),
+                                          descriptor, &data._0)) {
+    return nullptr;
+  }
+  return wui_str_to_byte_array(env, data);
+}
+
 JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_dropWatcherGuard(
     JNIEnv *, jclass, jlong guardPtr) {
   g_sym.waterui_drop_box_watcher_guard(jlong_to_ptr<WuiWatcherGuard>(guardPtr));
@@ -3771,14 +4466,46 @@ JNIEXPORT jobject JNICALL Java_dev_waterui_android_ffi_WatcherJni_forceAsPhoto(
     JNIEnv *env, jclass, jlong viewPtr) {
   auto photo = g_sym.waterui_force_as_photo(jlong_to_ptr<WuiAnyView>(viewPtr));
   jclass cls = find_app_class(env, "dev/waterui/android/runtime/PhotoStruct");
-  jmethodID ctor = env->GetMethodID(cls, "<init>", "(Ljava/lang/String;)V");
+  jmethodID ctor =
+      env->GetMethodID(cls, "<init>", "(Ljava/lang/String;JZIZFJ)V");
   jstring sourceStr = wui_str_to_jstring(env, photo.source);
-  jobject obj = env->NewObject(cls, ctor, sourceStr);
+  // The event callback is boxed so Kotlin can hold it until the view is disposed
+  auto *onEvent = new WuiFn_WuiPhotoEvent(photo.on_event);
+  jobject obj = env->NewObject(
+      cls, ctor, sourceStr, ptr_to_jlong(onEvent),
+      photo.resizable ? JNI_TRUE : JNI_FALSE,
+      static_cast<jint>(photo.content_mode),
+      photo.clipped ? JNI_TRUE : JNI_FALSE, photo.corner_radius,
+      ptr_to_jlong(photo.tint));
   env->DeleteLocalRef(cls);
   env->DeleteLocalRef(sourceStr);
   return obj;
 }
 
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_callPhotoEvent(
+    JNIEnv *env, jclass, jlong handlerPtr, jint eventType, jstring message) {
+  auto *onEvent = jlong_to_ptr<WuiFn_WuiPhotoEvent>(handlerPtr);
+  if (onEvent == nullptr || onEvent->call == nullptr) {
+    return;
+  }
+  WuiPhotoEvent event{};
+  event.event_type = static_cast<WuiPhotoEventType>(eventType);
+  event.error_message = str_from_jstring(env, message);
+  onEvent->call(onEvent->data, event);
+}
+
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_dropPhotoEvent(
+    JNIEnv *, jclass, jlong handlerPtr) {
+  auto *onEvent = jlong_to_ptr<WuiFn_WuiPhotoEvent>(handlerPtr);
+  if (onEvent == nullptr) {
+    return;
+  }
+  if (onEvent->drop != nullptr) {
+    onEvent->drop(onEvent->data);
+  }
+  delete onEvent;
+}
+
 // ========== Video (Raw) Functions ==========
 
 JNIEXPORT jobject JNICALL
@@ -3815,15 +4542,44 @@ Java_dev_waterui_android_ffi_WatcherJni_forceAsVideoPlayer(JNIEnv *env, jclass,
       g_sym.waterui_force_as_video_player(jlong_to_ptr<WuiAnyView>(viewPtr));
   jclass cls =
       find_app_class(env, "dev/waterui/android/runtime/VideoPlayerStruct");
-  jmethodID ctor = env->GetMethodID(cls, "<init>", "(JJIZ)V");
-  jobject obj = env->NewObject(cls, ctor, ptr_to_jlong(vp.source),
-                               ptr_to_jlong(vp.volume),
-                               static_cast<jint>(vp.aspect_ratio),
-                               static_cast<jboolean>(vp.show_controls));
+  jmethodID ctor = env->GetMethodID(cls, "<init>", "(JJIZJJJJZJ)V");
+  // The event callback is boxed so Kotlin can hold it until the view is disposed
+  auto *onEvent = new WuiFn_WuiVideoEvent(vp.on_event);
+  jobject obj = env->NewObject(
+      cls, ctor, ptr_to_jlong(vp.source), ptr_to_jlong(vp.volume),
+      static_cast<jint>(vp.aspect_ratio),
+      static_cast<jboolean>(vp.show_controls), ptr_to_jlong(vp.playing),
+      ptr_to_jlong(vp.current_time), ptr_to_jlong(vp.duration),
+      ptr_to_jlong(vp.rate), static_cast<jboolean>(vp.loops),
+      ptr_to_jlong(onEvent));
   env->DeleteLocalRef(cls);
   return obj;
 }
 
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_callVideoEvent(
+    JNIEnv *env, jclass, jlong handlerPtr, jint eventType, jstring message) {
+  auto *onEvent = jlong_to_ptr<WuiFn_WuiVideoEvent>(handlerPtr);
+  if (onEvent == nullptr || onEvent->call == nullptr) {
+    return;
+  }
+  WuiVideoEvent event{};
+  event.event_type = static_cast<WuiVideoEventType>(eventType);
+  event.error_message = str_from_jstring(env, message);
+  onEvent->call(onEvent->data, event);
+}
+
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_dropVideoEvent(
+    JNIEnv *, jclass, jlong handlerPtr) {
+  auto *onEvent = jlong_to_ptr<WuiFn_WuiVideoEvent>(handlerPtr);
+  if (onEvent == nullptr) {
+    return;
+  }
+  if (onEvent->drop != nullptr) {
+    onEvent->drop(onEvent->data);
+  }
+  delete onEvent;
+}
+
 // ========== WebView Functions ==========
 
 static void drop_wui_str(WuiStr value) { value._0.vtable.drop(value._0.data); }
@@ -4602,15 +5358,17 @@ Java_dev_waterui_android_ffi_WatcherJni_forceAsGpuSurface(JNIEnv *env, jclass,
       g_sym.waterui_force_as_gpu_surface(jlong_to_ptr<WuiAnyView>(viewPtr));
   jclass cls =
       find_app_class(env, "dev/waterui/android/runtime/GpuSurfaceStruct");
-  jmethodID ctor = env->GetMethodID(cls, "<init>", "(J)V");
-  jobject obj = env->NewObject(cls, ctor, ptr_to_jlong(gpuSurface.renderer));
+  jmethodID ctor = env->GetMethodID(cls, "<init>", "(JII)V");
+  jobject obj = env->NewObject(cls, ctor, ptr_to_jlong(gpuSurface.renderer),
+                               static_cast<jint>(gpuSurface.present_mode),
+                               static_cast<jint>(gpuSurface.sample_count));
   env->DeleteLocalRef(cls);
   return obj;
 }
 
 JNIEXPORT jlong JNICALL Java_dev_waterui_android_ffi_WatcherJni_gpuSurfaceInit(
-    JNIEnv *env, jclass, jlong rendererPtr, jobject javaSurface, jint width,
-    jint height) {
+    JNIEnv *env, jclass, jlong rendererPtr, jint presentMode, jint sampleCount,
+    jobject javaSurface, jint width, jint height) {
   if (javaSurface == nullptr || rendererPtr == 0) {
     return 0;
   }
@@ -4626,6 +5384,8 @@ JNIEXPORT jlong JNICALL Java_dev_waterui_android_ffi_WatcherJni_gpuSurfaceInit(
   // Create a temporary WuiGpuSurface struct to pass to init
   WuiGpuSurface surface{};
   surface.renderer = jlong_to_ptr<void>(rendererPtr);
+  surface.present_mode = static_cast<WuiPresentMode>(presentMode);
+  surface.sample_count = static_cast<uint32_t>(sampleCount);
   WuiGpuSurfaceState *state = g_sym.waterui_gpu_surface_init(
       &surface, nativeWindow, static_cast<uint32_t>(width),
       static_cast<uint32_t>(height));
@@ -4641,10 +5401,11 @@ JNIEXPORT jboolean JNICALL
 Java_dev_waterui_android_ffi_WatcherJni_gpuSurfaceRender(JNIEnv *, jclass,
                                                          jlong statePtr,
                                                          jint width,
-                                                         jint height) {
+                                                         jint height,
+                                                         jlong frameTimeNanos) {
   bool result = g_sym.waterui_gpu_surface_render(
       jlong_to_ptr<WuiGpuSurfaceState>(statePtr), static_cast<uint32_t>(width),
-      static_cast<uint32_t>(height));
+      static_cast<uint32_t>(height), static_cast<uint64_t>(frameTimeNanos));
   return result ? JNI_TRUE : JNI_FALSE;
 }
 
@@ -4653,6 +5414,27 @@ JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_gpuSurfaceDrop(
   g_sym.waterui_gpu_surface_drop(jlong_to_ptr<WuiGpuSurfaceState>(statePtr));
 }
 
+JNIEXPORT void JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_gpuSurfacePointer(
+    JNIEnv *, jclass, jlong statePtr, jint phase, jint kind, jint pointerId,
+    jfloat x, jfloat y, jfloat pressure) {
+  g_sym.waterui_gpu_surface_pointer(
+      jlong_to_ptr<WuiGpuSurfaceState>(statePtr),
+      static_cast<WuiPointerPhase>(phase), static_cast<WuiPointerKind>(kind),
+      static_cast<uint32_t>(pointerId), x, y, pressure);
+}
+
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_gpuSurfaceKey(
+    JNIEnv *env, jclass, jlong statePtr, jint phase, jstring key,
+    jint modifiers, jboolean repeat) {
+  const char *keyCStr = env->GetStringUTFChars(key, nullptr);
+  g_sym.waterui_gpu_surface_key(jlong_to_ptr<WuiGpuSurfaceState>(statePtr),
+                                static_cast<WuiKeyPhase>(phase), keyCStr,
+                                static_cast<uint32_t>(modifiers),
+                                repeat == JNI_TRUE);
+  env->ReleaseStringUTFChars(key, keyCStr);
+}
+
 // ========== List Functions ==========
 
 JNIEXPORT jobject JNICALL
@@ -4671,14 +5453,41 @@ JNIEXPORT jobject JNICALL Java_dev_waterui_android_ffi_WatcherJni_forceAsList(
     JNIEnv *env, jclass, jlong viewPtr) {
   WuiList list = g_sym.waterui_force_as_list(jlong_to_ptr<WuiAnyView>(viewPtr));
   jclass cls = find_app_class(env, "dev/waterui/android/runtime/ListStruct");
-  jmethodID ctor = env->GetMethodID(cls, "<init>", "(JJJJ)V");
+  jmethodID ctor = env->GetMethodID(cls, "<init>", "(JJJJIJ)V");
   jobject obj = env->NewObject(
       cls, ctor, ptr_to_jlong(list.contents), ptr_to_jlong(list.editing),
-      ptr_to_jlong(list.on_delete), ptr_to_jlong(list.on_move));
+      ptr_to_jlong(list.on_delete), ptr_to_jlong(list.on_move),
+      static_cast<jint>(list.separator), ptr_to_jlong(list.selection));
   env->DeleteLocalRef(cls);
   return obj;
 }
 
+// Converts swipe actions to SwipeActionStruct[]. The label and action pointers
+// are handed over to Kotlin; only the array buffer is released here.
+static jobjectArray swipe_actions_to_jarray(JNIEnv *env,
+                                            WuiArray_WuiSwipeAction actions) {
+  auto slice = actions.vtable.slice(actions.data);
+
+  jclass actionCls =
+      find_app_class(env, "dev/waterui/android/runtime/SwipeActionStruct");
+  jmethodID actionCtor = env->GetMethodID(actionCls, "<init>", "(JIJ)V");
+  jobjectArray actionArray =
+      env->NewObjectArray(static_cast<jsize>(slice.len), actionCls, nullptr);
+
+  for (size_t i = 0; i < slice.len; i++) {
+    WuiSwipeAction action = slice.head[i];
+    jobject actionObj = env->NewObject(
+        actionCls, actionCtor, ptr_to_jlong(action.label.content),
+        static_cast<jint>(action.role), ptr_to_jlong(action.action));
+    env->SetObjectArrayElement(actionArray, static_cast<jsize>(i), actionObj);
+    env->DeleteLocalRef(actionObj);
+  }
+
+  actions.vtable.drop(actions.data);
+  env->DeleteLocalRef(actionCls);
+  return actionArray;
+}
+
 JNIEXPORT jobject JNICALL
 Java_dev_waterui_android_ffi_WatcherJni_forceAsListItem(JNIEnv *env, jclass,
                                                         jlong viewPtr) {
@@ -4686,9 +5495,143 @@ Java_dev_waterui_android_ffi_WatcherJni_forceAsListItem(JNIEnv *env, jclass,
       g_sym.waterui_force_as_list_item(jlong_to_ptr<WuiAnyView>(viewPtr));
   jclass cls =
       find_app_class(env, "dev/waterui/android/runtime/ListItemStruct");
-  jmethodID ctor = env->GetMethodID(cls, "<init>", "(JJ)V");
+  jmethodID ctor = env->GetMethodID(
+      cls, "<init>",
+      "(JJ[Ldev/waterui/android/runtime/SwipeActionStruct;"
+      "[Ldev/waterui/android/runtime/SwipeActionStruct;)V");
+  jobjectArray leading = swipe_actions_to_jarray(env, item.leading_actions);
+  jobjectArray trailing = swipe_actions_to_jarray(env, item.trailing_actions);
   jobject obj = env->NewObject(cls, ctor, ptr_to_jlong(item.content),
-                               ptr_to_jlong(item.deletable));
+                               ptr_to_jlong(item.deletable), leading, trailing);
+  env->DeleteLocalRef(leading);
+  env->DeleteLocalRef(trailing);
+  env->DeleteLocalRef(cls);
+  return obj;
+}
+
+JNIEXPORT jobject JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_listSectionId(JNIEnv *env, jclass) {
+  auto id = g_sym.waterui_list_section_id();
+  return new_type_id_struct(env, id);
+}
+
+JNIEXPORT jobject JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_forceAsListSection(JNIEnv *env, jclass,
+                                                           jlong viewPtr) {
+  WuiListSection section =
+      g_sym.waterui_force_as_list_section(jlong_to_ptr<WuiAnyView>(viewPtr));
+  jclass cls =
+      find_app_class(env, "dev/waterui/android/runtime/ListSectionStruct");
+  jmethodID ctor = env->GetMethodID(cls, "<init>", "(JJJJJ)V");
+  jobject obj = env->NewObject(cls, ctor, ptr_to_jlong(section.header),
+                               ptr_to_jlong(section.footer),
+                               ptr_to_jlong(section.contents),
+                               ptr_to_jlong(section.on_delete),
+                               ptr_to_jlong(section.on_move));
+  env->DeleteLocalRef(cls);
+  return obj;
+}
+
+// ========== Lazy Stack Functions ==========
+
+JNIEXPORT jobject JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_lazyStackId(JNIEnv *env, jclass) {
+  auto id = g_sym.waterui_lazy_stack_id();
+  return new_type_id_struct(env, id);
+}
+
+JNIEXPORT jobject JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_forceAsLazyStack(JNIEnv *env, jclass,
+                                                         jlong viewPtr) {
+  WuiLazyStack stack =
+      g_sym.waterui_force_as_lazy_stack(jlong_to_ptr<WuiAnyView>(viewPtr));
+  jclass cls =
+      find_app_class(env, "dev/waterui/android/runtime/LazyStackStruct");
+  jmethodID ctor = env->GetMethodID(cls, "<init>", "(IFFJ)V");
+  jobject obj = env->NewObject(cls, ctor, static_cast<jint>(stack.axis),
+                               static_cast<jfloat>(stack.spacing),
+                               static_cast<jfloat>(stack.estimated_item_size),
+                               ptr_to_jlong(stack.contents));
+  env->DeleteLocalRef(cls);
+  return obj;
+}
+
+// ========== Geometry Reader Functions ==========
+
+JNIEXPORT jobject JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_geometryReaderId(JNIEnv *env, jclass) {
+  auto id = g_sym.waterui_geometry_reader_id();
+  return new_type_id_struct(env, id);
+}
+
+JNIEXPORT jobject JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_forceAsGeometryReader(JNIEnv *env,
+                                                              jclass,
+                                                              jlong viewPtr) {
+  WuiGeometryReader reader =
+      g_sym.waterui_force_as_geometry_reader(jlong_to_ptr<WuiAnyView>(viewPtr));
+  jclass cls =
+      find_app_class(env, "dev/waterui/android/runtime/GeometryReaderStruct");
+  jmethodID ctor = env->GetMethodID(cls, "<init>", "(JJ)V");
+  jobject obj = env->NewObject(cls, ctor, ptr_to_jlong(reader.proxy),
+                               ptr_to_jlong(reader.content));
+  env->DeleteLocalRef(cls);
+  return obj;
+}
+
+JNIEXPORT void JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_geometryProxyUpdate(
+    JNIEnv *, jclass, jlong proxyPtr, jfloat x, jfloat y, jfloat width,
+    jfloat height) {
+  WuiRect frame{{x, y}, {width, height}};
+  g_sym.waterui_geometry_proxy_update(jlong_to_ptr<WuiGeometryProxy>(proxyPtr),
+                                      frame);
+}
+
+JNIEXPORT void JNICALL Java_dev_waterui_android_ffi_WatcherJni_dropGeometryProxy(
+    JNIEnv *, jclass, jlong proxyPtr) {
+  g_sym.waterui_drop_geometry_proxy(jlong_to_ptr<WuiGeometryProxy>(proxyPtr));
+}
+
+// ========== Lazy Grid Functions ==========
+
+JNIEXPORT jobject JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_lazyGridId(JNIEnv *env, jclass) {
+  auto id = g_sym.waterui_lazy_grid_id();
+  return new_type_id_struct(env, id);
+}
+
+JNIEXPORT jobject JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_forceAsLazyGrid(JNIEnv *env, jclass,
+                                                        jlong viewPtr) {
+  WuiLazyGrid grid =
+      g_sym.waterui_force_as_lazy_grid(jlong_to_ptr<WuiAnyView>(viewPtr));
+
+  auto slice = grid.items.vtable.slice(grid.items.data);
+  jclass itemCls =
+      find_app_class(env, "dev/waterui/android/runtime/GridItemStruct");
+  jmethodID itemCtor = env->GetMethodID(itemCls, "<init>", "(IFFFI)V");
+  jobjectArray itemArray =
+      env->NewObjectArray(static_cast<jsize>(slice.len), itemCls, nullptr);
+  for (size_t i = 0; i < slice.len; i++) {
+    WuiGridItem item = slice.head[i];
+    jobject itemObj = env->NewObject(
+        itemCls, itemCtor, static_cast<jint>(item.size),
+        static_cast<jfloat>(item.minimum), static_cast<jfloat>(item.maximum),
+        static_cast<jfloat>(item.spacing), static_cast<jint>(item.alignment));
+    env->SetObjectArrayElement(itemArray, static_cast<jsize>(i), itemObj);
+    env->DeleteLocalRef(itemObj);
+  }
+  grid.items.vtable.drop(grid.items.data);
+  env->DeleteLocalRef(itemCls);
+
+  jclass cls = find_app_class(env, "dev/waterui/android/runtime/LazyGridStruct");
+  jmethodID ctor = env->GetMethodID(
+      cls, "<init>", "(I[Ldev/waterui/android/runtime/GridItemStruct;FJ)V");
+  jobject obj = env->NewObject(cls, ctor, static_cast<jint>(grid.axis),
+                               itemArray, static_cast<jfloat>(grid.spacing),
+                               ptr_to_jlong(grid.contents));
+  env->DeleteLocalRef(itemArray);
   env->DeleteLocalRef(cls);
   return obj;
 }
@@ -4746,6 +5689,7 @@ static bool initMediaLoaderJni(JNIEnv *env) {
 // MediaPickerManager JNI globals
 static jclass gMediaPickerManagerClass = nullptr;
 static jmethodID gMediaPickerPresentMethod = nullptr;
+static jmethodID gMediaPickerCameraMethod = nullptr;
 
 static bool initMediaPickerManagerJni(JNIEnv *env) {
   if (gMediaPickerManagerClass != nullptr) {
@@ -4771,6 +5715,15 @@ static bool initMediaPickerManagerJni(JNIEnv *env) {
     return false;
   }
 
+  gMediaPickerCameraMethod = env->GetStaticMethodID(gMediaPickerManagerClass,
+                                                    "presentCamera", "(IJJ)V");
+  if (gMediaPickerCameraMethod == nullptr) {
+    __android_log_print(
+        ANDROID_LOG_ERROR, LOG_TAG,
+        "Failed to find MediaPickerManager.presentCamera method");
+    return false;
+  }
+
   return true;
 }
 
@@ -4799,6 +5752,31 @@ void waterui_present_media_picker(WuiMediaFilterType filter,
                             reinterpret_cast<jlong>(callback.call));
 }
 
+// Capture a photo or video - calls into Kotlin MediaPickerManager
+void waterui_capture_media(WuiCaptureMode mode,
+                           MediaPickerPresentCallback callback) {
+  ScopedEnv scoped;
+  JNIEnv *env = scoped.env;
+  if (env == nullptr) {
+    __android_log_print(ANDROID_LOG_FATAL, LOG_TAG,
+                        "waterui_capture_media: failed to get JNIEnv");
+    std::abort();
+  }
+
+  if (!initMediaPickerManagerJni(env)) {
+    __android_log_print(
+        ANDROID_LOG_FATAL, LOG_TAG,
+        "waterui_capture_media: failed to init MediaPickerManager JNI");
+    std::abort();
+  }
+
+  // Call MediaPickerManager.presentCamera(mode, callbackData, callFnPtr)
+  env->CallStaticVoidMethod(gMediaPickerManagerClass, gMediaPickerCameraMethod,
+                            static_cast<jint>(mode),
+                            reinterpret_cast<jlong>(callback.data),
+                            reinterpret_cast<jlong>(callback.call));
+}
+
 /**
  * JNI function called by MediaPickerManager.kt when user selects media.
  * Invokes the Rust callback with the selected media ID.
@@ -4922,6 +5900,727 @@ Java_dev_waterui_android_runtime_NativeBindings_callOnSelection(
   callFn(data, selected);
 }
 
+// ========== Clipboard ==========
+
+// ClipboardBridge JNI globals
+static jclass gClipboardBridgeClass = nullptr;
+static jmethodID gClipboardWriteTextMethod = nullptr;
+static jmethodID gClipboardReadTextMethod = nullptr;
+static jmethodID gClipboardWriteImageMethod = nullptr;
+
+static bool initClipboardBridgeJni(JNIEnv *env) {
+  if (gClipboardBridgeClass != nullptr) {
+    return true; // Already initialized
+  }
+
+  jclass cls =
+      find_app_class(env, "dev/waterui/android/runtime/ClipboardBridge");
+  if (cls == nullptr) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "Failed to find ClipboardBridge class");
+    return false;
+  }
+
+  gClipboardWriteTextMethod =
+      env->GetStaticMethodID(cls, "writeText", "(Ljava/lang/String;)V");
+  gClipboardReadTextMethod = env->GetStaticMethodID(cls, "readText", "(JJ)V");
+  gClipboardWriteImageMethod =
+      env->GetStaticMethodID(cls, "writeImage", "([B)V");
+  if (gClipboardWriteTextMethod == nullptr ||
+      gClipboardReadTextMethod == nullptr ||
+      gClipboardWriteImageMethod == nullptr) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "Failed to find ClipboardBridge methods");
+    env->DeleteLocalRef(cls);
+    return false;
+  }
+
+  gClipboardBridgeClass = reinterpret_cast<jclass>(env->NewGlobalRef(cls));
+  env->DeleteLocalRef(cls);
+  return true;
+}
+
+static JNIEnv *clipboard_env(ScopedEnv &scoped, const char *caller) {
+  JNIEnv *env = scoped.env;
+  if (env == nullptr) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG, "%s: failed to get JNIEnv",
+                        caller);
+    return nullptr;
+  }
+  if (!initClipboardBridgeJni(env)) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "%s: failed to init ClipboardBridge JNI", caller);
+    return nullptr;
+  }
+  return env;
+}
+
+// Write text to the clipboard - calls into Kotlin ClipboardBridge
+void waterui_clipboard_write_text(const char *text) {
+  ScopedEnv scoped;
+  JNIEnv *env = clipboard_env(scoped, "waterui_clipboard_write_text");
+  if (env == nullptr) {
+    return;
+  }
+
+  jstring jtext = env->NewStringUTF(text != nullptr ? text : "");
+  env->CallStaticVoidMethod(gClipboardBridgeClass, gClipboardWriteTextMethod,
+                            jtext);
+  clear_jni_exception(env, "calling ClipboardBridge.writeText");
+  env->DeleteLocalRef(jtext);
+}
+
+// Read text from the clipboard - the result is delivered asynchronously via
+// ClipboardBridge.nativeCompleteReadText
+void waterui_clipboard_read_text(ClipboardReadCallback callback) {
+  ScopedEnv scoped;
+  JNIEnv *env = clipboard_env(scoped, "waterui_clipboard_read_text");
+  if (env == nullptr) {
+    callback.call(callback.data, nullptr);
+    return;
+  }
+
+  // Call ClipboardBridge.readText(callbackData, callFnPtr)
+  env->CallStaticVoidMethod(gClipboardBridgeClass, gClipboardReadTextMethod,
+                            reinterpret_cast<jlong>(callback.data),
+                            reinterpret_cast<jlong>(callback.call));
+  clear_jni_exception(env, "calling ClipboardBridge.readText");
+}
+
+// Write a PNG image to the clipboard - calls into Kotlin ClipboardBridge
+void waterui_clipboard_write_image(const uint8_t *data, uintptr_t len) {
+  ScopedEnv scoped;
+  JNIEnv *env = clipboard_env(scoped, "waterui_clipboard_write_image");
+  if (env == nullptr) {
+    return;
+  }
+
+  jbyteArray bytes = env->NewByteArray(static_cast<jsize>(len));
+  if (len > 0) {
+    env->SetByteArrayRegion(bytes, 0, static_cast<jsize>(len),
+                            reinterpret_cast<const jbyte *>(data));
+  }
+  env->CallStaticVoidMethod(gClipboardBridgeClass, gClipboardWriteImageMethod,
+                            bytes);
+  clear_jni_exception(env, "calling ClipboardBridge.writeImage");
+  env->DeleteLocalRef(bytes);
+}
+
+/**
+ * JNI function called by ClipboardBridge.kt once the clipboard has been read.
+ * Invokes the Rust callback with the clipboard text, or null if there is none.
+ */
+JNIEXPORT void JNICALL
+Java_dev_waterui_android_runtime_ClipboardBridge_nativeCompleteReadText(
+    JNIEnv *env, jclass, jlong callbackData, jlong callbackFn, jstring text) {
+  auto callFn = reinterpret_cast<void (*)(void *, const char *)>(callbackFn);
+  void *data = reinterpret_cast<void *>(callbackData);
+
+  if (text == nullptr) {
+    callFn(data, nullptr);
+    return;
+  }
+
+  const char *textCStr = env->GetStringUTFChars(text, nullptr);
+  callFn(data, textCStr);
+  env->ReleaseStringUTFChars(text, textCStr);
+}
+
+// ========== Assets ==========
+
+// AssetBridge JNI globals
+static jclass gAssetBridgeClass = nullptr;
+static jmethodID gAssetReadMethod = nullptr;
+
+static bool initAssetBridgeJni(JNIEnv *env) {
+  if (gAssetBridgeClass != nullptr) {
+    return true; // Already initialized
+  }
+
+  jclass cls = find_app_class(env, "dev/waterui/android/runtime/AssetBridge");
+  if (cls == nullptr) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "Failed to find AssetBridge class");
+    return false;
+  }
+
+  gAssetReadMethod =
+      env->GetStaticMethodID(cls, "read", "(Ljava/lang/String;)[B");
+  if (gAssetReadMethod == nullptr) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "Failed to find AssetBridge.read");
+    env->DeleteLocalRef(cls);
+    return false;
+  }
+
+  gAssetBridgeClass = reinterpret_cast<jclass>(env->NewGlobalRef(cls));
+  env->DeleteLocalRef(cls);
+  return true;
+}
+
+// Read a bundled asset - calls into Kotlin AssetBridge
+bool waterui_asset_read(const char *name, WuiArray_u8 *data) {
+  ScopedEnv scoped;
+  JNIEnv *env = scoped.env;
+  if (env == nullptr || !initAssetBridgeJni(env)) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "waterui_asset_read: AssetBridge unavailable");
+    return false;
+  }
+
+  jstring nameStr = env->NewStringUTF(name);
+  auto bytes = static_cast<jbyteArray>(
+      env->CallStaticObjectMethod(gAssetBridgeClass, gAssetReadMethod, nameStr));
+  env->DeleteLocalRef(nameStr);
+  if (env->ExceptionCheck()) {
+    clear_jni_exception(env, "calling AssetBridge.read");
+    return false;
+  }
+  if (bytes == nullptr) {
+    return false;
+  }
+
+  *data = str_from_byte_array(env, bytes)._0;
+  env->DeleteLocalRef(bytes);
+  return true;
+}
+
+// SettingsBridge JNI globals
+static jclass gSettingsBridgeClass = nullptr;
+static jmethodID gSettingsReadMethod = nullptr;
+static jmethodID gSettingsWriteMethod = nullptr;
+static jmethodID gSettingsRemoveMethod = nullptr;
+
+static bool initSettingsBridgeJni(JNIEnv *env) {
+  if (gSettingsBridgeClass != nullptr) {
+    return true; // Already initialized
+  }
+
+  jclass cls = find_app_class(env, "dev/waterui/android/runtime/SettingsBridge");
+  if (cls == nullptr) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "Failed to find SettingsBridge class");
+    return false;
+  }
+
+  gSettingsReadMethod =
+      env->GetStaticMethodID(cls, "read", "(Ljava/lang/String;)[B");
+  gSettingsWriteMethod =
+      env->GetStaticMethodID(cls, "write", "(Ljava/lang/String;[B)V");
+  gSettingsRemoveMethod =
+      env->GetStaticMethodID(cls, "remove", "(Ljava/lang/String;)V");
+  if (gSettingsReadMethod == nullptr || gSettingsWriteMethod == nullptr ||
+      gSettingsRemoveMethod == nullptr) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "Failed to find SettingsBridge methods");
+    env->DeleteLocalRef(cls);
+    return false;
+  }
+
+  gSettingsBridgeClass = reinterpret_cast<jclass>(env->NewGlobalRef(cls));
+  env->DeleteLocalRef(cls);
+  return true;
+}
+
+// Read a stored setting - calls into Kotlin SettingsBridge
+bool waterui_settings_read(const char *key, WuiArray_u8 *data) {
+  ScopedEnv scoped;
+  JNIEnv *env = scoped.env;
+  if (env == nullptr || !initSettingsBridgeJni(env)) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "waterui_settings_read: SettingsBridge unavailable");
+    return false;
+  }
+
+  jstring keyStr = env->NewStringUTF(key);
+  auto bytes = static_cast<jbyteArray>(env->CallStaticObjectMethod(
+      gSettingsBridgeClass, gSettingsReadMethod, keyStr));
+  env->DeleteLocalRef(keyStr);
+  if (env->ExceptionCheck()) {
+    clear_jni_exception(env, "calling SettingsBridge.read");
+    return false;
+  }
+  if (bytes == nullptr) {
+    return false;
+  }
+
+  *data = str_from_byte_array(env, bytes)._0;
+  env->DeleteLocalRef(bytes);
+  return true;
+}
+
+// Persist a setting - calls into Kotlin SettingsBridge
+void waterui_settings_write(const char *key, const uint8_t *data,
+                            uintptr_t len) {
+  ScopedEnv scoped;
+  JNIEnv *env = scoped.env;
+  if (env == nullptr || !initSettingsBridgeJni(env)) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "waterui_settings_write: SettingsBridge unavailable");
+    return;
+  }
+
+  jstring keyStr = env->NewStringUTF(key);
+  jbyteArray bytes = env->NewByteArray(static_cast<jsize>(len));
+  if (len > 0) {
+    env->SetByteArrayRegion(bytes, 0, static_cast<jsize>(len),
+                            reinterpret_cast<const jbyte *>(data));
+  }
+  env->CallStaticVoidMethod(gSettingsBridgeClass, gSettingsWriteMethod, keyStr,
+                            bytes);
+  clear_jni_exception(env, "calling SettingsBridge.write");
+  env->DeleteLocalRef(bytes);
+  env->DeleteLocalRef(keyStr);
+}
+
+// Remove a setting - calls into Kotlin SettingsBridge
+void waterui_settings_remove(const char *key) {
+  ScopedEnv scoped;
+  JNIEnv *env = scoped.env;
+  if (env == nullptr || !initSettingsBridgeJni(env)) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "waterui_settings_remove: SettingsBridge unavailable");
+    return;
+  }
+
+  jstring keyStr = env->NewStringUTF(key);
+  env->CallStaticVoidMethod(gSettingsBridgeClass, gSettingsRemoveMethod,
+                            keyStr);
+  clear_jni_exception(env, "calling SettingsBridge.remove");
+  env->DeleteLocalRef(keyStr);
+}
+
+// FeedbackBridge JNI globals
+static jclass gFeedbackBridgeClass = nullptr;
+static jmethodID gFeedbackImpactMethod = nullptr;
+static jmethodID gFeedbackSelectionMethod = nullptr;
+static jmethodID gFeedbackPlaySoundMethod = nullptr;
+
+static bool initFeedbackBridgeJni(JNIEnv *env) {
+  if (gFeedbackBridgeClass != nullptr) {
+    return true; // Already initialized
+  }
+
+  jclass cls = find_app_class(env, "dev/waterui/android/runtime/FeedbackBridge");
+  if (cls == nullptr) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "Failed to find FeedbackBridge class");
+    return false;
+  }
+
+  gFeedbackImpactMethod = env->GetStaticMethodID(cls, "impact", "(I)V");
+  gFeedbackSelectionMethod =
+      env->GetStaticMethodID(cls, "selectionChanged", "()V");
+  gFeedbackPlaySoundMethod =
+      env->GetStaticMethodID(cls, "playSound", "(Ljava/lang/String;)V");
+  if (gFeedbackImpactMethod == nullptr || gFeedbackSelectionMethod == nullptr ||
+      gFeedbackPlaySoundMethod == nullptr) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "Failed to find FeedbackBridge methods");
+    env->DeleteLocalRef(cls);
+    return false;
+  }
+
+  gFeedbackBridgeClass = reinterpret_cast<jclass>(env->NewGlobalRef(cls));
+  env->DeleteLocalRef(cls);
+  return true;
+}
+
+// Play an impact haptic - calls into Kotlin FeedbackBridge
+void waterui_feedback_impact(WuiImpactStyle style) {
+  ScopedEnv scoped;
+  JNIEnv *env = scoped.env;
+  if (env == nullptr || !initFeedbackBridgeJni(env)) {
+    return;
+  }
+
+  env->CallStaticVoidMethod(gFeedbackBridgeClass, gFeedbackImpactMethod,
+                            static_cast<jint>(style));
+  clear_jni_exception(env, "calling FeedbackBridge.impact");
+}
+
+// Play a selection-changed haptic - calls into Kotlin FeedbackBridge
+void waterui_feedback_selection(void) {
+  ScopedEnv scoped;
+  JNIEnv *env = scoped.env;
+  if (env == nullptr || !initFeedbackBridgeJni(env)) {
+    return;
+  }
+
+  env->CallStaticVoidMethod(gFeedbackBridgeClass, gFeedbackSelectionMethod);
+  clear_jni_exception(env, "calling FeedbackBridge.selectionChanged");
+}
+
+// Play a bundled sound effect - calls into Kotlin FeedbackBridge
+void waterui_feedback_play_sound(const char *asset) {
+  ScopedEnv scoped;
+  JNIEnv *env = scoped.env;
+  if (env == nullptr || !initFeedbackBridgeJni(env)) {
+    return;
+  }
+
+  jstring assetStr = env->NewStringUTF(asset);
+  env->CallStaticVoidMethod(gFeedbackBridgeClass, gFeedbackPlaySoundMethod,
+                            assetStr);
+  clear_jni_exception(env, "calling FeedbackBridge.playSound");
+  env->DeleteLocalRef(assetStr);
+}
+
+// NotificationsBridge JNI globals
+static jclass gNotificationsBridgeClass = nullptr;
+static jmethodID gNotificationsScheduleMethod = nullptr;
+static jmethodID gNotificationsCancelMethod = nullptr;
+
+static bool initNotificationsBridgeJni(JNIEnv *env) {
+  if (gNotificationsBridgeClass != nullptr) {
+    return true; // Already initialized
+  }
+
+  jclass cls =
+      find_app_class(env, "dev/waterui/android/runtime/NotificationsBridge");
+  if (cls == nullptr) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "Failed to find NotificationsBridge class");
+    return false;
+  }
+
+  gNotificationsScheduleMethod = env->GetStaticMethodID(
+      cls, "schedule",
+      "(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;J"
+      "[Ljava/lang/String;[Ljava/lang/String;)V");
+  gNotificationsCancelMethod =
+      env->GetStaticMethodID(cls, "cancel", "(Ljava/lang/String;)V");
+  if (gNotificationsScheduleMethod == nullptr ||
+      gNotificationsCancelMethod == nullptr) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "Failed to find NotificationsBridge methods");
+    env->DeleteLocalRef(cls);
+    return false;
+  }
+
+  gNotificationsBridgeClass = reinterpret_cast<jclass>(env->NewGlobalRef(cls));
+  env->DeleteLocalRef(cls);
+  return true;
+}
+
+// Schedule a local notification - calls into Kotlin NotificationsBridge
+void waterui_notifications_schedule(WuiNotificationRequest request) {
+  ScopedEnv scoped;
+  JNIEnv *env = scoped.env;
+  if (env == nullptr || !initNotificationsBridgeJni(env)) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "waterui_notifications_schedule: bridge unavailable");
+    return;
+  }
+
+  jstring id = wui_str_to_jstring(env, request.id);
+  jstring title = wui_str_to_jstring(env, request.title);
+  jstring body = wui_str_to_jstring(env, request.body);
+
+  auto slice = request.actions.vtable.slice(request.actions.data);
+  jclass stringCls = env->FindClass("java/lang/String");
+  jobjectArray actionIds =
+      env->NewObjectArray(static_cast<jsize>(slice.len), stringCls, nullptr);
+  jobjectArray actionTitles =
+      env->NewObjectArray(static_cast<jsize>(slice.len), stringCls, nullptr);
+  for (size_t i = 0; i < slice.len; i++) {
+    jstring actionId = wui_str_to_jstring(env, slice.head[i].id);
+    jstring actionTitle = wui_str_to_jstring(env, slice.head[i].title);
+    env->SetObjectArrayElement(actionIds, static_cast<jsize>(i), actionId);
+    env->SetObjectArrayElement(actionTitles, static_cast<jsize>(i), actionTitle);
+    env->DeleteLocalRef(actionId);
+    env->DeleteLocalRef(actionTitle);
+  }
+  request.actions.vtable.drop(request.actions.data);
+
+  env->CallStaticVoidMethod(gNotificationsBridgeClass,
+                            gNotificationsScheduleMethod, id, title, body,
+                            static_cast<jlong>(request.trigger_time_ms),
+                            actionIds, actionTitles);
+  clear_jni_exception(env, "calling NotificationsBridge.schedule");
+
+  env->DeleteLocalRef(actionTitles);
+  env->DeleteLocalRef(actionIds);
+  env->DeleteLocalRef(stringCls);
+  env->DeleteLocalRef(body);
+  env->DeleteLocalRef(title);
+  env->DeleteLocalRef(id);
+}
+
+// Cancel a local notification - calls into Kotlin NotificationsBridge
+void waterui_notifications_cancel(WuiStr id) {
+  ScopedEnv scoped;
+  JNIEnv *env = scoped.env;
+  if (env == nullptr || !initNotificationsBridgeJni(env)) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "waterui_notifications_cancel: bridge unavailable");
+    return;
+  }
+
+  jstring idStr = wui_str_to_jstring(env, id);
+  env->CallStaticVoidMethod(gNotificationsBridgeClass,
+                            gNotificationsCancelMethod, idStr);
+  clear_jni_exception(env, "calling NotificationsBridge.cancel");
+  env->DeleteLocalRef(idStr);
+}
+
+// BackNavigationBridge JNI globals
+static jclass gBackNavigationBridgeClass = nullptr;
+static jmethodID gBackNavigationSetEnabledMethod = nullptr;
+
+static bool initBackNavigationBridgeJni(JNIEnv *env) {
+  if (gBackNavigationBridgeClass != nullptr) {
+    return true; // Already initialized
+  }
+
+  jclass cls =
+      find_app_class(env, "dev/waterui/android/runtime/BackNavigationBridge");
+  if (cls == nullptr) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "Failed to find BackNavigationBridge class");
+    return false;
+  }
+
+  gBackNavigationSetEnabledMethod =
+      env->GetStaticMethodID(cls, "setEnabled", "(Z)V");
+  if (gBackNavigationSetEnabledMethod == nullptr) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "Failed to find BackNavigationBridge.setEnabled");
+    env->DeleteLocalRef(cls);
+    return false;
+  }
+
+  gBackNavigationBridgeClass = reinterpret_cast<jclass>(env->NewGlobalRef(cls));
+  env->DeleteLocalRef(cls);
+  return true;
+}
+
+// Enable or disable app back handling - calls into Kotlin BackNavigationBridge
+void waterui_back_navigation_set_enabled(bool enabled) {
+  ScopedEnv scoped;
+  JNIEnv *env = scoped.env;
+  if (env == nullptr || !initBackNavigationBridgeJni(env)) {
+    return;
+  }
+
+  env->CallStaticVoidMethod(gBackNavigationBridgeClass,
+                            gBackNavigationSetEnabledMethod,
+                            static_cast<jboolean>(enabled));
+  clear_jni_exception(env, "calling BackNavigationBridge.setEnabled");
+}
+
+// HotReload JNI globals
+static jclass gHotReloadClass = nullptr;
+static jmethodID gHotReloadOnRebuiltMethod = nullptr;
+
+static bool initHotReloadBridgeJni(JNIEnv *env) {
+  if (gHotReloadClass != nullptr) {
+    return true; // Already initialized
+  }
+
+  jclass cls = find_app_class(env, "dev/waterui/android/runtime/HotReload");
+  if (cls == nullptr) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "Failed to find HotReload class");
+    return false;
+  }
+
+  gHotReloadOnRebuiltMethod =
+      env->GetStaticMethodID(cls, "onRebuilt", "(Ljava/lang/String;)V");
+  if (gHotReloadOnRebuiltMethod == nullptr) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "Failed to find HotReload.onRebuilt");
+    env->DeleteLocalRef(cls);
+    return false;
+  }
+
+  gHotReloadClass = reinterpret_cast<jclass>(env->NewGlobalRef(cls));
+  env->DeleteLocalRef(cls);
+  return true;
+}
+
+// Swap in a library received over the live reload channel - calls into Kotlin
+// HotReload
+void waterui_hot_reload_library(const char *path) {
+  ScopedEnv scoped;
+  JNIEnv *env = scoped.env;
+  if (env == nullptr || !initHotReloadBridgeJni(env)) {
+    return;
+  }
+
+  jstring pathStr = env->NewStringUTF(path);
+  env->CallStaticVoidMethod(gHotReloadClass, gHotReloadOnRebuiltMethod,
+                            pathStr);
+  clear_jni_exception(env, "calling HotReload.onRebuilt");
+  env->DeleteLocalRef(pathStr);
+}
+
+// MainExecutorBridge JNI globals
+static jclass gMainExecutorBridgeClass = nullptr;
+static jmethodID gMainExecutorPostMethod = nullptr;
+
+static bool initMainExecutorBridgeJni(JNIEnv *env) {
+  if (gMainExecutorBridgeClass != nullptr) {
+    return true; // Already initialized
+  }
+
+  jclass cls =
+      find_app_class(env, "dev/waterui/android/runtime/MainExecutorBridge");
+  if (cls == nullptr) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "Failed to find MainExecutorBridge class");
+    return false;
+  }
+
+  gMainExecutorPostMethod = env->GetStaticMethodID(cls, "post", "(JJ)V");
+  if (gMainExecutorPostMethod == nullptr) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "Failed to find MainExecutorBridge.post");
+    env->DeleteLocalRef(cls);
+    return false;
+  }
+
+  gMainExecutorBridgeClass = reinterpret_cast<jclass>(env->NewGlobalRef(cls));
+  env->DeleteLocalRef(cls);
+  return true;
+}
+
+JNIEXPORT void JNICALL
+Java_dev_waterui_android_ffi_WatcherJni_envInstallMainExecutor(JNIEnv *env,
+                                                               jclass,
+                                                               jlong envPtr) {
+  initMainExecutorBridgeJni(env);
+  g_sym.waterui_env_install_main_executor(jlong_to_ptr<WuiEnv>(envPtr),
+                                          waterui_main_executor_post,
+                                          waterui_main_executor_is_main);
+}
+
+// Post a task to the main looper - calls into Kotlin MainExecutorBridge
+void waterui_main_executor_post(WuiMainTask task) {
+  ScopedEnv scoped;
+  JNIEnv *env = scoped.env;
+  if (env == nullptr || !initMainExecutorBridgeJni(env)) {
+    __android_log_print(ANDROID_LOG_FATAL, LOG_TAG,
+                        "waterui_main_executor_post: MainExecutorBridge "
+                        "unavailable");
+    std::abort();
+  }
+
+  // Call MainExecutorBridge.post(taskData, taskFn)
+  env->CallStaticVoidMethod(gMainExecutorBridgeClass, gMainExecutorPostMethod,
+                            reinterpret_cast<jlong>(task.data),
+                            reinterpret_cast<jlong>(task.call));
+}
+
+// The main thread of an Android process is its first thread, so its tid is the
+// pid. This holds no matter which thread installed the executor.
+bool waterui_main_executor_is_main() { return gettid() == getpid(); }
+
+/**
+ * JNI function called by MainExecutorBridge.kt on the main thread.
+ * Runs and consumes the posted Rust task.
+ */
+JNIEXPORT void JNICALL
+Java_dev_waterui_android_runtime_MainExecutorBridge_nativeRun(JNIEnv *, jclass,
+                                                              jlong taskData,
+                                                              jlong taskFn) {
+  auto callFn = reinterpret_cast<void (*)(void *)>(taskFn);
+  callFn(reinterpret_cast<void *>(taskData));
+}
+
+// PermissionsBridge JNI globals
+static jclass gPermissionsBridgeClass = nullptr;
+static jmethodID gPermissionStatusMethod = nullptr;
+static jmethodID gPermissionRequestMethod = nullptr;
+
+static bool initPermissionsBridgeJni(JNIEnv *env) {
+  if (gPermissionsBridgeClass != nullptr) {
+    return true; // Already initialized
+  }
+
+  jclass cls =
+      find_app_class(env, "dev/waterui/android/runtime/PermissionsBridge");
+  if (cls == nullptr) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "Failed to find PermissionsBridge class");
+    return false;
+  }
+
+  gPermissionStatusMethod = env->GetStaticMethodID(cls, "status", "(I)I");
+  gPermissionRequestMethod = env->GetStaticMethodID(cls, "request", "(IJJ)V");
+  if (gPermissionStatusMethod == nullptr ||
+      gPermissionRequestMethod == nullptr) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "Failed to find PermissionsBridge methods");
+    env->DeleteLocalRef(cls);
+    return false;
+  }
+
+  gPermissionsBridgeClass = reinterpret_cast<jclass>(env->NewGlobalRef(cls));
+  env->DeleteLocalRef(cls);
+  return true;
+}
+
+// Query a permission - calls into Kotlin PermissionsBridge
+WuiPermissionStatus waterui_permission_status(WuiPermission permission) {
+  ScopedEnv scoped;
+  JNIEnv *env = scoped.env;
+  if (env == nullptr || !initPermissionsBridgeJni(env)) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "waterui_permission_status: PermissionsBridge "
+                        "unavailable");
+    return WuiPermissionStatus_Denied;
+  }
+
+  jint status = env->CallStaticIntMethod(
+      gPermissionsBridgeClass, gPermissionStatusMethod,
+      static_cast<jint>(permission));
+  if (env->ExceptionCheck()) {
+    clear_jni_exception(env, "calling PermissionsBridge.status");
+    return WuiPermissionStatus_Denied;
+  }
+  return static_cast<WuiPermissionStatus>(status);
+}
+
+// Prompt for a permission - calls into Kotlin PermissionsBridge
+void waterui_request_permission(WuiPermission permission,
+                                PermissionRequestCallback callback) {
+  ScopedEnv scoped;
+  JNIEnv *env = scoped.env;
+  if (env == nullptr) {
+    __android_log_print(ANDROID_LOG_FATAL, LOG_TAG,
+                        "waterui_request_permission: failed to get JNIEnv");
+    std::abort();
+  }
+
+  if (!initPermissionsBridgeJni(env)) {
+    __android_log_print(ANDROID_LOG_ERROR, LOG_TAG,
+                        "waterui_request_permission: PermissionsBridge "
+                        "unavailable");
+    callback.call(callback.data, WuiPermissionStatus_Denied);
+    return;
+  }
+
+  // Call PermissionsBridge.request(permission, callbackData, callFnPtr)
+  env->CallStaticVoidMethod(gPermissionsBridgeClass, gPermissionRequestMethod,
+                            static_cast<jint>(permission),
+                            reinterpret_cast<jlong>(callback.data),
+                            reinterpret_cast<jlong>(callback.call));
+}
+
+/**
+ * JNI function called by PermissionsBridge.kt once the user has responded.
+ * Invokes the Rust callback with the resulting status.
+ */
+JNIEXPORT void JNICALL
+Java_dev_waterui_android_runtime_PermissionsBridge_nativeCompleteRequest(
+    JNIEnv *, jclass, jlong callbackData, jlong callbackFn, jint status) {
+  auto callFn =
+      reinterpret_cast<void (*)(void *, WuiPermissionStatus)>(callbackFn);
+  callFn(reinterpret_cast<void *>(callbackData),
+         static_cast<WuiPermissionStatus>(status));
+}
+
 // ========== Drag and Drop JNI Functions ==========
 
 JNIEXPORT jobject JNICALL
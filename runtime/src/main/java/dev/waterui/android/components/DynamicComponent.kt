@@ -4,20 +4,30 @@ import dev.waterui.android.layout.PassThroughFrameLayout
 import dev.waterui.android.reactive.WatcherStructFactory
 import dev.waterui.android.runtime.NativeBindings
 import dev.waterui.android.runtime.RegistryBuilder
+import dev.waterui.android.runtime.ViewStateSnapshot
 import dev.waterui.android.runtime.WuiRenderer
 import dev.waterui.android.runtime.WuiTypeId
 import dev.waterui.android.runtime.disposeWith
 import dev.waterui.android.runtime.inflateAnyView
 
-
 private val dynamicTypeId: WuiTypeId by lazy { NativeBindings.waterui_dynamic_id().toTypeId() }
 
+/**
+ * Dynamic component renderer.
+ *
+ * Replaces its child whenever the Rust side publishes a new view. Transient
+ * native state is carried over through [ViewStateSnapshot] for views under the
+ * same explicit `.id(...)` in the old and new tree.
+ */
 private val dynamicRenderer = WuiRenderer { context, node, env, registry ->
     val dynamic = NativeBindings.waterui_force_as_dynamic(node.rawPtr)
     val container = PassThroughFrameLayout(context)
 
     val watcher = WatcherStructFactory.anyView { pointer, _ ->
         container.post {
+            // Keep scroll, focus and selection for views under an id that survives the rebuild
+            val snapshot = container.getChildAt(0)?.let { ViewStateSnapshot.capture(it) }
+
             // Remove old views - their resources are cleaned up via disposeWith callbacks
             // when they're detached from the window
             container.removeAllViews()
@@ -27,6 +37,10 @@ private val dynamicRenderer = WuiRenderer { context, node, env, registry ->
                 // The Kotlin view tree now owns the resources, cleaned up via disposeWith.
                 val child = inflateAnyView(context, pointer, env, registry)
                 container.addView(child)
+                if (snapshot != null && !snapshot.isEmpty) {
+                    // Restore after the new content has been laid out
+                    child.post { snapshot.restore(child) }
+                }
             }
         }
     }
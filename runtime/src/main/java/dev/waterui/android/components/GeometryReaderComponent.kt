@@ -0,0 +1,114 @@
+package dev.waterui.android.components
+
+import android.content.Context
+import android.view.View
+import android.view.ViewTreeObserver
+import dev.waterui.android.layout.PassThroughFrameLayout
+import dev.waterui.android.runtime.NativeBindings
+import dev.waterui.android.runtime.RegistryBuilder
+import dev.waterui.android.runtime.WuiRenderer
+import dev.waterui.android.runtime.WuiTypeId
+import dev.waterui.android.runtime.disposeWith
+import dev.waterui.android.runtime.inflateAnyView
+
+private val geometryReaderTypeId: WuiTypeId by lazy {
+    NativeBindings.waterui_geometry_reader_id().toTypeId()
+}
+
+/**
+ * Fills the space proposed by its parent and places its only child at the
+ * top-leading corner, proposing it the full size.
+ */
+private class GeometryReaderLayout(context: Context) : PassThroughFrameLayout(context) {
+    override fun onMeasure(widthMeasureSpec: Int, heightMeasureSpec: Int) {
+        val child = if (childCount > 0) getChildAt(0) else null
+        child?.measure(
+            proposedSpec(widthMeasureSpec),
+            proposedSpec(heightMeasureSpec)
+        )
+        setMeasuredDimension(
+            resolveFill(widthMeasureSpec, child?.measuredWidth ?: 0),
+            resolveFill(heightMeasureSpec, child?.measuredHeight ?: 0)
+        )
+    }
+
+    override fun onLayout(changed: Boolean, left: Int, top: Int, right: Int, bottom: Int) {
+        val child = if (childCount > 0) getChildAt(0) else return
+        val x = if (layoutDirection == View.LAYOUT_DIRECTION_RTL) {
+            right - left - child.measuredWidth
+        } else 0
+        child.layout(x, 0, x + child.measuredWidth, child.measuredHeight)
+    }
+
+    private fun proposedSpec(spec: Int): Int =
+        if (MeasureSpec.getMode(spec) == MeasureSpec.UNSPECIFIED) {
+            spec
+        } else {
+            MeasureSpec.makeMeasureSpec(MeasureSpec.getSize(spec), MeasureSpec.AT_MOST)
+        }
+
+    private fun resolveFill(spec: Int, childSize: Int): Int =
+        if (MeasureSpec.getMode(spec) == MeasureSpec.UNSPECIFIED) childSize else MeasureSpec.getSize(spec)
+}
+
+/**
+ * GeometryReader renderer.
+ *
+ * After each layout pass, and whenever an ancestor scrolls, the reader publishes
+ * its frame (in points, relative to the window) through the geometry proxy, so
+ * content built from the proxy signals can adapt to the space it was given.
+ * Unchanged frames are not published again.
+ */
+private val geometryReaderRenderer = WuiRenderer { context, node, env, registry ->
+    val struct = NativeBindings.waterui_force_as_geometry_reader(node.rawPtr)
+    val proxyPtr = struct.proxyPtr
+    val density = context.resources.displayMetrics.density
+
+    val container = GeometryReaderLayout(context)
+    if (struct.contentPtr != 0L) {
+        container.addView(inflateAnyView(context, struct.contentPtr, env, registry))
+    }
+
+    val location = IntArray(2)
+    val published = IntArray(4)
+    var hasPublished = false
+    fun publish() {
+        container.getLocationInWindow(location)
+        val width = container.width
+        val height = container.height
+        if (hasPublished && published[0] == location[0] && published[1] == location[1] &&
+            published[2] == width && published[3] == height
+        ) return
+        hasPublished = true
+        published[0] = location[0]
+        published[1] = location[1]
+        published[2] = width
+        published[3] = height
+        NativeBindings.waterui_geometry_proxy_update(
+            proxyPtr,
+            location[0] / density,
+            location[1] / density,
+            width / density,
+            height / density
+        )
+    }
+
+    container.addOnLayoutChangeListener { _, _, _, _, _, _, _, _, _ -> publish() }
+    // Scrolling an ancestor moves the reader without laying it out again
+    val scrollListener = ViewTreeObserver.OnScrollChangedListener { publish() }
+    container.viewTreeObserver.addOnScrollChangedListener(scrollListener)
+
+    container.disposeWith {
+        container.viewTreeObserver.removeOnScrollChangedListener(scrollListener)
+        NativeBindings.waterui_drop_geometry_proxy(proxyPtr)
+    }
+
+    container
+}
+
+/**
+ * Register GeometryReader with the registry.
+ */
+internal fun RegistryBuilder.registerWuiGeometryReader() {
+    register({ geometryReaderTypeId }, geometryReaderRenderer)
+}
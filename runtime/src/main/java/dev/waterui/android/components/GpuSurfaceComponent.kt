@@ -2,11 +2,17 @@ package dev.waterui.android.components
 
 import android.content.Context
 import android.view.Choreographer
+import android.view.KeyEvent
+import android.view.MotionEvent
 import android.view.SurfaceHolder
 import android.view.SurfaceView
 import android.view.ViewGroup
 import dev.waterui.android.runtime.GpuSurfaceStruct
+import dev.waterui.android.runtime.KeyNames
+import dev.waterui.android.runtime.KeyPhase
 import dev.waterui.android.runtime.NativeBindings
+import dev.waterui.android.runtime.PointerKind
+import dev.waterui.android.runtime.PointerPhase
 import dev.waterui.android.runtime.RegistryBuilder
 import dev.waterui.android.runtime.WuiRenderer
 import dev.waterui.android.runtime.WuiTypeId
@@ -26,6 +32,13 @@ private val gpuSurfaceTypeId: WuiTypeId by lazy { NativeBindings.waterui_gpu_sur
  * - SurfaceView provides an ANativeWindow for zero-copy GPU access
  * - Choreographer provides VSync-aligned frame callbacks
  * - Rust owns wgpu Device/Queue/Surface and calls user's GpuRenderer
+ * - Size changes and the VSync timestamp are passed with every frame, so Rust
+ *   can reconfigure the surface and compute frame delta times
+ * - Touch, mouse, stylus and hardware keyboard events are queued into Rust and
+ *   reach the renderer in the context of the next frame; the view takes focus
+ *   when touched so it receives keys
+ * - The present mode and MSAA sample count declared in Rust are passed back to
+ *   init, which configures the surface with them
  *
  * # HDR Support
  *
@@ -66,6 +79,10 @@ private class GpuSurfaceView(
 
         // Register for surface callbacks
         holder.addCallback(this)
+
+        // Key events only reach the focused view
+        isFocusable = true
+        isFocusableInTouchMode = true
     }
 
     // ========== SurfaceHolder.Callback ==========
@@ -82,7 +99,7 @@ private class GpuSurfaceView(
             // Initialize GPU resources with the native surface
             // The JNI layer extracts ANativeWindow from the Surface via ANativeWindow_fromSurface
             gpuState = NativeBindings.waterui_gpu_surface_init(
-                gpuSurfaceData.rendererPtr,
+                gpuSurfaceData,
                 holder.surface,
                 width,
                 height
@@ -113,11 +130,12 @@ private class GpuSurfaceView(
             return
         }
 
-        // Render frame with current dimensions
+        // Render frame with current dimensions; Rust handles resizes and the frame clock
         NativeBindings.waterui_gpu_surface_render(
             gpuState,
             surfaceWidth,
-            surfaceHeight
+            surfaceHeight,
+            frameTimeNanos
         )
 
         // Schedule next frame if still rendering
@@ -126,6 +144,77 @@ private class GpuSurfaceView(
         }
     }
 
+    // ========== Input ==========
+
+    override fun onTouchEvent(event: MotionEvent): Boolean {
+        if (gpuState == 0L) return super.onTouchEvent(event)
+        when (event.actionMasked) {
+            MotionEvent.ACTION_DOWN -> {
+                requestFocus()
+                queuePointer(event, event.actionIndex, PointerPhase.DOWN)
+            }
+            MotionEvent.ACTION_POINTER_DOWN ->
+                queuePointer(event, event.actionIndex, PointerPhase.DOWN)
+            MotionEvent.ACTION_MOVE ->
+                for (i in 0 until event.pointerCount) queuePointer(event, i, PointerPhase.MOVE)
+            MotionEvent.ACTION_UP, MotionEvent.ACTION_POINTER_UP ->
+                queuePointer(event, event.actionIndex, PointerPhase.UP)
+            MotionEvent.ACTION_CANCEL ->
+                for (i in 0 until event.pointerCount) queuePointer(event, i, PointerPhase.CANCEL)
+        }
+        return true
+    }
+
+    override fun onHoverEvent(event: MotionEvent): Boolean {
+        if (gpuState != 0L && event.actionMasked == MotionEvent.ACTION_HOVER_MOVE) {
+            queuePointer(event, 0, PointerPhase.HOVER)
+        }
+        return super.onHoverEvent(event)
+    }
+
+    override fun onKeyDown(keyCode: Int, event: KeyEvent): Boolean =
+        queueKey(event, KeyPhase.DOWN) || super.onKeyDown(keyCode, event)
+
+    override fun onKeyUp(keyCode: Int, event: KeyEvent): Boolean =
+        queueKey(event, KeyPhase.UP) || super.onKeyUp(keyCode, event)
+
+    private fun queuePointer(event: MotionEvent, index: Int, phase: Int) {
+        val kind = when (event.getToolType(index)) {
+            MotionEvent.TOOL_TYPE_MOUSE -> PointerKind.MOUSE
+            MotionEvent.TOOL_TYPE_STYLUS, MotionEvent.TOOL_TYPE_ERASER -> PointerKind.PEN
+            else -> PointerKind.TOUCH
+        }
+        // Devices without pressure sensing report 0 or 1; normalize to 1
+        val pressure = event.getPressure(index).takeIf { it > 0f } ?: 1f
+        // Surface pixels, the same space as the size passed to render
+        NativeBindings.waterui_gpu_surface_pointer(
+            gpuState,
+            phase,
+            kind,
+            event.getPointerId(index),
+            event.getX(index),
+            event.getY(index),
+            pressure
+        )
+    }
+
+    /**
+     * Queues [event] for the renderer. Returns false for keys it does not take,
+     * such as back and volume, so the system still handles them.
+     */
+    private fun queueKey(event: KeyEvent, phase: Int): Boolean {
+        if (gpuState == 0L) return false
+        val key = KeyNames.name(event.keyCode, event.unicodeChar) ?: return false
+        NativeBindings.waterui_gpu_surface_key(
+            gpuState,
+            phase,
+            key,
+            KeyNames.modifiers(event.metaState),
+            event.repeatCount > 0
+        )
+        return true
+    }
+
     // ========== Layout ==========
 
     override fun onMeasure(widthMeasureSpec: Int, heightMeasureSpec: Int) {
@@ -29,7 +29,7 @@ private val labelRenderer = WuiRenderer { context, node, env, _ ->
     val font = ThemeBridge.bodyFont(env)
     font.observe { resolved ->
         textView.setTextSize(TypedValue.COMPLEX_UNIT_SP, resolved.size)
-        textView.typeface = resolved.toTypeface()
+        textView.typeface = resolved.toTypeface(env)
     }
     font.attachTo(textView)
     textView
@@ -0,0 +1,234 @@
+package dev.waterui.android.components
+
+import android.content.Context
+import android.graphics.Rect
+import android.view.Gravity
+import android.view.View
+import android.view.ViewGroup
+import android.widget.FrameLayout
+import androidx.recyclerview.widget.GridLayoutManager
+import androidx.recyclerview.widget.RecyclerView
+import dev.waterui.android.layout.GridTrack
+import dev.waterui.android.layout.GridTracks
+import dev.waterui.android.runtime.NativeBindings
+import dev.waterui.android.runtime.RegistryBuilder
+import dev.waterui.android.runtime.RenderRegistry
+import dev.waterui.android.runtime.WuiEnvironment
+import dev.waterui.android.runtime.WuiRenderer
+import dev.waterui.android.runtime.WuiTypeId
+import dev.waterui.android.runtime.disposeWith
+import dev.waterui.android.runtime.dp
+import dev.waterui.android.runtime.inflateAnyView
+import kotlin.math.roundToInt
+
+private val lazyGridTypeId: WuiTypeId by lazy { NativeBindings.waterui_lazy_grid_id().toTypeId() }
+
+private const val AXIS_HORIZONTAL = 0
+
+private const val ALIGN_CENTER = 1
+private const val ALIGN_TRAILING = 2
+
+/**
+ * LazyVGrid / LazyHGrid renderer.
+ *
+ * Uses a GridLayoutManager with one span per cross-axis pixel, so each cell's span
+ * matches the width of its resolved `GridItem` track. Tracks are recomputed whenever
+ * the viewport changes size. Cells are materialized from the native `AnyViews`
+ * collection only when bound.
+ */
+private val lazyGridRenderer = WuiRenderer { context, node, env, registry ->
+    val struct = NativeBindings.waterui_force_as_lazy_grid(node.rawPtr)
+    val orientation = if (struct.axis == AXIS_HORIZONTAL) {
+        RecyclerView.HORIZONTAL
+    } else {
+        RecyclerView.VERTICAL
+    }
+    val density = context.resources.displayMetrics.density
+    val items = struct.items.toList()
+
+    val layoutManager = GridLayoutManager(context, 1, orientation, false)
+    val recyclerView = RecyclerView(context).apply {
+        this.layoutManager = layoutManager
+        layoutParams = ViewGroup.LayoutParams(
+            ViewGroup.LayoutParams.MATCH_PARENT,
+            ViewGroup.LayoutParams.MATCH_PARENT
+        )
+        clipToPadding = false
+        // Cell content disposes its watchers when detached, so an off-screen cell must
+        // always be rebound rather than reattached from the view cache.
+        setItemViewCacheSize(0)
+    }
+
+    val contentsPtr = struct.contentsPtr
+    val adapter = LazyGridAdapter(context, contentsPtr, orientation, env, registry)
+    recyclerView.adapter = adapter
+    // Every line holds one cell per track, so span positions follow directly from
+    // the position instead of the default scan over all preceding items
+    layoutManager.spanSizeLookup = object : GridLayoutManager.SpanSizeLookup() {
+        override fun getSpanSize(position: Int): Int = adapter.spanAt(position)
+        override fun getSpanIndex(position: Int, spanCount: Int): Int = adapter.spanIndexAt(position)
+        override fun getSpanGroupIndex(adapterPosition: Int, spanCount: Int): Int =
+            adapterPosition / adapter.spans.size.coerceAtLeast(1)
+    }
+    recyclerView.addItemDecoration(LazyGridSpacing(struct.spacing.dp(context).toInt(), orientation, adapter))
+
+    // Resolve tracks against the cross-axis viewport size
+    recyclerView.addOnLayoutChangeListener { _, left, top, right, bottom, _, _, _, _ ->
+        val crossPx = if (orientation == RecyclerView.HORIZONTAL) bottom - top else right - left
+        if (crossPx <= 0) return@addOnLayoutChangeListener
+        val tracks = GridTracks.resolve(items, crossPx / density)
+        if (tracks.isEmpty()) return@addOnLayoutChangeListener
+        val spans = GridTracks.pixelSpans(tracks, density, crossPx)
+        if (tracks == adapter.tracks && spans.contentEquals(adapter.spans)) return@addOnLayoutChangeListener
+        recyclerView.post {
+            adapter.tracks = tracks
+            adapter.spans = spans
+            layoutManager.spanCount = spans.sum()
+            recyclerView.invalidateItemDecorations()
+            adapter.notifyItemRangeChanged(0, adapter.itemCount)
+        }
+    }
+
+    recyclerView.disposeWith {
+        if (contentsPtr != 0L) {
+            NativeBindings.waterui_drop_any_views(contentsPtr)
+        }
+    }
+
+    recyclerView
+}
+
+/**
+ * Adds the grid spacing: the spacing of each track after its cells across the axis,
+ * and the line spacing between consecutive lines along the scrolling axis.
+ */
+private class LazyGridSpacing(
+    private val lineSpacingPx: Int,
+    private val orientation: Int,
+    private val adapter: LazyGridAdapter
+) : RecyclerView.ItemDecoration() {
+    override fun getItemOffsets(outRect: Rect, view: View, parent: RecyclerView, state: RecyclerView.State) {
+        val position = parent.getChildAdapterPosition(view)
+        val trackCount = adapter.tracks.size
+        if (position == RecyclerView.NO_POSITION || trackCount == 0) return
+        val trackSpacingPx = adapter.trackSpacingPx(position)
+        val lineSpacingPx = if (position < trackCount) 0 else lineSpacingPx
+        if (orientation == RecyclerView.HORIZONTAL) {
+            outRect.bottom = trackSpacingPx
+            outRect.left = lineSpacingPx
+        } else {
+            if (parent.layoutDirection == View.LAYOUT_DIRECTION_RTL) {
+                outRect.left = trackSpacingPx
+            } else {
+                outRect.right = trackSpacingPx
+            }
+            outRect.top = lineSpacingPx
+        }
+    }
+}
+
+/**
+ * RecyclerView adapter that creates grid cells on demand from a native AnyViews collection.
+ */
+private class LazyGridAdapter(
+    private val context: Context,
+    private val contentsPtr: Long,
+    private val orientation: Int,
+    private val env: WuiEnvironment,
+    private val registry: RenderRegistry
+) : RecyclerView.Adapter<LazyGridAdapter.ViewHolder>() {
+
+    var tracks: List<GridTrack> = emptyList()
+
+    /** Pixel span of each track, including its spacing; see [GridTracks.pixelSpans]. */
+    var spans: IntArray = IntArray(0)
+
+    private val count = if (contentsPtr != 0L) NativeBindings.waterui_any_views_len(contentsPtr) else 0
+    private val density = context.resources.displayMetrics.density
+
+    init {
+        setHasStableIds(true)
+    }
+
+    class ViewHolder(val container: FrameLayout) : RecyclerView.ViewHolder(container)
+
+    override fun onCreateViewHolder(parent: ViewGroup, viewType: Int): ViewHolder {
+        val container = FrameLayout(context).apply {
+            layoutParams = if (orientation == RecyclerView.HORIZONTAL) {
+                RecyclerView.LayoutParams(
+                    ViewGroup.LayoutParams.WRAP_CONTENT,
+                    ViewGroup.LayoutParams.MATCH_PARENT
+                )
+            } else {
+                RecyclerView.LayoutParams(
+                    ViewGroup.LayoutParams.MATCH_PARENT,
+                    ViewGroup.LayoutParams.WRAP_CONTENT
+                )
+            }
+        }
+        return ViewHolder(container)
+    }
+
+    override fun onBindViewHolder(holder: ViewHolder, position: Int) {
+        holder.container.removeAllViews()
+
+        // Materialize the cell now that it is about to scroll into view
+        val viewPtr = NativeBindings.waterui_any_views_get_view(contentsPtr, position)
+        if (viewPtr == 0L) return
+        val contentView = inflateAnyView(context, viewPtr, env, registry)
+
+        // Size the cell to its track inside the span and align it within the track
+        val track = tracks.getOrNull(if (tracks.isEmpty()) 0 else position % tracks.size)
+        val trackPx = track?.let { (it.size * density).roundToInt() } ?: ViewGroup.LayoutParams.MATCH_PARENT
+        val params = if (orientation == RecyclerView.HORIZONTAL) {
+            FrameLayout.LayoutParams(ViewGroup.LayoutParams.WRAP_CONTENT, trackPx)
+        } else {
+            FrameLayout.LayoutParams(trackPx, ViewGroup.LayoutParams.WRAP_CONTENT)
+        }
+        params.gravity = when (track?.alignment) {
+            ALIGN_CENTER -> Gravity.CENTER
+            ALIGN_TRAILING -> if (orientation == RecyclerView.HORIZONTAL) Gravity.BOTTOM else Gravity.END
+            else -> if (orientation == RecyclerView.HORIZONTAL) Gravity.TOP else Gravity.START
+        }
+        holder.container.addView(contentView, params)
+    }
+
+    fun spanAt(position: Int): Int =
+        if (spans.isEmpty()) 1 else spans[position % spans.size]
+
+    fun spanIndexAt(position: Int): Int {
+        if (spans.isEmpty()) return 0
+        var index = 0
+        for (track in 0 until position % spans.size) index += spans[track]
+        return index
+    }
+
+    /**
+     * Spacing after the track of [position]. It is what remains of the span once the
+     * track is laid out, so the cell gets exactly its track width despite rounding;
+     * the last span also holds the leftover width of the line and keeps plain spacing.
+     */
+    fun trackSpacingPx(position: Int): Int {
+        if (tracks.isEmpty() || spans.size != tracks.size) return 0
+        val index = position % tracks.size
+        val track = tracks[index]
+        if (index == tracks.lastIndex) return (track.spacing * density).roundToInt()
+        return (spans[index] - (track.size * density).roundToInt()).coerceAtLeast(0)
+    }
+
+    override fun onViewRecycled(holder: ViewHolder) {
+        holder.container.removeAllViews()
+    }
+
+    override fun getItemCount(): Int = count
+
+    override fun getItemId(position: Int): Long =
+        NativeBindings.waterui_any_views_get_id(contentsPtr, position).toLong()
+}
+
+/**
+ * Register LazyVGrid / LazyHGrid with the registry.
+ */
+internal fun RegistryBuilder.registerWuiLazyGrid() {
+    register({ lazyGridTypeId }, lazyGridRenderer)
+}
@@ -0,0 +1,166 @@
+package dev.waterui.android.components
+
+import android.content.Context
+import android.graphics.Rect
+import android.view.View
+import android.view.ViewGroup
+import android.widget.FrameLayout
+import androidx.recyclerview.widget.LinearLayoutManager
+import androidx.recyclerview.widget.RecyclerView
+import dev.waterui.android.runtime.NativeBindings
+import dev.waterui.android.runtime.RegistryBuilder
+import dev.waterui.android.runtime.RenderRegistry
+import dev.waterui.android.runtime.WuiEnvironment
+import dev.waterui.android.runtime.WuiRenderer
+import dev.waterui.android.runtime.WuiTypeId
+import dev.waterui.android.runtime.disposeWith
+import dev.waterui.android.runtime.dp
+import dev.waterui.android.runtime.inflateAnyView
+import kotlin.math.ceil
+
+private val lazyStackTypeId: WuiTypeId by lazy { NativeBindings.waterui_lazy_stack_id().toTypeId() }
+
+private const val AXIS_HORIZONTAL = 0
+
+/** Recycled holders kept when no estimated item size is provided. */
+private const val DEFAULT_POOL_SIZE = 10
+
+/**
+ * LazyVStack / LazyHStack renderer.
+ *
+ * Items are materialized from the native `AnyViews` collection only when RecyclerView
+ * binds them, so a stack with thousands of items only inflates what is on screen.
+ * Item containers are recycled through the RecyclerView pool.
+ */
+private val lazyStackRenderer = WuiRenderer { context, node, env, registry ->
+    val struct = NativeBindings.waterui_force_as_lazy_stack(node.rawPtr)
+    val orientation = if (struct.axis == AXIS_HORIZONTAL) {
+        RecyclerView.HORIZONTAL
+    } else {
+        RecyclerView.VERTICAL
+    }
+
+    val recyclerView = RecyclerView(context).apply {
+        layoutManager = LinearLayoutManager(context, orientation, false)
+        layoutParams = ViewGroup.LayoutParams(
+            ViewGroup.LayoutParams.MATCH_PARENT,
+            ViewGroup.LayoutParams.MATCH_PARENT
+        )
+        clipToPadding = false
+        // Item content disposes its watchers when detached, so an off-screen holder must
+        // always be rebound rather than reattached from the view cache.
+        setItemViewCacheSize(0)
+    }
+
+    val spacingPx = struct.spacing.dp(context).toInt()
+    if (spacingPx > 0) {
+        recyclerView.addItemDecoration(LazyStackSpacing(spacingPx, orientation))
+    }
+
+    val contentsPtr = struct.contentsPtr
+    val adapter = LazyStackAdapter(context, contentsPtr, orientation, env, registry)
+    recyclerView.adapter = adapter
+
+    // Size the recycling pool from the estimated item size once the viewport is known.
+    recyclerView.recycledViewPool.setMaxRecycledViews(0, DEFAULT_POOL_SIZE)
+    val estimatedPx = struct.estimatedItemSize.dp(context)
+    if (estimatedPx > 0f) {
+        recyclerView.addOnLayoutChangeListener { view, left, top, right, bottom, _, _, _, _ ->
+            val viewport = if (orientation == RecyclerView.HORIZONTAL) right - left else bottom - top
+            if (viewport > 0) {
+                val visible = ceil(viewport / (estimatedPx + spacingPx)).toInt()
+                (view as RecyclerView).recycledViewPool.setMaxRecycledViews(0, visible + 2)
+            }
+        }
+    }
+
+    recyclerView.disposeWith {
+        if (contentsPtr != 0L) {
+            NativeBindings.waterui_drop_any_views(contentsPtr)
+        }
+    }
+
+    recyclerView
+}
+
+/**
+ * Adds the stack spacing between consecutive items along the main axis.
+ */
+private class LazyStackSpacing(
+    private val spacingPx: Int,
+    private val orientation: Int
+) : RecyclerView.ItemDecoration() {
+    override fun getItemOffsets(outRect: Rect, view: View, parent: RecyclerView, state: RecyclerView.State) {
+        if (parent.getChildAdapterPosition(view) <= 0) return
+        if (orientation == RecyclerView.HORIZONTAL) {
+            outRect.left = spacingPx
+        } else {
+            outRect.top = spacingPx
+        }
+    }
+}
+
+/**
+ * RecyclerView adapter that creates item views on demand from a native AnyViews collection.
+ */
+private class LazyStackAdapter(
+    private val context: Context,
+    private val contentsPtr: Long,
+    private val orientation: Int,
+    private val env: WuiEnvironment,
+    private val registry: RenderRegistry
+) : RecyclerView.Adapter<LazyStackAdapter.ViewHolder>() {
+
+    private val count = if (contentsPtr != 0L) NativeBindings.waterui_any_views_len(contentsPtr) else 0
+
+    init {
+        setHasStableIds(true)
+    }
+
+    class ViewHolder(val container: FrameLayout) : RecyclerView.ViewHolder(container)
+
+    override fun onCreateViewHolder(parent: ViewGroup, viewType: Int): ViewHolder {
+        val container = FrameLayout(context).apply {
+            layoutParams = if (orientation == RecyclerView.HORIZONTAL) {
+                RecyclerView.LayoutParams(
+                    ViewGroup.LayoutParams.WRAP_CONTENT,
+                    ViewGroup.LayoutParams.MATCH_PARENT
+                )
+            } else {
+                RecyclerView.LayoutParams(
+                    ViewGroup.LayoutParams.MATCH_PARENT,
+                    ViewGroup.LayoutParams.WRAP_CONTENT
+                )
+            }
+        }
+        return ViewHolder(container)
+    }
+
+    override fun onBindViewHolder(holder: ViewHolder, position: Int) {
+        holder.container.removeAllViews()
+
+        // Materialize the item now that it is about to scroll into view
+        val viewPtr = NativeBindings.waterui_any_views_get_view(contentsPtr, position)
+        if (viewPtr != 0L) {
+            val contentView = inflateAnyView(context, viewPtr, env, registry)
+            holder.container.addView(contentView)
+        }
+    }
+
+    override fun onViewRecycled(holder: ViewHolder) {
+        // Release the item content while the holder waits in the pool
+        holder.container.removeAllViews()
+    }
+
+    override fun getItemCount(): Int = count
+
+    override fun getItemId(position: Int): Long =
+        NativeBindings.waterui_any_views_get_id(contentsPtr, position).toLong()
+}
+
+/**
+ * Register LazyVStack / LazyHStack with the registry.
+ */
+internal fun RegistryBuilder.registerWuiLazyStack() {
+    register({ lazyStackTypeId }, lazyStackRenderer)
+}
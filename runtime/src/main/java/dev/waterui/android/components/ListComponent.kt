@@ -1,16 +1,26 @@
 package dev.waterui.android.components
 
 import android.content.Context
+import android.graphics.Bitmap
 import android.graphics.Canvas
 import android.graphics.Color
+import android.graphics.Paint
 import android.graphics.drawable.ColorDrawable
+import android.view.View
 import android.view.ViewGroup
 import android.widget.FrameLayout
 import androidx.recyclerview.widget.ItemTouchHelper
 import androidx.recyclerview.widget.LinearLayoutManager
 import androidx.recyclerview.widget.RecyclerView
+import dev.waterui.android.reactive.WuiBinding
 import dev.waterui.android.reactive.WuiComputedBool
+import dev.waterui.android.runtime.ListItemStruct
+import dev.waterui.android.runtime.ListSectionStruct
+import dev.waterui.android.runtime.SwipeActionStruct
+import dev.waterui.android.runtime.ThemeBridge
+import dev.waterui.android.runtime.attachTo
 import dev.waterui.android.runtime.disposeWith
+import dev.waterui.android.runtime.dp
 import dev.waterui.android.runtime.NativeBindings
 import dev.waterui.android.runtime.RegistryBuilder
 import dev.waterui.android.runtime.WuiEnvironment
@@ -18,13 +28,29 @@ import dev.waterui.android.runtime.WuiRenderer
 import dev.waterui.android.runtime.WuiTypeId
 import dev.waterui.android.runtime.inflateAnyView
 import dev.waterui.android.runtime.RenderRegistry
+import dev.waterui.android.runtime.toColorInt
 
 private val listTypeId: WuiTypeId by lazy { NativeBindings.waterui_list_id().toTypeId() }
+private val listSectionTypeId: WuiTypeId by lazy { NativeBindings.waterui_list_section_id().toTypeId() }
+
+private const val SEPARATOR_HIDDEN = 2
+private const val ROLE_DESTRUCTIVE = 1
+
+private const val VIEW_TYPE_ITEM = 0
+private const val VIEW_TYPE_HEADER = 1
+private const val VIEW_TYPE_FOOTER = 2
 
 /**
  * List component renderer.
- * Renders a scrollable list of items using RecyclerView.
- * Supports swipe-to-delete and drag-to-reorder via ItemTouchHelper.
+ * Renders a scrollable, virtualized list of items using RecyclerView.
+ *
+ * Row content is only inflated when RecyclerView binds it. Supports sections with
+ * pinned headers, separators, a selection binding, swipe actions, swipe-to-delete
+ * and drag-to-reorder via ItemTouchHelper.
+ *
+ * Each section carries its own delete and move actions, called with indices into
+ * the section's contents; the list's actions cover rows outside any section.
+ * Rows cannot be dragged into another section.
  */
 private val listRenderer = WuiRenderer { context, node, env, registry ->
     val struct = NativeBindings.waterui_force_as_list(node.rawPtr)
@@ -35,116 +61,214 @@ private val listRenderer = WuiRenderer { context, node, env, registry ->
             ViewGroup.LayoutParams.MATCH_PARENT,
             ViewGroup.LayoutParams.MATCH_PARENT
         )
+        // Row content disposes its watchers when detached, so an off-screen row must
+        // always be rebound rather than reattached from the view cache.
+        setItemViewCacheSize(0)
     }
 
-    // Load items from WuiAnyViews
-    val items = mutableListOf<ListItemData>()
+    // Flatten sections and items into rows. Only metadata is read here; content is
+    // materialized in onBindViewHolder.
     val contentsPtr = struct.contentsPtr
+    val rows = mutableListOf<ListRow>()
+    val sections = mutableListOf<ListSectionStruct>()
     if (contentsPtr != 0L) {
         val count = NativeBindings.waterui_any_views_len(contentsPtr)
         for (i in 0 until count) {
-            val id = NativeBindings.waterui_any_views_get_id(contentsPtr, i)
             val viewPtr = NativeBindings.waterui_any_views_get_view(contentsPtr, i)
-            if (viewPtr != 0L) {
-                // Get the ListItem and extract its content
-                val listItem = NativeBindings.waterui_force_as_list_item(viewPtr)
-
-                // Create deletable computed if pointer exists
-                val deletableComputed = if (listItem.deletablePtr != 0L) {
-                    WuiComputedBool(listItem.deletablePtr)
-                } else null
-
-                items.add(ListItemData(id, listItem.contentPtr, deletableComputed))
+            if (viewPtr == 0L) continue
+            val typeId = NativeBindings.waterui_view_id(viewPtr).toTypeId()
+            if (typeId == listSectionTypeId) {
+                val sectionIndex = sections.size
+                val section = NativeBindings.waterui_force_as_list_section(viewPtr)
+                sections.add(section)
+                if (section.headerPtr != 0L) {
+                    rows.add(ListRow.Supplementary(sectionIndex, contentsPtr, i, section.headerPtr, header = true))
+                }
+                if (section.contentsPtr != 0L) {
+                    val itemCount = NativeBindings.waterui_any_views_len(section.contentsPtr)
+                    for (j in 0 until itemCount) {
+                        val itemPtr = NativeBindings.waterui_any_views_get_view(section.contentsPtr, j)
+                        if (itemPtr != 0L) {
+                            rows.add(loadItemRow(section.contentsPtr, j, sectionIndex, sectionIndex, itemPtr))
+                        }
+                    }
+                }
+                if (section.footerPtr != 0L) {
+                    rows.add(ListRow.Supplementary(sectionIndex, contentsPtr, i, section.footerPtr, header = false))
+                }
+            } else {
+                rows.add(loadItemRow(contentsPtr, i, null, sections.size, viewPtr))
             }
         }
     }
 
-    val adapter = WuiListAdapter(context, items, env, registry)
+    val selection = if (struct.selectionPtr != 0L) {
+        WuiBinding.int(struct.selectionPtr, env)
+    } else null
+
+    val adapter = WuiListAdapter(context, rows, env, registry, selection)
     recyclerView.adapter = adapter
 
+    // Only the rows losing and gaining the selection change, and only their highlight
+    var selectedId: Int? = null
+    selection?.observe { id ->
+        val previous = selectedId
+        selectedId = id
+        if (previous == null || previous == id) return@observe
+        adapter.notifySelectionChanged(previous)
+        adapter.notifySelectionChanged(id)
+    }
+    selection?.attachTo(recyclerView)
+
+    val stickyHeaders = if (sections.isNotEmpty()) StickyHeaderDecoration(rows) else null
+    stickyHeaders?.let { recyclerView.addItemDecoration(it) }
+
+    if (struct.separator != SEPARATOR_HIDDEN) {
+        val separators = ListSeparatorDecoration(rows, 1f.dp(context).coerceAtLeast(1f))
+        val border = ThemeBridge.border(env)
+        border.observe { color ->
+            separators.color = color.toColorInt()
+            recyclerView.invalidateItemDecorations()
+        }
+        border.attachTo(recyclerView)
+        recyclerView.addItemDecoration(separators)
+    }
+
     // Setup editing state watcher if provided
     val editingComputed = if (struct.editingPtr != 0L) {
         WuiComputedBool(struct.editingPtr)
     } else null
 
-    // Setup ItemTouchHelper for swipe-to-delete and drag-to-reorder
+    // Setup ItemTouchHelper for swipe actions, swipe-to-delete and drag-to-reorder
     val onDeletePtr = struct.onDeletePtr
     val onMovePtr = struct.onMovePtr
+    fun deleteActionOf(item: ListRow.Item): Long = item.section?.let { sections[it].onDeletePtr } ?: onDeletePtr
+    fun moveActionOf(item: ListRow.Item): Long = item.section?.let { sections[it].onMovePtr } ?: onMovePtr
+
+    val hasDeleteActions = onDeletePtr != 0L || sections.any { it.onDeletePtr != 0L }
+    val hasMoveActions = onMovePtr != 0L || sections.any { it.onMovePtr != 0L }
+    val hasSwipeActions = rows.any {
+        it is ListRow.Item && (it.leadingActions.isNotEmpty() || it.trailingActions.isNotEmpty())
+    }
+
+    if (hasDeleteActions || hasMoveActions || hasSwipeActions) {
+        val accent = ThemeBridge.accent(env)
+        var accentColor = Color.GRAY
+        accent.observe { color -> accentColor = color.toColorInt() }
+        accent.attachTo(recyclerView)
+
+        val labelPaint = Paint(Paint.ANTI_ALIAS_FLAG).apply {
+            color = Color.WHITE
+            textSize = 15f.dp(context)
+        }
+        val labelPadding = 16f.dp(context)
 
-    if (onDeletePtr != 0L || onMovePtr != 0L) {
         val touchCallback = object : ItemTouchHelper.SimpleCallback(
-            if (onMovePtr != 0L) ItemTouchHelper.UP or ItemTouchHelper.DOWN else 0,
-            if (onDeletePtr != 0L) ItemTouchHelper.START or ItemTouchHelper.END else 0
+            ItemTouchHelper.UP or ItemTouchHelper.DOWN,
+            ItemTouchHelper.START or ItemTouchHelper.END
         ) {
             override fun onMove(
                 recyclerView: RecyclerView,
                 viewHolder: RecyclerView.ViewHolder,
                 target: RecyclerView.ViewHolder
             ): Boolean {
-                if (onMovePtr == 0L) return false
-
                 val fromPosition = viewHolder.adapterPosition
                 val toPosition = target.adapterPosition
+                val from = rows.getOrNull(fromPosition) as? ListRow.Item ?: return false
+                val to = rows.getOrNull(toPosition) as? ListRow.Item ?: return false
+                // Rows only move within the collection they belong to
+                if (from.section != to.section || from.sectionsBefore != to.sectionsBefore) return false
+                val moveAction = moveActionOf(from)
+                if (moveAction == 0L) return false
+
+                val fromIndex = collectionIndexOf(rows, fromPosition)
+                val toIndex = collectionIndexOf(rows, toPosition)
 
                 // Update local list
-                val item = items.removeAt(fromPosition)
-                items.add(toPosition, item)
+                val item = rows.removeAt(fromPosition)
+                rows.add(toPosition, item)
                 adapter.notifyItemMoved(fromPosition, toPosition)
 
                 // Call Rust callback
                 NativeBindings.waterui_call_move_action(
-                    onMovePtr,
+                    moveAction,
                     env.raw(),
-                    fromPosition.toLong(),
-                    toPosition.toLong()
+                    fromIndex.toLong(),
+                    toIndex.toLong()
                 )
 
                 return true
             }
 
             override fun onSwiped(viewHolder: RecyclerView.ViewHolder, direction: Int) {
-                if (onDeletePtr == 0L) return
-
                 val position = viewHolder.adapterPosition
-                val item = items[position]
+                val item = rows.getOrNull(position) as? ListRow.Item ?: return
 
-                // Check if item is deletable
-                val isDeletable = item.deletable?.value ?: true
-                if (!isDeletable) {
-                    // Restore the item if not deletable
+                // Swiping towards the end reveals the leading edge and vice versa.
+                // A full swipe performs the first action on that edge.
+                val action = swipeActionFor(item, direction)
+                if (action != null) {
                     adapter.notifyItemChanged(position)
+                    if (action.actionPtr != 0L) {
+                        NativeBindings.waterui_call_shared_action(action.actionPtr, env.raw())
+                    }
                     return
                 }
 
+                val deleteAction = deleteActionOf(item)
+                if (deleteAction == 0L || !item.isDeletable()) {
+                    adapter.notifyItemChanged(position)
+                    return
+                }
+
+                val index = collectionIndexOf(rows, position)
+
                 // Remove from local list
-                items.removeAt(position)
+                rows.removeAt(position)
                 adapter.notifyItemRemoved(position)
+                item.release()
 
                 // Call Rust callback
                 NativeBindings.waterui_call_index_action(
-                    onDeletePtr,
+                    deleteAction,
                     env.raw(),
-                    position.toLong()
+                    index.toLong()
                 )
             }
 
-            override fun getSwipeDirs(
+            override fun getMovementFlags(
+                recyclerView: RecyclerView,
+                viewHolder: RecyclerView.ViewHolder
+            ): Int {
+                if (viewHolder.itemViewType != VIEW_TYPE_ITEM) return 0
+                return super.getMovementFlags(recyclerView, viewHolder)
+            }
+
+            override fun getDragDirs(
                 recyclerView: RecyclerView,
                 viewHolder: RecyclerView.ViewHolder
             ): Int {
-                if (onDeletePtr == 0L) return 0
+                val item = rows.getOrNull(viewHolder.adapterPosition) as? ListRow.Item ?: return 0
+                return if (moveActionOf(item) != 0L) super.getDragDirs(recyclerView, viewHolder) else 0
+            }
 
+            override fun getSwipeDirs(
+                recyclerView: RecyclerView,
+                viewHolder: RecyclerView.ViewHolder
+            ): Int {
                 val position = viewHolder.adapterPosition
-                if (position < 0 || position >= items.size) return 0
+                val item = rows.getOrNull(position) as? ListRow.Item ?: return 0
 
-                val item = items[position]
-                val isDeletable = item.deletable?.value ?: true
-                return if (isDeletable) super.getSwipeDirs(recyclerView, viewHolder) else 0
+                val canDelete = deleteActionOf(item) != 0L && item.isDeletable()
+                var dirs = 0
+                if (item.leadingActions.isNotEmpty()) dirs = dirs or ItemTouchHelper.END
+                if (item.trailingActions.isNotEmpty() || canDelete) dirs = dirs or ItemTouchHelper.START
+                return dirs
             }
 
             override fun isLongPressDragEnabled(): Boolean {
                 // Only enable drag if editing mode is on or if there's no editing state
-                return onMovePtr != 0L && (editingComputed?.value ?: true)
+                return hasMoveActions && (editingComputed?.value ?: true)
             }
 
             override fun onChildDraw(
@@ -156,10 +280,16 @@ private val listRenderer = WuiRenderer { context, node, env, registry ->
                 actionState: Int,
                 isCurrentlyActive: Boolean
             ) {
-                // Draw red background when swiping to delete
-                if (actionState == ItemTouchHelper.ACTION_STATE_SWIPE) {
+                // Draw the action background and label while swiping
+                if (actionState == ItemTouchHelper.ACTION_STATE_SWIPE && dX != 0f) {
                     val itemView = viewHolder.itemView
-                    val background = ColorDrawable(Color.RED)
+                    val item = rows.getOrNull(viewHolder.adapterPosition) as? ListRow.Item
+                    val towardsEnd = (dX > 0) == (recyclerView.layoutDirection == View.LAYOUT_DIRECTION_LTR)
+                    val action = item?.let {
+                        swipeActionFor(it, if (towardsEnd) ItemTouchHelper.END else ItemTouchHelper.START)
+                    }
+                    val destructive = action == null || action.role == ROLE_DESTRUCTIVE
+                    val background = ColorDrawable(if (destructive) Color.RED else accentColor)
 
                     if (dX > 0) {
                         background.setBounds(
@@ -177,6 +307,17 @@ private val listRenderer = WuiRenderer { context, node, env, registry ->
                         )
                     }
                     background.draw(c)
+
+                    val label = action?.let { item.labelFor(it) }
+                    if (!label.isNullOrEmpty()) {
+                        val baseline = itemView.top + (itemView.height - labelPaint.descent() - labelPaint.ascent()) / 2f
+                        val x = if (dX > 0) {
+                            itemView.left + labelPadding
+                        } else {
+                            itemView.right - labelPadding - labelPaint.measureText(label)
+                        }
+                        c.drawText(label, x, baseline, labelPaint)
+                    }
                 }
 
                 super.onChildDraw(c, recyclerView, viewHolder, dX, dY, actionState, isCurrentlyActive)
@@ -188,6 +329,13 @@ private val listRenderer = WuiRenderer { context, node, env, registry ->
     }
 
     recyclerView.disposeWith {
+        stickyHeaders?.clear()
+        rows.forEach { it.release() }
+        sections.forEach { section ->
+            if (section.contentsPtr != 0L) NativeBindings.waterui_drop_any_views(section.contentsPtr)
+            if (section.onDeletePtr != 0L) NativeBindings.waterui_drop_index_action(section.onDeletePtr)
+            if (section.onMovePtr != 0L) NativeBindings.waterui_drop_move_action(section.onMovePtr)
+        }
         if (contentsPtr != 0L) {
             NativeBindings.waterui_drop_any_views(contentsPtr)
         }
@@ -200,36 +348,191 @@ private val listRenderer = WuiRenderer { context, node, env, registry ->
         if (onMovePtr != 0L) {
             NativeBindings.waterui_drop_move_action(onMovePtr)
         }
-        // Drop deletable computeds
-        items.forEach { item ->
-            item.deletable?.dispose()
-        }
     }
 
     recyclerView
 }
 
 /**
- * Data for a single list item.
+ * Reads the metadata of a list item. The content pointer is kept for the first bind.
+ */
+private fun loadItemRow(
+    sourcePtr: Long,
+    index: Int,
+    section: Int?,
+    sectionsBefore: Int,
+    viewPtr: Long
+): ListRow.Item {
+    val id = NativeBindings.waterui_any_views_get_id(sourcePtr, index)
+    val listItem = NativeBindings.waterui_force_as_list_item(viewPtr)
+
+    // Create deletable computed if pointer exists
+    val deletableComputed = if (listItem.deletablePtr != 0L) {
+        WuiComputedBool(listItem.deletablePtr)
+    } else null
+
+    return ListRow.Item(
+        id = id,
+        section = section,
+        sectionsBefore = sectionsBefore,
+        sourcePtr = sourcePtr,
+        index = index,
+        pendingContentPtr = listItem.contentPtr,
+        deletable = deletableComputed,
+        leadingActions = listItem.leadingActions,
+        trailingActions = listItem.trailingActions
+    )
+}
+
+/**
+ * Returns the index of the item at [position] in the collection it belongs to: its
+ * section's contents, or the list's contents for a row outside any section.
  */
-private data class ListItemData(
-    val id: Int,
-    val contentPtr: Long,
-    val deletable: WuiComputedBool?
-)
+private fun collectionIndexOf(rows: List<ListRow>, position: Int): Int {
+    val item = rows[position] as ListRow.Item
+    var index = 0
+    for (i in 0 until position) {
+        val row = rows[i]
+        if (row is ListRow.Item && row.section == item.section) index++
+    }
+    // In the list's contents, every earlier section takes one entry
+    return if (item.section == null) index + item.sectionsBefore else index
+}
+
+private fun swipeActionFor(item: ListRow.Item, direction: Int): SwipeActionStruct? = when (direction) {
+    ItemTouchHelper.END, ItemTouchHelper.RIGHT -> item.leadingActions.firstOrNull()
+    else -> item.trailingActions.firstOrNull()
+}
+
+private fun dropSwipeActions(actions: Array<SwipeActionStruct>) {
+    actions.forEach { action ->
+        if (action.labelPtr != 0L) NativeBindings.waterui_drop_computed_styled_str(action.labelPtr)
+        if (action.actionPtr != 0L) NativeBindings.waterui_drop_shared_action(action.actionPtr)
+    }
+}
+
+/**
+ * A single row of a list: a section header, an item or a section footer.
+ *
+ * Each row remembers where its view lives in the native collection so it can be
+ * materialized again after RecyclerView recycles it.
+ */
+private sealed class ListRow {
+    abstract val section: Int?
+    abstract val viewType: Int
+
+    /** Content pointer handed out by the initial metadata read, consumed on first bind. */
+    abstract var pendingContentPtr: Long
+
+    /** Returns a fresh content pointer for binding this row. */
+    abstract fun takeContent(): Long
+
+    open fun release() {
+        if (pendingContentPtr != 0L) {
+            NativeBindings.waterui_drop_anyview(pendingContentPtr)
+            pendingContentPtr = 0L
+        }
+    }
+
+    class Item(
+        val id: Int,
+        override val section: Int?,
+        /** Sections preceding this row in the list's contents. */
+        val sectionsBefore: Int,
+        private val sourcePtr: Long,
+        private val index: Int,
+        override var pendingContentPtr: Long,
+        val deletable: WuiComputedBool?,
+        val leadingActions: Array<SwipeActionStruct>,
+        val trailingActions: Array<SwipeActionStruct>
+    ) : ListRow() {
+        override val viewType: Int = VIEW_TYPE_ITEM
+        private val labels = HashMap<Long, String>()
+
+        fun isDeletable(): Boolean = deletable?.value ?: true
+
+        fun labelFor(action: SwipeActionStruct): String = labels.getOrPut(action.labelPtr) {
+            if (action.labelPtr == 0L) return@getOrPut ""
+            val styledStr = NativeBindings.waterui_read_computed_styled_str(action.labelPtr)
+            styledStr.chunks.joinToString("") { it.text }
+        }
+
+        override fun takeContent(): Long {
+            val pending = pendingContentPtr
+            if (pending != 0L) {
+                pendingContentPtr = 0L
+                return pending
+            }
+            val viewPtr = NativeBindings.waterui_any_views_get_view(sourcePtr, index)
+            if (viewPtr == 0L) return 0L
+            // Only the content is needed; release the duplicated item metadata
+            val fresh: ListItemStruct = NativeBindings.waterui_force_as_list_item(viewPtr)
+            if (fresh.deletablePtr != 0L) NativeBindings.waterui_drop_binding_bool(fresh.deletablePtr)
+            dropSwipeActions(fresh.leadingActions)
+            dropSwipeActions(fresh.trailingActions)
+            return fresh.contentPtr
+        }
+
+        override fun release() {
+            super.release()
+            deletable?.dispose()
+            dropSwipeActions(leadingActions)
+            dropSwipeActions(trailingActions)
+        }
+    }
+
+    /** Section header or footer. */
+    class Supplementary(
+        override val section: Int,
+        private val listPtr: Long,
+        private val sectionIndexInList: Int,
+        override var pendingContentPtr: Long,
+        private val header: Boolean
+    ) : ListRow() {
+        override val viewType: Int = if (header) VIEW_TYPE_HEADER else VIEW_TYPE_FOOTER
+
+        override fun takeContent(): Long {
+            val pending = pendingContentPtr
+            if (pending != 0L) {
+                pendingContentPtr = 0L
+                return pending
+            }
+            val viewPtr = NativeBindings.waterui_any_views_get_view(listPtr, sectionIndexInList)
+            if (viewPtr == 0L) return 0L
+            val fresh: ListSectionStruct = NativeBindings.waterui_force_as_list_section(viewPtr)
+            val (keep, discard) = if (header) {
+                fresh.headerPtr to fresh.footerPtr
+            } else {
+                fresh.footerPtr to fresh.headerPtr
+            }
+            if (discard != 0L) NativeBindings.waterui_drop_anyview(discard)
+            if (fresh.contentsPtr != 0L) NativeBindings.waterui_drop_any_views(fresh.contentsPtr)
+            if (fresh.onDeletePtr != 0L) NativeBindings.waterui_drop_index_action(fresh.onDeletePtr)
+            if (fresh.onMovePtr != 0L) NativeBindings.waterui_drop_move_action(fresh.onMovePtr)
+            return keep
+        }
+    }
+
+    /** Whether this row is a section header. */
+    val isHeader: Boolean
+        get() = viewType == VIEW_TYPE_HEADER
+}
 
 /**
  * RecyclerView adapter for WaterUI List.
  */
 private class WuiListAdapter(
     private val context: Context,
-    private val items: MutableList<ListItemData>,
+    private val rows: MutableList<ListRow>,
     private val env: WuiEnvironment,
-    private val registry: RenderRegistry
+    private val registry: RenderRegistry,
+    private val selection: WuiBinding<Int>?
 ) : RecyclerView.Adapter<WuiListAdapter.ViewHolder>() {
 
     class ViewHolder(val container: FrameLayout) : RecyclerView.ViewHolder(container)
 
+    override fun getItemViewType(position: Int): Int = rows[position].viewType
+
     override fun onCreateViewHolder(parent: ViewGroup, viewType: Int): ViewHolder {
         val container = FrameLayout(context).apply {
             layoutParams = ViewGroup.LayoutParams(
@@ -240,22 +543,152 @@ private class WuiListAdapter(
         return ViewHolder(container)
     }
 
+    /**
+     * Refreshes the highlight of the row with [id] without rebinding its content.
+     */
+    fun notifySelectionChanged(id: Int) {
+        val position = rows.indexOfFirst { it is ListRow.Item && it.id == id }
+        if (position >= 0) notifyItemChanged(position, PAYLOAD_SELECTION)
+    }
+
+    override fun onBindViewHolder(holder: ViewHolder, position: Int, payloads: MutableList<Any>) {
+        if (payloads.isNotEmpty() && payloads.all { it === PAYLOAD_SELECTION }) {
+            bindSelection(holder, rows[position])
+        } else {
+            onBindViewHolder(holder, position)
+        }
+    }
+
     override fun onBindViewHolder(holder: ViewHolder, position: Int) {
-        val item = items[position]
+        val row = rows[position]
         holder.container.removeAllViews()
 
-        if (item.contentPtr != 0L) {
-            val contentView = inflateAnyView(context, item.contentPtr, env, registry)
+        val contentPtr = row.takeContent()
+        if (contentPtr != 0L) {
+            val contentView = inflateAnyView(context, contentPtr, env, registry)
             holder.container.addView(contentView, ViewGroup.LayoutParams(
                 ViewGroup.LayoutParams.MATCH_PARENT,
                 ViewGroup.LayoutParams.WRAP_CONTENT
             ))
         }
+        bindSelection(holder, row)
     }
 
-    override fun getItemCount(): Int = items.size
+    private fun bindSelection(holder: ViewHolder, row: ListRow) {
+        if (row is ListRow.Item && selection != null) {
+            holder.container.isActivated = selection.current() == row.id
+            holder.container.foreground = if (holder.container.isActivated) {
+                ColorDrawable(SELECTED_OVERLAY)
+            } else null
+            holder.container.setOnClickListener { selection.set(row.id) }
+        } else {
+            holder.container.foreground = null
+            holder.container.setOnClickListener(null)
+            holder.container.isClickable = false
+        }
+    }
+
+    override fun onViewRecycled(holder: ViewHolder) {
+        holder.container.removeAllViews()
+    }
+
+    override fun getItemCount(): Int = rows.size
 
-    override fun getItemId(position: Int): Long = items[position].id.toLong()
+    companion object {
+        private const val SELECTED_OVERLAY = 0x1F000000
+        private val PAYLOAD_SELECTION = Any()
+    }
+}
+
+/**
+ * Draws separators between consecutive item rows of the same section.
+ */
+private class ListSeparatorDecoration(
+    private val rows: List<ListRow>,
+    private val thickness: Float
+) : RecyclerView.ItemDecoration() {
+    private val paint = Paint()
+
+    var color: Int
+        get() = paint.color
+        set(value) { paint.color = value }
+
+    override fun onDraw(c: Canvas, parent: RecyclerView, state: RecyclerView.State) {
+        for (i in 0 until parent.childCount) {
+            val child = parent.getChildAt(i)
+            val position = parent.getChildAdapterPosition(child)
+            if (position == RecyclerView.NO_POSITION) continue
+            val row = rows.getOrNull(position) as? ListRow.Item ?: continue
+            val next = rows.getOrNull(position + 1) as? ListRow.Item ?: continue
+            if (next.section != row.section) continue
+
+            val bottom = child.bottom + child.translationY
+            c.drawRect(
+                child.left + child.translationX,
+                bottom - thickness,
+                child.right + child.translationX,
+                bottom,
+                paint
+            )
+        }
+    }
+}
+
+/**
+ * Keeps the header of the topmost section pinned while its rows scroll beneath it.
+ * The next section's header pushes the pinned one out as it arrives.
+ */
+private class StickyHeaderDecoration(
+    private val rows: List<ListRow>
+) : RecyclerView.ItemDecoration() {
+    private val snapshots = HashMap<Int, Bitmap>()
+
+    override fun onDrawOver(c: Canvas, parent: RecyclerView, state: RecyclerView.State) {
+        // Refresh snapshots of visible headers so pinned content stays current
+        for (i in 0 until parent.childCount) {
+            val child = parent.getChildAt(i)
+            val row = rows.getOrNull(parent.getChildAdapterPosition(child)) ?: continue
+            if (row.isHeader) capture(row.section ?: continue, child)
+        }
+
+        val top = parent.getChildAt(0) ?: return
+        val topPosition = parent.getChildAdapterPosition(top)
+        val section = rows.getOrNull(topPosition)?.section ?: return
+        if (rows[topPosition].isHeader && top.top >= 0) return
+        val bitmap = snapshots[section] ?: return
+
+        var offset = 0f
+        for (i in 1 until parent.childCount) {
+            val child = parent.getChildAt(i)
+            val row = rows.getOrNull(parent.getChildAdapterPosition(child)) ?: continue
+            if (row.isHeader && row.section != section && child.top < bitmap.height) {
+                offset = (child.top - bitmap.height).toFloat()
+                break
+            }
+        }
+
+        c.drawBitmap(bitmap, 0f, offset, null)
+    }
+
+    /** Recycles every cached snapshot. */
+    fun clear() {
+        snapshots.values.forEach { it.recycle() }
+        snapshots.clear()
+    }
+
+    private fun capture(section: Int, view: View) {
+        if (view.width <= 0 || view.height <= 0) return
+        val existing = snapshots[section]
+        val bitmap = if (existing != null && existing.width == view.width && existing.height == view.height) {
+            existing.eraseColor(Color.TRANSPARENT)
+            existing
+        } else {
+            existing?.recycle()
+            Bitmap.createBitmap(view.width, view.height, Bitmap.Config.ARGB_8888)
+        }
+        view.draw(Canvas(bitmap))
+        snapshots[section] = bitmap
+    }
 }
 
 /**
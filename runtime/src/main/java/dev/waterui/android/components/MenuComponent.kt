@@ -4,9 +4,13 @@ import android.content.res.ColorStateList
 import android.graphics.Color
 import android.graphics.drawable.GradientDrawable
 import android.graphics.drawable.RippleDrawable
+import android.view.Menu
 import android.view.View
 import android.widget.FrameLayout
 import android.widget.PopupMenu
+import androidx.core.view.MenuCompat
+import dev.waterui.android.runtime.MenuItemKind
+import dev.waterui.android.runtime.MenuItemStruct
 import dev.waterui.android.runtime.NativeBindings
 import dev.waterui.android.runtime.RegistryBuilder
 import dev.waterui.android.runtime.StretchAxis
@@ -82,7 +86,7 @@ private val menuRenderer = WuiRenderer { context, node, env, registry ->
     // Setup click listener to show menu
     if (menuData.itemsPtr != 0L) {
         container.setOnClickListener { view ->
-            showMenu(view, menuData.itemsPtr, envPtr)
+            showMenuItems(view, menuData.itemsPtr, envPtr)
         }
     }
 
@@ -98,23 +102,21 @@ private val menuRenderer = WuiRenderer { context, node, env, registry ->
 
 /**
  * Shows a popup menu with the specified items.
+ * Shared by Menu and context menus.
+ *
+ * Dividers start a new menu group, toggles are checkable items and submenus
+ * open their nested items.
  */
-private fun showMenu(anchor: View, itemsPtr: Long, envPtr: Long) {
+internal fun showMenuItems(anchor: View, itemsPtr: Long, envPtr: Long) {
     val items = NativeBindings.waterui_read_computed_menu_items(itemsPtr)
     if (items.isEmpty()) return
 
     val popup = PopupMenu(anchor.context, anchor)
-
-    items.forEachIndexed { index, item ->
-        // Read the styled text to get the label
-        val styledStr = NativeBindings.waterui_read_computed_styled_str(item.labelPtr)
-        val label = styledStr.chunks.joinToString("") { it.text }
-
-        popup.menu.add(0, index, index, label)
-    }
+    val actionItems = mutableListOf<MenuItemStruct>()
+    addMenuItems(popup.menu, items, actionItems)
 
     popup.setOnMenuItemClickListener { menuItem ->
-        val item = items.getOrNull(menuItem.itemId)
+        val item = actionItems.getOrNull(menuItem.itemId)
         if (item != null && item.actionPtr != 0L) {
             NativeBindings.waterui_call_shared_action(item.actionPtr, envPtr)
         }
@@ -124,6 +126,41 @@ private fun showMenu(anchor: View, itemsPtr: Long, envPtr: Long) {
     popup.show()
 }
 
+/**
+ * Adds [items] to [menu]. Items with actions are assigned ids by their index
+ * in [actionItems] so clicks in any submenu resolve to the right action.
+ */
+private fun addMenuItems(menu: Menu, items: Array<MenuItemStruct>, actionItems: MutableList<MenuItemStruct>) {
+    MenuCompat.setGroupDividerEnabled(menu, true)
+    var group = 0
+
+    for (item in items) {
+        if (item.kind == MenuItemKind.DIVIDER) {
+            group++
+            continue
+        }
+
+        // Read the styled text to get the label
+        val styledStr = NativeBindings.waterui_read_computed_styled_str(item.labelPtr)
+        val label = styledStr.chunks.joinToString("") { it.text }
+
+        if (item.kind == MenuItemKind.SUBMENU) {
+            val submenu = menu.addSubMenu(group, Menu.NONE, Menu.NONE, label)
+            if (item.submenuPtr != 0L) {
+                addMenuItems(submenu, NativeBindings.waterui_read_computed_menu_items(item.submenuPtr), actionItems)
+            }
+            continue
+        }
+
+        val menuItem = menu.add(group, actionItems.size, Menu.NONE, label)
+        actionItems.add(item)
+        if (item.kind == MenuItemKind.TOGGLE) {
+            menuItem.isCheckable = true
+            menuItem.isChecked = item.checked
+        }
+    }
+}
+
 /**
  * Adjusts the alpha of a color.
  */
@@ -1,8 +1,6 @@
 package dev.waterui.android.components
 
-import android.view.View
 import android.widget.FrameLayout
-import android.widget.PopupMenu
 import dev.waterui.android.runtime.NativeBindings
 import dev.waterui.android.runtime.RegistryBuilder
 import dev.waterui.android.runtime.TAG_STRETCH_AXIS
@@ -38,7 +36,7 @@ private val metadataContextMenuRenderer = WuiRenderer { context, node, env, regi
     // Setup long-press listener to show context menu
     if (menuData.itemsPtr != 0L) {
         container.setOnLongClickListener { view ->
-            showContextMenu(view, menuData.itemsPtr, envPtr)
+            showMenuItems(view, menuData.itemsPtr, envPtr)
             true
         }
     }
@@ -53,31 +51,6 @@ private val metadataContextMenuRenderer = WuiRenderer { context, node, env, regi
     container
 }
 
-private fun showContextMenu(anchor: View, itemsPtr: Long, envPtr: Long) {
-    val items = NativeBindings.waterui_read_computed_menu_items(itemsPtr)
-    if (items.isEmpty()) return
-
-    val popup = PopupMenu(anchor.context, anchor)
-
-    items.forEachIndexed { index, item ->
-        // Read the styled text to get the label
-        val styledStr = NativeBindings.waterui_read_computed_styled_str(item.labelPtr)
-        val label = styledStr.chunks.joinToString("") { it.text }
-
-        popup.menu.add(0, index, index, label)
-    }
-
-    popup.setOnMenuItemClickListener { menuItem ->
-        val item = items.getOrNull(menuItem.itemId)
-        if (item != null && item.actionPtr != 0L) {
-            NativeBindings.waterui_call_shared_action(item.actionPtr, envPtr)
-        }
-        true
-    }
-
-    popup.show()
-}
-
 internal fun RegistryBuilder.registerWuiContextMenu() {
     registerMetadata({ metadataContextMenuTypeId }, metadataContextMenuRenderer)
 }
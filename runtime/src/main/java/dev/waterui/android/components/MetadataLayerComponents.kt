@@ -0,0 +1,159 @@
+package dev.waterui.android.components
+
+import android.content.Context
+import android.view.Gravity
+import android.view.View
+import dev.waterui.android.layout.PassThroughFrameLayout
+import dev.waterui.android.runtime.MetadataLayerStruct
+import dev.waterui.android.runtime.NativeBindings
+import dev.waterui.android.runtime.RegistryBuilder
+import dev.waterui.android.runtime.RenderRegistry
+import dev.waterui.android.runtime.TAG_STRETCH_AXIS
+import dev.waterui.android.runtime.WuiEnvironment
+import dev.waterui.android.runtime.WuiRenderer
+import dev.waterui.android.runtime.WuiTypeId
+import dev.waterui.android.runtime.getWuiStretchAxis
+import dev.waterui.android.runtime.inflateAnyView
+
+private val metadataOverlayTypeId: WuiTypeId by lazy {
+    NativeBindings.waterui_metadata_overlay_id().toTypeId()
+}
+
+private val metadataBackgroundViewTypeId: WuiTypeId by lazy {
+    NativeBindings.waterui_metadata_background_view_id().toTypeId()
+}
+
+/**
+ * Maps a native `Alignment` (top-leading to bottom-trailing, row by row) to a gravity.
+ */
+private fun alignmentGravity(alignment: Int): Int {
+    val vertical = when (alignment / 3) {
+        0 -> Gravity.TOP
+        2 -> Gravity.BOTTOM
+        else -> Gravity.CENTER_VERTICAL
+    }
+    val horizontal = when (alignment % 3) {
+        0 -> Gravity.START
+        2 -> Gravity.END
+        else -> Gravity.CENTER_HORIZONTAL
+    }
+    return vertical or horizontal
+}
+
+/**
+ * Container that sizes itself to its content and lays a secondary view
+ * above or below it.
+ *
+ * The layer is proposed the content's size and positioned within the content
+ * bounds by [gravity]; it never contributes to the container's size.
+ */
+private class LayeredFrameLayout(
+    context: Context,
+    private val content: View,
+    private val layer: View,
+    private val gravity: Int,
+    layerAbove: Boolean
+) : PassThroughFrameLayout(context) {
+
+    init {
+        if (layerAbove) {
+            addView(content)
+            addView(layer)
+        } else {
+            addView(layer)
+            addView(content)
+        }
+    }
+
+    override fun onMeasure(widthMeasureSpec: Int, heightMeasureSpec: Int) {
+        content.measure(widthMeasureSpec, heightMeasureSpec)
+        val width = content.measuredWidth
+        val height = content.measuredHeight
+        layer.measure(
+            MeasureSpec.makeMeasureSpec(width, MeasureSpec.AT_MOST),
+            MeasureSpec.makeMeasureSpec(height, MeasureSpec.AT_MOST)
+        )
+        setMeasuredDimension(width, height)
+    }
+
+    override fun onLayout(changed: Boolean, left: Int, top: Int, right: Int, bottom: Int) {
+        val width = right - left
+        val height = bottom - top
+        content.layout(0, 0, content.measuredWidth, content.measuredHeight)
+
+        val layerWidth = layer.measuredWidth
+        val layerHeight = layer.measuredHeight
+        val absolute = Gravity.getAbsoluteGravity(gravity, layoutDirection)
+        val x = when (absolute and Gravity.HORIZONTAL_GRAVITY_MASK) {
+            Gravity.LEFT -> 0
+            Gravity.RIGHT -> width - layerWidth
+            else -> (width - layerWidth) / 2
+        }
+        val y = when (gravity and Gravity.VERTICAL_GRAVITY_MASK) {
+            Gravity.TOP -> 0
+            Gravity.BOTTOM -> height - layerHeight
+            else -> (height - layerHeight) / 2
+        }
+        layer.layout(x, y, x + layerWidth, y + layerHeight)
+    }
+}
+
+/**
+ * Shared renderer body for Metadata<Overlay> and Metadata<BackgroundView>.
+ */
+private fun renderLayer(
+    context: Context,
+    metadata: MetadataLayerStruct,
+    env: WuiEnvironment,
+    registry: RenderRegistry,
+    layerAbove: Boolean
+): View {
+    val content = if (metadata.contentPtr != 0L) {
+        inflateAnyView(context, metadata.contentPtr, env, registry)
+    } else {
+        View(context)
+    }
+    val layer = if (metadata.layerPtr != 0L) {
+        inflateAnyView(context, metadata.layerPtr, env, registry)
+    } else {
+        View(context)
+    }
+
+    val container = LayeredFrameLayout(
+        context,
+        content,
+        layer,
+        alignmentGravity(metadata.alignment),
+        layerAbove
+    )
+    container.setTag(TAG_STRETCH_AXIS, content.getWuiStretchAxis())
+    return container
+}
+
+/**
+ * Renderer for Metadata<Overlay>.
+ *
+ * Draws the overlay view above the content, aligned within the content bounds.
+ */
+private val metadataOverlayRenderer = WuiRenderer { context, node, env, registry ->
+    val metadata = NativeBindings.waterui_force_as_metadata_overlay(node.rawPtr)
+    renderLayer(context, metadata, env, registry, layerAbove = true)
+}
+
+/**
+ * Renderer for Metadata<BackgroundView>.
+ *
+ * Draws the background view below the content, aligned within the content bounds.
+ */
+private val metadataBackgroundViewRenderer = WuiRenderer { context, node, env, registry ->
+    val metadata = NativeBindings.waterui_force_as_metadata_background_view(node.rawPtr)
+    renderLayer(context, metadata, env, registry, layerAbove = false)
+}
+
+internal fun RegistryBuilder.registerWuiOverlay() {
+    registerMetadata({ metadataOverlayTypeId }, metadataOverlayRenderer)
+}
+
+internal fun RegistryBuilder.registerWuiBackgroundView() {
+    registerMetadata({ metadataBackgroundViewTypeId }, metadataBackgroundViewRenderer)
+}
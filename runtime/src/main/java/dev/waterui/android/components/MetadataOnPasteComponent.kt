@@ -0,0 +1,101 @@
+package dev.waterui.android.components
+
+import android.content.Context
+import android.view.View
+import android.view.ViewGroup
+import android.widget.EditText
+import androidx.core.view.ContentInfoCompat
+import androidx.core.view.OnReceiveContentListener
+import androidx.core.view.ViewCompat
+import dev.waterui.android.layout.PassThroughFrameLayout
+import dev.waterui.android.runtime.NativeBindings
+import dev.waterui.android.runtime.RegistryBuilder
+import dev.waterui.android.runtime.TAG_STRETCH_AXIS
+import dev.waterui.android.runtime.WuiRenderer
+import dev.waterui.android.runtime.WuiTypeId
+import dev.waterui.android.runtime.disposeWith
+import dev.waterui.android.runtime.getWuiStretchAxis
+import dev.waterui.android.runtime.inflateAnyView
+
+private val metadataOnPasteTypeId: WuiTypeId by lazy {
+    NativeBindings.waterui_metadata_on_paste_id().toTypeId()
+}
+
+private val PASTE_MIME_TYPES = arrayOf("text/*")
+
+/**
+ * Marks the scope of an OnPaste handler, so an enclosing handler leaves the
+ * editors of a nested one alone.
+ */
+private class PasteScopeLayout(context: Context) : PassThroughFrameLayout(context)
+
+/**
+ * Installs [listener] on every editable text view below [view], stopping at nested
+ * paste scopes.
+ */
+private fun attachToEditors(view: View, listener: OnReceiveContentListener) {
+    if (view is EditText) {
+        ViewCompat.setOnReceiveContentListener(view, PASTE_MIME_TYPES, listener)
+    }
+    if (view is ViewGroup) {
+        for (i in 0 until view.childCount) {
+            val child = view.getChildAt(i)
+            if (child !is PasteScopeLayout) attachToEditors(child, listener)
+        }
+    }
+}
+
+/**
+ * Renderer for Metadata<OnPaste>.
+ *
+ * Android has no paste command for views in general, so the scope is the
+ * editable text views inside the wrapped content. Text pasted into them, by
+ * context menu, Ctrl+V, drag and drop or IME commit, goes to the handler in
+ * place of being inserted. The container itself stays out of focus traversal.
+ * The handler can be called multiple times (Fn, repeatable).
+ */
+private val metadataOnPasteRenderer = WuiRenderer { context, node, env, registry ->
+    val onPasteData = NativeBindings.waterui_force_as_metadata_on_paste(node.rawPtr)
+
+    val container = PasteScopeLayout(context)
+    val envPtr = env.raw()
+    val handlerPtr = onPasteData.handlerPtr
+
+    // Inflate the content
+    if (onPasteData.contentPtr != 0L) {
+        val child = inflateAnyView(context, onPasteData.contentPtr, env, registry)
+        container.addView(child)
+        container.setTag(TAG_STRETCH_AXIS, child.getWuiStretchAxis())
+    }
+
+    val listener = OnReceiveContentListener { view, payload ->
+        val split = payload.partition { item -> item.text != null }
+        val accepted: ContentInfoCompat? = split.first
+        if (accepted != null) {
+            val clip = accepted.clip
+            val text = buildString {
+                for (i in 0 until clip.itemCount) {
+                    append(clip.getItemAt(i).coerceToText(view.context))
+                }
+            }
+            NativeBindings.waterui_call_on_paste(handlerPtr, envPtr, text)
+        }
+        split.second
+    }
+
+    // Content can change below the container (Dynamic, lazy rows), so reattach after each layout
+    attachToEditors(container, listener)
+    container.addOnLayoutChangeListener { view, _, _, _, _, _, _, _, _ -> attachToEditors(view, listener) }
+
+    // Cleanup
+    container.disposeWith {
+        // Drop the repeatable handler
+        NativeBindings.waterui_drop_on_paste(handlerPtr)
+    }
+
+    container
+}
+
+internal fun RegistryBuilder.registerWuiOnPaste() {
+    registerMetadata({ metadataOnPasteTypeId }, metadataOnPasteRenderer)
+}
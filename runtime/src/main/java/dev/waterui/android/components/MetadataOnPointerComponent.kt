@@ -0,0 +1,111 @@
+package dev.waterui.android.components
+
+import android.content.Context
+import android.view.MotionEvent
+import dev.waterui.android.layout.PassThroughFrameLayout
+import dev.waterui.android.runtime.NativeBindings
+import dev.waterui.android.runtime.PointerKind
+import dev.waterui.android.runtime.PointerPhase
+import dev.waterui.android.runtime.RegistryBuilder
+import dev.waterui.android.runtime.TAG_STRETCH_AXIS
+import dev.waterui.android.runtime.WuiRenderer
+import dev.waterui.android.runtime.WuiTypeId
+import dev.waterui.android.runtime.disposeWith
+import dev.waterui.android.runtime.getWuiStretchAxis
+import dev.waterui.android.runtime.inflateAnyView
+
+private val metadataOnPointerTypeId: WuiTypeId by lazy {
+    NativeBindings.waterui_metadata_on_pointer_id().toTypeId()
+}
+
+/**
+ * Sees every pointer event aimed at its content before the content does, so
+ * pointers over buttons and scrollables are reported too. Events are passed on
+ * unchanged; the layout keeps a gesture no descendant takes, so Rust still sees
+ * its later phases.
+ */
+private class PointerTrackingLayout(
+    context: Context,
+    private val onPointer: (MotionEvent) -> Unit
+) : PassThroughFrameLayout(context) {
+    override fun dispatchTouchEvent(ev: MotionEvent): Boolean {
+        onPointer(ev)
+        super.dispatchTouchEvent(ev)
+        return true
+    }
+
+    override fun dispatchHoverEvent(event: MotionEvent): Boolean {
+        if (event.actionMasked == MotionEvent.ACTION_HOVER_MOVE) {
+            onPointer(event)
+        }
+        return super.dispatchHoverEvent(event)
+    }
+}
+
+/**
+ * Renderer for Metadata<OnPointer>.
+ *
+ * Forwards raw touch, mouse and stylus events of the wrapped view to Rust,
+ * one call per pointer, with coordinates in points relative to the view.
+ * Canvases use this for hit testing in canvas space.
+ */
+private val metadataOnPointerRenderer = WuiRenderer { context, node, env, registry ->
+    val metadata = NativeBindings.waterui_force_as_metadata_on_pointer(node.rawPtr)
+
+    val handlerPtr = metadata.handlerPtr
+    val density = context.resources.displayMetrics.density
+
+    fun report(event: MotionEvent, index: Int, phase: Int) {
+        val kind = when (event.getToolType(index)) {
+            MotionEvent.TOOL_TYPE_MOUSE -> PointerKind.MOUSE
+            MotionEvent.TOOL_TYPE_STYLUS, MotionEvent.TOOL_TYPE_ERASER -> PointerKind.PEN
+            else -> PointerKind.TOUCH
+        }
+        // Devices without pressure sensing report 0 or 1; normalize to 1
+        val pressure = event.getPressure(index).takeIf { it > 0f } ?: 1f
+        NativeBindings.waterui_call_on_pointer(
+            handlerPtr,
+            phase,
+            kind,
+            event.getPointerId(index),
+            event.getX(index) / density,
+            event.getY(index) / density,
+            pressure
+        )
+    }
+
+    val container = PointerTrackingLayout(context) { event ->
+        when (event.actionMasked) {
+            MotionEvent.ACTION_DOWN, MotionEvent.ACTION_POINTER_DOWN ->
+                report(event, event.actionIndex, PointerPhase.DOWN)
+            MotionEvent.ACTION_MOVE ->
+                for (i in 0 until event.pointerCount) report(event, i, PointerPhase.MOVE)
+            MotionEvent.ACTION_UP, MotionEvent.ACTION_POINTER_UP ->
+                report(event, event.actionIndex, PointerPhase.UP)
+            MotionEvent.ACTION_CANCEL ->
+                for (i in 0 until event.pointerCount) report(event, i, PointerPhase.CANCEL)
+            MotionEvent.ACTION_HOVER_MOVE -> report(event, 0, PointerPhase.HOVER)
+        }
+    }.apply {
+        consumesTouches = true
+        setTag(PassThroughFrameLayout.TAG_WANTS_TOUCHES, true)
+    }
+
+    // Inflate the content
+    if (metadata.contentPtr != 0L) {
+        val child = inflateAnyView(context, metadata.contentPtr, env, registry)
+        container.addView(child)
+        container.setTag(TAG_STRETCH_AXIS, child.getWuiStretchAxis())
+    }
+
+    // Cleanup
+    container.disposeWith {
+        NativeBindings.waterui_drop_on_pointer(handlerPtr)
+    }
+
+    container
+}
+
+internal fun RegistryBuilder.registerWuiOnPointer() {
+    registerMetadata({ metadataOnPointerTypeId }, metadataOnPointerRenderer)
+}
@@ -0,0 +1,54 @@
+package dev.waterui.android.components
+
+import dev.waterui.android.layout.PassThroughFrameLayout
+import dev.waterui.android.runtime.NativeBindings
+import dev.waterui.android.runtime.RegistryBuilder
+import dev.waterui.android.runtime.TAG_STRETCH_AXIS
+import dev.waterui.android.runtime.WuiRenderer
+import dev.waterui.android.runtime.WuiTypeId
+import dev.waterui.android.runtime.disposeWith
+import dev.waterui.android.runtime.getWuiStretchAxis
+import dev.waterui.android.runtime.inflateAnyView
+
+private val metadataOnSizeChangeTypeId: WuiTypeId by lazy {
+    NativeBindings.waterui_metadata_on_size_change_id().toTypeId()
+}
+
+/**
+ * Renderer for Metadata<OnSizeChange>.
+ *
+ * Reports the laid-out size of the wrapped view (in points) back to Rust after
+ * every layout pass in which it changed, updating the bound `Size`.
+ */
+private val metadataOnSizeChangeRenderer = WuiRenderer { context, node, env, registry ->
+    val metadata = NativeBindings.waterui_force_as_metadata_on_size_change(node.rawPtr)
+
+    val container = PassThroughFrameLayout(context)
+    val handlerPtr = metadata.handlerPtr
+    val density = context.resources.displayMetrics.density
+
+    // Inflate the content
+    if (metadata.contentPtr != 0L) {
+        val child = inflateAnyView(context, metadata.contentPtr, env, registry)
+        container.addView(child)
+        container.setTag(TAG_STRETCH_AXIS, child.getWuiStretchAxis())
+    }
+
+    container.addOnLayoutChangeListener { _, left, top, right, bottom, oldLeft, oldTop, oldRight, oldBottom ->
+        val width = right - left
+        val height = bottom - top
+        if (width == oldRight - oldLeft && height == oldBottom - oldTop) return@addOnLayoutChangeListener
+        NativeBindings.waterui_call_on_size_change(handlerPtr, width / density, height / density)
+    }
+
+    // Cleanup
+    container.disposeWith {
+        NativeBindings.waterui_drop_on_size_change(handlerPtr)
+    }
+
+    container
+}
+
+internal fun RegistryBuilder.registerWuiOnSizeChange() {
+    registerMetadata({ metadataOnSizeChangeTypeId }, metadataOnSizeChangeRenderer)
+}
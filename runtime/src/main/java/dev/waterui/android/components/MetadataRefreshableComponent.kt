@@ -0,0 +1,82 @@
+package dev.waterui.android.components
+
+import android.view.View
+import android.view.ViewGroup
+import androidx.swiperefreshlayout.widget.SwipeRefreshLayout
+import dev.waterui.android.runtime.NativeBindings
+import dev.waterui.android.runtime.RegistryBuilder
+import dev.waterui.android.runtime.TAG_STRETCH_AXIS
+import dev.waterui.android.runtime.ThemeBridge
+import dev.waterui.android.runtime.WuiRenderer
+import dev.waterui.android.runtime.WuiTypeId
+import dev.waterui.android.runtime.attachTo
+import dev.waterui.android.runtime.disposeWith
+import dev.waterui.android.runtime.getWuiStretchAxis
+import dev.waterui.android.runtime.inflateAnyView
+import dev.waterui.android.runtime.toColorInt
+
+private val metadataRefreshableTypeId: WuiTypeId by lazy {
+    NativeBindings.waterui_metadata_refreshable_id().toTypeId()
+}
+
+/**
+ * Renderer for Metadata<Refreshable>.
+ *
+ * Wraps the scroll view or list in a [SwipeRefreshLayout]. Pulling down starts
+ * the Rust refresh closure, and the spinner stays visible until its future
+ * completes.
+ */
+private val metadataRefreshableRenderer = WuiRenderer { context, node, env, registry ->
+    val metadata = NativeBindings.waterui_force_as_metadata_refreshable(node.rawPtr)
+
+    val container = SwipeRefreshLayout(context)
+    val envPtr = env.raw()
+    val handlerPtr = metadata.handlerPtr
+    var disposed = false
+
+    // Inflate the content
+    if (metadata.contentPtr != 0L) {
+        val child = inflateAnyView(context, metadata.contentPtr, env, registry)
+        container.addView(
+            child,
+            ViewGroup.LayoutParams(ViewGroup.LayoutParams.MATCH_PARENT, ViewGroup.LayoutParams.MATCH_PARENT)
+        )
+        container.setTag(TAG_STRETCH_AXIS, child.getWuiStretchAxis())
+
+        // The scrollable view may be nested inside metadata wrappers
+        container.setOnChildScrollUpCallback { _, _ -> canAnyScrollUp(child) }
+    }
+
+    container.setOnRefreshListener {
+        NativeBindings.waterui_call_refreshable(handlerPtr, envPtr) {
+            // A refresh may outlive the view
+            if (!disposed) container.isRefreshing = false
+        }
+    }
+
+    val accent = ThemeBridge.accent(env)
+    accent.observe { color -> container.setColorSchemeColors(color.toColorInt()) }
+    accent.attachTo(container)
+
+    // Cleanup
+    container.disposeWith {
+        disposed = true
+        NativeBindings.waterui_drop_refreshable(handlerPtr)
+    }
+
+    container
+}
+
+private fun canAnyScrollUp(view: View): Boolean {
+    if (view.canScrollVertically(-1)) return true
+    if (view is ViewGroup) {
+        for (i in 0 until view.childCount) {
+            if (canAnyScrollUp(view.getChildAt(i))) return true
+        }
+    }
+    return false
+}
+
+internal fun RegistryBuilder.registerWuiRefreshable() {
+    registerMetadata({ metadataRefreshableTypeId }, metadataRefreshableRenderer)
+}
@@ -0,0 +1,42 @@
+package dev.waterui.android.components
+
+import dev.waterui.android.layout.PassThroughFrameLayout
+import dev.waterui.android.runtime.NativeBindings
+import dev.waterui.android.runtime.RegistryBuilder
+import dev.waterui.android.runtime.TAG_STRETCH_AXIS
+import dev.waterui.android.runtime.TAG_VIEW_ID
+import dev.waterui.android.runtime.WuiRenderer
+import dev.waterui.android.runtime.WuiTypeId
+import dev.waterui.android.runtime.getWuiStretchAxis
+import dev.waterui.android.runtime.inflateAnyView
+
+private val metadataViewIdTypeId: WuiTypeId by lazy {
+    NativeBindings.waterui_metadata_view_id_id().toTypeId()
+}
+
+/**
+ * Renderer for Metadata<ViewId>.
+ *
+ * Tags the container with the view's id so an enclosing scroll view can
+ * locate it for `scroll_to(id, anchor)`, and so a `Dynamic` rebuild carries
+ * its scroll, focus and selection state over.
+ */
+private val metadataViewIdRenderer = WuiRenderer { context, node, env, registry ->
+    val metadata = NativeBindings.waterui_force_as_metadata_view_id(node.rawPtr)
+
+    val container = PassThroughFrameLayout(context)
+    container.setTag(TAG_VIEW_ID, metadata.id)
+
+    // Inflate the content
+    if (metadata.contentPtr != 0L) {
+        val child = inflateAnyView(context, metadata.contentPtr, env, registry)
+        container.addView(child)
+        container.setTag(TAG_STRETCH_AXIS, child.getWuiStretchAxis())
+    }
+
+    container
+}
+
+internal fun RegistryBuilder.registerWuiViewId() {
+    registerMetadata({ metadataViewIdTypeId }, metadataViewIdRenderer)
+}
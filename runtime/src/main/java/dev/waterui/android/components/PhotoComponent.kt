@@ -1,77 +1,125 @@
 package dev.waterui.android.components
 
-import android.graphics.BitmapFactory
+import android.content.res.ColorStateList
+import android.graphics.Outline
+import android.graphics.PorterDuff
+import android.view.View
 import android.view.ViewGroup
+import android.view.ViewOutlineProvider
 import android.widget.ImageView
+import dev.waterui.android.reactive.WuiComputed
+import dev.waterui.android.runtime.ImageLoader
 import dev.waterui.android.runtime.NativeBindings
+import dev.waterui.android.runtime.PhotoEventType
 import dev.waterui.android.runtime.RegistryBuilder
 import dev.waterui.android.runtime.WuiRenderer
 import dev.waterui.android.runtime.WuiTypeId
+import dev.waterui.android.runtime.attachTo
+import dev.waterui.android.runtime.disposeWith
 import dev.waterui.android.runtime.dp
-import kotlinx.coroutines.CoroutineScope
-import kotlinx.coroutines.Dispatchers
-import kotlinx.coroutines.Job
-import kotlinx.coroutines.launch
-import kotlinx.coroutines.withContext
-import java.net.URL
+import dev.waterui.android.runtime.toColorInt
+import java.util.concurrent.Future
+import kotlin.math.roundToInt
 
 private val photoTypeId: WuiTypeId by lazy { NativeBindings.waterui_photo_id().toTypeId() }
 
 /**
  * Photo component renderer.
  *
- * Displays an image from a URL using Android's ImageView.
- * Loads the image asynchronously and displays it when ready.
+ * Displays an image from a URL using Android's ImageView. The image is loaded
+ * through [ImageLoader] and reported to Rust as a Loaded or Error event.
+ *
+ * Resizable images are loaded once the view has been laid out, downsampled to
+ * the displayed size and scaled according to the content mode. Other images
+ * are shown at their natural size, one image pixel per point.
  */
 private val photoRenderer = WuiRenderer { context, node, env, registry ->
     val struct = NativeBindings.waterui_force_as_photo(node.rawPtr)
+    val density = context.resources.displayMetrics.density
 
     val imageView = object : ImageView(context) {
-        private var loadJob: Job? = null
+        private var loadJob: Future<*>? = null
+        private var loadGeneration = 0
+        private var loadedWidth = 0
+        private var loadedHeight = 0
+        private var naturalWidth = 0
+        private var naturalHeight = 0
+        private var reported = false
+        private var disposed = false
         private val defaultSizePx: Int = 200f.dp(context).toInt()
 
         init {
-            scaleType = ScaleType.FIT_CENTER
+            scaleType = when {
+                !struct.resizable -> ScaleType.FIT_XY
+                struct.contentMode == AspectRatioMode.FILL -> ScaleType.CENTER_CROP
+                struct.contentMode == AspectRatioMode.STRETCH -> ScaleType.FIT_XY
+                else -> ScaleType.FIT_CENTER
+            }
             layoutParams = ViewGroup.LayoutParams(
                 ViewGroup.LayoutParams.MATCH_PARENT,
                 ViewGroup.LayoutParams.MATCH_PARENT
             )
+            disposeWith {
+                disposed = true
+                loadJob?.cancel(true)
+                NativeBindings.waterui_drop_photo_event(struct.onEventPtr)
+            }
+
+            if (!struct.resizable) {
+                loadImage(0, 0)
+            }
+        }
 
-            // Start loading the image
-            loadImage(struct.source)
+        override fun onSizeChanged(w: Int, h: Int, oldw: Int, oldh: Int) {
+            super.onSizeChanged(w, h, oldw, oldh)
+            // Only reload when the view grew past the resolution already decoded
+            if (struct.resizable && (w > loadedWidth || h > loadedHeight)) {
+                loadImage(w, h)
+            }
         }
 
-        private fun loadImage(url: String) {
-            loadJob?.cancel()
-            loadJob = CoroutineScope(Dispatchers.IO).launch {
-                try {
-                    val connection = URL(url).openConnection()
-                    connection.connectTimeout = 10000
-                    connection.readTimeout = 10000
-                    val inputStream = connection.getInputStream()
-                    val bitmap = BitmapFactory.decodeStream(inputStream)
-                    inputStream.close()
-
-                    withContext(Dispatchers.Main) {
-                        if (bitmap != null) {
-                            setImageBitmap(bitmap)
-                            // TODO: Emit Loaded event
-                        } else {
-                            // TODO: Emit Error event
-                        }
-                    }
-                } catch (e: Exception) {
-                    // TODO: Emit Error event with message
+        private fun loadImage(width: Int, height: Int) {
+            loadJob?.cancel(true)
+            loadedWidth = width
+            loadedHeight = height
+            val generation = ++loadGeneration
+            loadJob = ImageLoader.load(context, struct.source, width, height) { result ->
+                // A result posted before cancellation may still arrive after disposal,
+                // or after a newer load for a larger size has started
+                if (disposed || generation != loadGeneration) return@load
+                result.onSuccess { bitmap ->
+                    naturalWidth = (bitmap.width * density).roundToInt()
+                    naturalHeight = (bitmap.height * density).roundToInt()
+                    setImageBitmap(bitmap)
+                    if (!struct.resizable) requestLayout()
                 }
+                report(result.exceptionOrNull())
             }
         }
 
-        override fun onDetachedFromWindow() {
-            super.onDetachedFromWindow()
-            loadJob?.cancel()
+        private fun report(error: Throwable?) {
+            if (reported) return
+            reported = true
+            if (error == null) {
+                NativeBindings.waterui_call_photo_event(struct.onEventPtr, PhotoEventType.LOADED, null)
+            } else {
+                NativeBindings.waterui_call_photo_event(
+                    struct.onEventPtr,
+                    PhotoEventType.ERROR,
+                    error.message ?: error.javaClass.simpleName
+                )
+            }
         }
 
         override fun onMeasure(widthMeasureSpec: Int, heightMeasureSpec: Int) {
+            if (!struct.resizable && drawable != null) {
+                setMeasuredDimension(
+                    View.resolveSize(naturalWidth, widthMeasureSpec),
+                    View.resolveSize(naturalHeight, heightMeasureSpec)
+                )
+                return
+            }
+
             val widthMode = MeasureSpec.getMode(widthMeasureSpec)
             val widthSize = MeasureSpec.getSize(widthMeasureSpec)
             val heightMode = MeasureSpec.getMode(heightMeasureSpec)
@@ -91,6 +139,27 @@ private val photoRenderer = WuiRenderer { context, node, env, registry ->
         }
     }
 
+    val cornerRadiusPx = struct.cornerRadius * density
+    if (cornerRadiusPx > 0f) {
+        imageView.outlineProvider = object : ViewOutlineProvider() {
+            override fun getOutline(view: View, outline: Outline) {
+                outline.setRoundRect(0, 0, view.width, view.height, cornerRadiusPx)
+            }
+        }
+        imageView.clipToOutline = true
+    } else if (struct.clipped) {
+        imageView.outlineProvider = ViewOutlineProvider.BOUNDS
+        imageView.clipToOutline = true
+    }
+
+    if (struct.tintPtr != 0L) {
+        val tint = WuiComputed.resolvedColor(struct.tintPtr, env)
+        NativeBindings.waterui_drop_color(struct.tintPtr)
+        imageView.imageTintMode = PorterDuff.Mode.SRC_IN
+        tint.observe { color -> imageView.imageTintList = ColorStateList.valueOf(color.toColorInt()) }
+        tint.attachTo(imageView)
+    }
+
     imageView
 }
 
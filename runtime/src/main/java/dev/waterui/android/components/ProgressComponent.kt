@@ -1,9 +1,10 @@
 package dev.waterui.android.components
 
-import android.content.res.ColorStateList
 import android.view.View
 import android.widget.LinearLayout
-import android.widget.ProgressBar
+import com.google.android.material.progressindicator.BaseProgressIndicator
+import com.google.android.material.progressindicator.CircularProgressIndicator
+import com.google.android.material.progressindicator.LinearProgressIndicator
 import dev.waterui.android.layout.AxisExpandingLinearLayout
 import dev.waterui.android.reactive.WuiComputed
 import dev.waterui.android.runtime.NativeBindings
@@ -21,7 +22,16 @@ import kotlin.math.roundToInt
 private val progressTypeId: WuiTypeId by lazy { NativeBindings.waterui_progress_id().toTypeId() }
 private const val PROGRESS_STYLE_LINEAR = 0
 private const val PROGRESS_STYLE_CIRCULAR = 1
+private const val PROGRESS_MAX = 1000
 
+/**
+ * Progress component renderer.
+ *
+ * Uses Material progress indicators: a bar for the linear style and a ring for
+ * the circular style. Both are determinate while the value is finite. When a
+ * value first arrives for an indeterminate indicator, the indicator finishes
+ * its current cycle and then animates into the determinate value.
+ */
 private val progressRenderer = WuiRenderer { context, node, env, registry ->
     val struct = NativeBindings.waterui_force_as_progress(node.rawPtr)
     val computed = struct.valuePtr.takeIf { it != 0L }?.let { WuiComputed.double(it, env) }
@@ -44,14 +54,14 @@ private val progressRenderer = WuiRenderer { context, node, env, registry ->
         container.addView(label)
     }
 
-    val progressBar = when (struct.style) {
-        PROGRESS_STYLE_CIRCULAR -> ProgressBar(context).apply {
+    val progressBar: BaseProgressIndicator<*> = when (struct.style) {
+        PROGRESS_STYLE_CIRCULAR -> CircularProgressIndicator(context).apply {
             // Circular: fixed size, doesn't expand
-            max = 1000
+            max = PROGRESS_MAX
         }
-        else -> ProgressBar(context, null, android.R.attr.progressBarStyleHorizontal).apply {
+        else -> LinearProgressIndicator(context).apply {
             // Linear: expands width to fill container
-            max = 1000
+            max = PROGRESS_MAX
             layoutParams = LinearLayout.LayoutParams(
                 LinearLayout.LayoutParams.MATCH_PARENT,
                 LinearLayout.LayoutParams.WRAP_CONTENT
@@ -69,32 +79,36 @@ private val progressRenderer = WuiRenderer { context, node, env, registry ->
         null
     }
 
+    if (computed == null) {
+        progressBar.isIndeterminate = true
+    }
+    var hasValue = false
     computed?.observe { value ->
-        val isFinite = value.isFinite()
-        progressBar.isIndeterminate = !isFinite
-        if (isFinite) {
-            val scaled = (value.coerceIn(0.0, 1.0) * 1000).roundToInt()
-            if (progressBar.max != 0) {
-                progressBar.progress = scaled
-            }
+        if (value.isFinite()) {
+            val scaled = (value.coerceIn(0.0, 1.0) * PROGRESS_MAX).roundToInt()
+            // setProgressCompat leaves indeterminate mode once the current cycle ends
+            progressBar.setProgressCompat(scaled, hasValue)
+            hasValue = true
             valueLabel?.visibility = View.VISIBLE
         } else {
+            progressBar.isIndeterminate = true
+            hasValue = false
             valueLabel?.visibility = View.GONE
         }
     }
 
     computed?.let { container.disposeWith(it) }
-    val accent = ThemeBridge.accent(env)
-    accent.observe { color ->
-        val tint = ColorStateList.valueOf(color.toColorInt())
-        progressBar.progressTintList = tint
-        progressBar.indeterminateTintList = tint
+    val tint = if (struct.tintPtr != 0L) {
+        WuiComputed.resolvedColor(struct.tintPtr, env).also {
+            NativeBindings.waterui_drop_color(struct.tintPtr)
+        }
+    } else {
+        ThemeBridge.accent(env)
     }
-    accent.attachTo(progressBar)
+    tint.observe { color -> progressBar.setIndicatorColor(color.toColorInt()) }
+    tint.attachTo(progressBar)
     val border = ThemeBridge.border(env)
-    border.observe { color ->
-        progressBar.secondaryProgressTintList = ColorStateList.valueOf(color.toColorInt())
-    }
+    border.observe { color -> progressBar.trackColor = color.toColorInt() }
     border.attachTo(progressBar)
     container
 }
@@ -1,12 +1,25 @@
 package dev.waterui.android.components
 
+import android.content.Context
+import android.graphics.Rect
+import android.view.MotionEvent
+import android.view.View
+import android.view.ViewGroup
 import android.widget.HorizontalScrollView
 import android.widget.ScrollView
+import androidx.core.view.ViewCompat
+import androidx.core.view.WindowInsetsCompat
+import androidx.core.view.updatePadding
+import dev.waterui.android.layout.ScrollTargets
 import dev.waterui.android.runtime.NativeBindings
 import dev.waterui.android.runtime.RegistryBuilder
+import dev.waterui.android.runtime.ScrollCommandSink
+import dev.waterui.android.runtime.TAG_VIEW_ID
 import dev.waterui.android.runtime.WuiRenderer
 import dev.waterui.android.runtime.WuiTypeId
+import dev.waterui.android.runtime.disposeWith
 import dev.waterui.android.runtime.inflateAnyView
+import kotlin.math.roundToInt
 
 
 private val scrollTypeId: WuiTypeId by lazy { NativeBindings.waterui_scroll_view_id().toTypeId() }
@@ -15,25 +28,228 @@ private const val AXIS_HORIZONTAL = 0
 private const val AXIS_VERTICAL = 1
 private const val AXIS_ALL = 2
 
+/**
+ * Vertical scroll view that can settle on viewport-sized pages.
+ */
+private class PagingScrollView(context: Context) : ScrollView(context) {
+    var pagingEnabled = false
+    private var flung = false
+
+    override fun fling(velocityY: Int) {
+        if (!pagingEnabled) {
+            super.fling(velocityY)
+            return
+        }
+        flung = true
+        settle(velocityY)
+    }
+
+    override fun onTouchEvent(ev: MotionEvent): Boolean {
+        if (ev.actionMasked == MotionEvent.ACTION_DOWN) flung = false
+        val handled = super.onTouchEvent(ev)
+        val released = ev.actionMasked == MotionEvent.ACTION_UP || ev.actionMasked == MotionEvent.ACTION_CANCEL
+        if (pagingEnabled && released && !flung) settle(0)
+        return handled
+    }
+
+    private fun settle(velocity: Int) {
+        val page = height - paddingTop - paddingBottom
+        val maxOffset = (getChildAt(0)?.height ?: 0) - page
+        smoothScrollTo(0, ScrollTargets.pageOffset(scrollY, page, maxOffset, velocity))
+    }
+}
+
+/**
+ * Horizontal scroll view that can settle on viewport-sized pages.
+ */
+private class PagingHorizontalScrollView(context: Context) : HorizontalScrollView(context) {
+    var pagingEnabled = false
+    private var flung = false
+
+    override fun fling(velocityX: Int) {
+        if (!pagingEnabled) {
+            super.fling(velocityX)
+            return
+        }
+        flung = true
+        settle(velocityX)
+    }
+
+    override fun onTouchEvent(ev: MotionEvent): Boolean {
+        if (ev.actionMasked == MotionEvent.ACTION_DOWN) flung = false
+        val handled = super.onTouchEvent(ev)
+        val released = ev.actionMasked == MotionEvent.ACTION_UP || ev.actionMasked == MotionEvent.ACTION_CANCEL
+        if (pagingEnabled && released && !flung) settle(0)
+        return handled
+    }
+
+    private fun settle(velocity: Int) {
+        val page = width - paddingLeft - paddingRight
+        val maxOffset = (getChildAt(0)?.width ?: 0) - page
+        smoothScrollTo(ScrollTargets.pageOffset(scrollX, page, maxOffset, velocity), 0)
+    }
+}
+
 private val scrollRenderer = WuiRenderer { context, node, env, registry ->
     val struct = NativeBindings.waterui_force_as_scroll(node.rawPtr)
     val content = inflateAnyView(context, struct.contentPtr, env, registry)
 
     // Layout decisions (including centering) are made by Rust layout engine.
     // Android only measures and places children.
-    when (struct.axis) {
-        AXIS_HORIZONTAL -> HorizontalScrollView(context).apply {
+    var vertical: PagingScrollView? = null
+    var horizontal: PagingHorizontalScrollView? = null
+    val root: ViewGroup = when (struct.axis) {
+        AXIS_HORIZONTAL -> PagingHorizontalScrollView(context).apply {
             isHorizontalScrollBarEnabled = true
             addView(content)
+            horizontal = this
         }
-        AXIS_ALL -> ScrollView(context).apply {
-            val horizontal = HorizontalScrollView(context)
-            horizontal.addView(content)
-            addView(horizontal)
+        // Nested containers intercept a single direction per drag, so two-axis
+        // scrolling is always locked to the dominant axis here and `lockAxis`
+        // needs no extra handling.
+        AXIS_ALL -> PagingScrollView(context).apply {
+            val inner = PagingHorizontalScrollView(context)
+            inner.addView(content)
+            addView(inner)
+            vertical = this
+            horizontal = inner
         }
-        else -> ScrollView(context).apply {
+        else -> PagingScrollView(context).apply {
             addView(content)
+            vertical = this
+        }
+    }
+    vertical?.pagingEnabled = struct.paging
+    horizontal?.pagingEnabled = struct.paging
+    vertical?.avoidKeyboard()
+
+    if (struct.proxyPtr != 0L) {
+        ScrollProxyConnection(struct.proxyPtr, root, content, vertical, horizontal).attach()
+    }
+
+    root
+}
+
+/**
+ * Pads the bottom of a vertical scroll view by the part of it covered by the
+ * software keyboard while one of its descendants has focus, and keeps the
+ * focused view visible.
+ */
+private fun ScrollView.avoidKeyboard() {
+    val basePadding = paddingBottom
+    val location = IntArray(2)
+    ViewCompat.setOnApplyWindowInsetsListener(this) { view, insets ->
+        val imeBottom = insets.getInsets(WindowInsetsCompat.Type.ime()).bottom
+        val focused = view.findFocus()
+        val overlap = if (imeBottom > 0 && focused != null) {
+            view.getLocationInWindow(location)
+            (location[1] + view.height - (view.rootView.height - imeBottom)).coerceAtLeast(0)
+        } else 0
+
+        view.updatePadding(bottom = basePadding + overlap)
+        if (overlap > 0 && focused != null) {
+            focused.post {
+                focused.requestRectangleOnScreen(Rect(0, 0, focused.width, focused.height))
+            }
+        }
+        insets
+    }
+}
+
+/**
+ * Bridges a native scroll proxy to the Android scroll containers: reports the
+ * scroll geometry after every scroll or layout change and executes scroll
+ * commands issued from Rust.
+ */
+private class ScrollProxyConnection(
+    private val proxyPtr: Long,
+    private val root: View,
+    private val content: View,
+    private val vertical: ScrollView?,
+    private val horizontal: HorizontalScrollView?
+) : ScrollCommandSink {
+    private val density = root.resources.displayMetrics.density
+    private var lastReport: List<Int> = emptyList()
+
+    fun attach() {
+        vertical?.setOnScrollChangeListener { _, _, _, _, _ -> report() }
+        horizontal?.setOnScrollChangeListener { _, _, _, _, _ -> report() }
+        content.addOnLayoutChangeListener { _, _, _, _, _, _, _, _, _ -> report() }
+        root.addOnLayoutChangeListener { _, _, _, _, _, _, _, _, _ -> report() }
+
+        NativeBindings.waterui_scroll_proxy_attach(proxyPtr, this)
+        root.disposeWith {
+            NativeBindings.waterui_drop_scroll_proxy(proxyPtr)
+        }
+    }
+
+    private fun viewportWidth(): Int = horizontal?.width ?: root.width
+
+    private fun viewportHeight(): Int = vertical?.height ?: root.height
+
+    private fun report() {
+        val current = listOf(
+            horizontal?.scrollX ?: 0,
+            vertical?.scrollY ?: 0,
+            content.width,
+            content.height,
+            viewportWidth(),
+            viewportHeight()
+        )
+        if (current == lastReport) return
+        lastReport = current
+        NativeBindings.waterui_scroll_proxy_report(
+            proxyPtr,
+            current[0] / density,
+            current[1] / density,
+            current[2] / density,
+            current[3] / density,
+            current[4] / density,
+            current[5] / density
+        )
+    }
+
+    private fun scrollContent(x: Int, y: Int, animated: Boolean) {
+        if (animated) {
+            vertical?.smoothScrollTo(0, y)
+            horizontal?.smoothScrollTo(x, 0)
+        } else {
+            vertical?.scrollTo(0, y)
+            horizontal?.scrollTo(x, 0)
+        }
+    }
+
+    override fun scrollToOffset(x: Float, y: Float, animated: Boolean) {
+        root.post {
+            scrollContent((x * density).roundToInt(), (y * density).roundToInt(), animated)
+        }
+    }
+
+    override fun scrollToId(id: Int, anchor: Int, animated: Boolean) {
+        root.post {
+            val target = findTaggedView(content, id) ?: return@post
+            val rect = Rect(0, 0, target.width, target.height)
+            if (target !== content) {
+                (content as? ViewGroup)?.offsetDescendantRectToMyCoords(target, rect)
+            }
+            val x = ScrollTargets.anchoredOffset(
+                rect.left, rect.right, viewportWidth(), anchor % 3, content.width - viewportWidth()
+            )
+            val y = ScrollTargets.anchoredOffset(
+                rect.top, rect.bottom, viewportHeight(), anchor / 3, content.height - viewportHeight()
+            )
+            scrollContent(x, y, animated)
+        }
+    }
+
+    private fun findTaggedView(view: View, id: Int): View? {
+        if (view.getTag(TAG_VIEW_ID) == id) return view
+        if (view is ViewGroup) {
+            for (i in 0 until view.childCount) {
+                findTaggedView(view.getChildAt(i), id)?.let { return it }
+            }
         }
+        return null
     }
 }
 
@@ -1,16 +1,23 @@
 package dev.waterui.android.components
 
+import android.graphics.Color
+import android.text.TextUtils
+import android.text.method.LinkMovementMethod
 import android.util.TypedValue
+import android.view.View
 import android.widget.TextView
 import dev.waterui.android.reactive.WuiComputed
+import dev.waterui.android.runtime.InlineAttachmentHost
 import dev.waterui.android.runtime.NativeBindings
 import dev.waterui.android.runtime.RegistryBuilder
+import dev.waterui.android.runtime.TextStruct
 import dev.waterui.android.runtime.ThemeBridge
 import dev.waterui.android.runtime.WuiRenderer
 import dev.waterui.android.runtime.WuiTypeId
 import dev.waterui.android.runtime.applyRustAnimation
 import dev.waterui.android.runtime.attachTo
 import dev.waterui.android.runtime.disposeWith
+import dev.waterui.android.runtime.dp
 import dev.waterui.android.runtime.toColorInt
 import dev.waterui.android.runtime.toTypeface
 
@@ -18,12 +25,43 @@ private val textTypeId: WuiTypeId by lazy {
     NativeBindings.waterui_text_id().toTypeId()
 }
 
-private val textRenderer = WuiRenderer { context, node, env, _ ->
+private const val TRUNCATE_HEAD = 0
+private const val TRUNCATE_MIDDLE = 1
+
+private const val ALIGN_CENTER = 1
+private const val ALIGN_TRAILING = 2
+
+/**
+ * Applies the line limit, truncation, alignment, spacing and digit options of a
+ * `Text` to its TextView. Kerning depends on the font size, so it is applied
+ * separately whenever the font changes.
+ */
+private fun TextView.applyTextConfig(struct: TextStruct) {
+    if (struct.lineLimit > 0) {
+        maxLines = struct.lineLimit
+        ellipsize = when (struct.truncationMode) {
+            TRUNCATE_HEAD -> TextUtils.TruncateAt.START
+            TRUNCATE_MIDDLE -> TextUtils.TruncateAt.MIDDLE
+            else -> TextUtils.TruncateAt.END
+        }
+    }
+    textAlignment = when (struct.multilineAlignment) {
+        ALIGN_CENTER -> View.TEXT_ALIGNMENT_CENTER
+        ALIGN_TRAILING -> View.TEXT_ALIGNMENT_VIEW_END
+        else -> View.TEXT_ALIGNMENT_VIEW_START
+    }
+    setLineSpacing(struct.lineSpacing.dp(context), 1f)
+    if (struct.monospacedDigits) {
+        fontFeatureSettings = "tnum"
+    }
+}
+
+private val textRenderer = WuiRenderer { context, node, env, registry ->
     val struct = NativeBindings.waterui_force_as_text(node.rawPtr)
     val computed = WuiComputed.styledString(struct.contentPtr, env)
     val textView = TextView(context).apply {
         includeFontPadding = false
-        setLineSpacing(0f, 1f)
+        applyTextConfig(struct)
     }
     val foreground = ThemeBridge.foreground(env)
     foreground.observe { color ->
@@ -33,13 +71,25 @@ private val textRenderer = WuiRenderer { context, node, env, _ ->
     val bodyFont = ThemeBridge.bodyFont(env)
     bodyFont.observe { font ->
         textView.setTextSize(TypedValue.COMPLEX_UNIT_SP, font.size)
-        textView.typeface = font.toTypeface()
+        textView.typeface = font.toTypeface(env)
+        // Kerning is in points while letterSpacing is in ems of the base font size
+        if (struct.kerning != 0f && font.size > 0f) {
+            textView.letterSpacing = struct.kerning / font.size
+        }
     }
     bodyFont.attachTo(textView)
+    val attachments = InlineAttachmentHost(textView, env, registry)
     computed.observeWithAnimation { styled, animation ->
-        val resolved = styled.toCharSequence(env)
+        attachments.reset()
+        val resolved = styled.toCharSequence(env, attachments)
+        // Tappable ranges need a movement method to receive clicks
+        if (styled.hasTapHandlers() && textView.movementMethod !is LinkMovementMethod) {
+            textView.movementMethod = LinkMovementMethod.getInstance()
+            textView.highlightColor = Color.TRANSPARENT
+        }
         textView.applyRustAnimation(animation) {
             textView.text = resolved
+            attachments.shown()
         }
         // Request layout after text change so parent can resize
         textView.requestLayout()
@@ -2,7 +2,8 @@ package dev.waterui.android.components
 
 import android.content.res.ColorStateList
 import android.text.InputType
-import android.text.method.PasswordTransformationMethod
+import android.view.KeyEvent
+import android.view.inputmethod.EditorInfo
 import android.widget.LinearLayout
 import androidx.appcompat.widget.AppCompatEditText
 import androidx.core.view.ViewCompat
@@ -26,12 +27,13 @@ import java.util.concurrent.atomic.AtomicBoolean
 
 private val textFieldTypeId: WuiTypeId by lazy { NativeBindings.waterui_text_field_id().toTypeId() }
 
+// Matches WuiKeyboardType; secure entry is the separate SecureField component
 private const val KEYBOARD_TEXT = 0
-private const val KEYBOARD_SECURE = 1
-private const val KEYBOARD_EMAIL = 2
-private const val KEYBOARD_URL = 3
-private const val KEYBOARD_NUMBER = 4
-private const val KEYBOARD_PHONE = 5
+private const val KEYBOARD_EMAIL = 1
+private const val KEYBOARD_URL = 2
+private const val KEYBOARD_NUMBER = 3
+private const val KEYBOARD_PHONE = 4
+private const val KEYBOARD_SEARCH = 5
 
 private val textFieldRenderer = WuiRenderer { context, node, env, registry ->
     val struct = NativeBindings.waterui_force_as_text_field(node.rawPtr)
@@ -51,10 +53,11 @@ private val textFieldRenderer = WuiRenderer { context, node, env, registry ->
 
     val editText = AppCompatEditText(context).apply {
         inputType = resolveInputType(struct.keyboardType)
-        if (struct.keyboardType == KEYBOARD_SECURE) {
-            transformationMethod = PasswordTransformationMethod.getInstance()
-        } else {
-            transformationMethod = null
+        transformationMethod = null
+        imeOptions = when {
+            struct.keyboardType == KEYBOARD_SEARCH -> EditorInfo.IME_ACTION_SEARCH
+            struct.onSubmitPtr != 0L -> EditorInfo.IME_ACTION_DONE
+            else -> EditorInfo.IME_ACTION_UNSPECIFIED
         }
         setPadding(horizontalPadding, verticalPadding, horizontalPadding, verticalPadding)
         layoutParams = LinearLayout.LayoutParams(
@@ -88,6 +91,20 @@ private val textFieldRenderer = WuiRenderer { context, node, env, registry ->
         }
     }
 
+    if (struct.onSubmitPtr != 0L) {
+        val envPtr = env.raw()
+        editText.setOnEditorActionListener { _, actionId, event ->
+            val isEnter = event?.keyCode == KeyEvent.KEYCODE_ENTER && event.action == KeyEvent.ACTION_DOWN
+            if (actionId == editText.imeOptions || isEnter) {
+                NativeBindings.waterui_call_action(struct.onSubmitPtr, envPtr)
+                true
+            } else {
+                false
+            }
+        }
+        container.disposeWith { NativeBindings.waterui_drop_action(struct.onSubmitPtr) }
+    }
+
     promptComputed?.observe { prompt ->
         editText.hint = prompt.toCharSequence(env)
     }
@@ -119,11 +136,11 @@ private val textFieldRenderer = WuiRenderer { context, node, env, registry ->
 
 private fun resolveInputType(keyboardType: Int): Int =
     when (keyboardType) {
-        KEYBOARD_SECURE -> InputType.TYPE_CLASS_TEXT or InputType.TYPE_TEXT_VARIATION_PASSWORD
         KEYBOARD_EMAIL -> InputType.TYPE_CLASS_TEXT or InputType.TYPE_TEXT_VARIATION_EMAIL_ADDRESS
         KEYBOARD_URL -> InputType.TYPE_CLASS_TEXT or InputType.TYPE_TEXT_VARIATION_URI
         KEYBOARD_NUMBER -> InputType.TYPE_CLASS_NUMBER or InputType.TYPE_NUMBER_FLAG_DECIMAL or InputType.TYPE_NUMBER_FLAG_SIGNED
         KEYBOARD_PHONE -> InputType.TYPE_CLASS_PHONE
+        KEYBOARD_SEARCH -> InputType.TYPE_CLASS_TEXT or InputType.TYPE_TEXT_FLAG_AUTO_CORRECT
         else -> InputType.TYPE_CLASS_TEXT
     }
 
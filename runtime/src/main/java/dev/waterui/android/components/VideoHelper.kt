@@ -3,7 +3,9 @@ package dev.waterui.android.components
 import android.content.Context
 import android.view.ViewGroup
 import androidx.annotation.OptIn
+import androidx.media3.common.C
 import androidx.media3.common.MediaItem
+import androidx.media3.common.PlaybackException
 import androidx.media3.common.Player
 import androidx.media3.common.util.UnstableApi
 import androidx.media3.exoplayer.ExoPlayer
@@ -42,6 +44,52 @@ class WuiVideoTextureView(
     private var exoPlayer: ExoPlayer? = null
     private var currentVolume = 1f
     private var currentUrl: String? = null
+    private var autoPlay = true
+    private var playbackRate = 1f
+
+    /**
+     * Receives playback state changes of the underlying player.
+     */
+    interface PlaybackListener {
+        fun onReady(durationSeconds: Double) {}
+        /** Play/pause intent changed, e.g. from the native controls. */
+        fun onPlayingChanged(playing: Boolean) {}
+        /** The position started or stopped advancing. */
+        fun onProgressingChanged(progressing: Boolean) {}
+        fun onBuffering(buffering: Boolean) {}
+        fun onEnded() {}
+        fun onError(message: String) {}
+    }
+
+    var playbackListener: PlaybackListener? = null
+
+    private val playerListener = object : Player.Listener {
+        override fun onPlaybackStateChanged(playbackState: Int) {
+            val listener = playbackListener ?: return
+            when (playbackState) {
+                Player.STATE_READY -> {
+                    listener.onBuffering(false)
+                    listener.onReady(durationSeconds())
+                }
+                Player.STATE_BUFFERING -> listener.onBuffering(true)
+                Player.STATE_ENDED -> listener.onEnded()
+                else -> {}
+            }
+        }
+
+        override fun onPlayWhenReadyChanged(playWhenReady: Boolean, reason: Int) {
+            autoPlay = playWhenReady
+            playbackListener?.onPlayingChanged(playWhenReady)
+        }
+
+        override fun onIsPlayingChanged(isPlaying: Boolean) {
+            playbackListener?.onProgressingChanged(isPlaying)
+        }
+
+        override fun onPlayerError(error: PlaybackException) {
+            playbackListener?.onError(error.message ?: error.errorCodeName)
+        }
+    }
 
     init {
         layoutParams = ViewGroup.LayoutParams(
@@ -68,6 +116,8 @@ class WuiVideoTextureView(
         exoPlayer = ExoPlayer.Builder(context).build().apply {
             repeatMode = if (loops) Player.REPEAT_MODE_ALL else Player.REPEAT_MODE_OFF
             volume = if (currentVolume < 0) 0f else currentVolume.coerceIn(0f, 1f)
+            setPlaybackSpeed(playbackRate)
+            addListener(playerListener)
         }
         player = exoPlayer
     }
@@ -83,10 +133,53 @@ class WuiVideoTextureView(
             val mediaItem = MediaItem.fromUri(url)
             player.setMediaItem(mediaItem)
             player.prepare()
-            player.playWhenReady = true
+            player.playWhenReady = autoPlay
         }
     }
 
+    /**
+     * Start or pause playback. Also decides whether newly set videos start automatically.
+     */
+    fun setPlaying(playing: Boolean) {
+        autoPlay = playing
+        exoPlayer?.let { player ->
+            // Playing a finished video starts it over
+            if (playing && player.playbackState == Player.STATE_ENDED) {
+                player.seekTo(0)
+            }
+            player.playWhenReady = playing
+        }
+    }
+
+    /**
+     * Seek to a position in seconds.
+     */
+    fun seekTo(seconds: Double) {
+        exoPlayer?.seekTo((seconds * 1000).toLong().coerceAtLeast(0L))
+    }
+
+    /**
+     * Set the playback rate, where 1.0 is normal speed.
+     */
+    fun setPlaybackRate(rate: Float) {
+        if (rate <= 0f) return
+        playbackRate = rate
+        exoPlayer?.setPlaybackSpeed(rate)
+    }
+
+    /**
+     * Current playback position in seconds.
+     */
+    fun currentTimeSeconds(): Double = (exoPlayer?.currentPosition ?: 0L) / 1000.0
+
+    /**
+     * Duration of the current video in seconds, or 0 when unknown.
+     */
+    fun durationSeconds(): Double {
+        val duration = exoPlayer?.duration ?: C.TIME_UNSET
+        return if (duration == C.TIME_UNSET) 0.0 else duration / 1000.0
+    }
+
     /**
      * Set the volume level.
      * @param volume 0.0 to 1.0 for volume, negative values mean muted
@@ -102,6 +195,7 @@ class WuiVideoTextureView(
     fun release() {
         exoPlayer?.let { player ->
             // Stop playback immediately to prevent audio from continuing
+            player.removeListener(playerListener)
             player.stop()
             player.clearMediaItems()
             player.release()
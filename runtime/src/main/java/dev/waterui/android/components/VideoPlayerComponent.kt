@@ -4,18 +4,27 @@ import dev.waterui.android.reactive.WuiBinding
 import dev.waterui.android.reactive.WuiComputed
 import dev.waterui.android.runtime.NativeBindings
 import dev.waterui.android.runtime.RegistryBuilder
+import dev.waterui.android.runtime.VideoEventType
 import dev.waterui.android.runtime.WuiRenderer
 import dev.waterui.android.runtime.WuiTypeId
 import dev.waterui.android.runtime.disposeWith
+import kotlin.math.abs
 
 private val videoPlayerTypeId: WuiTypeId by lazy { NativeBindings.waterui_video_player_id().toTypeId() }
 
+private const val POSITION_INTERVAL_MS = 250L
+private const val SEEK_TOLERANCE_SECONDS = 0.5
+
 /**
  * VideoPlayer component renderer.
  *
  * Uses WuiVideoTextureView for video playback with proper aspect ratio support.
  * VideoPlayer has native controls enabled.
  *
+ * Playback can be driven from Rust through the optional `playing`, `current_time`
+ * and `rate` signals; the position and duration are written back while playing,
+ * and player state changes are reported through the event handler.
+ *
  * Aspect Ratio Modes:
  * - Fit (0): Video fits within bounds, maintaining aspect ratio (may show letterbox)
  * - Fill (1): Video scales to fill bounds completely, cropping excess (like iOS resizeAspectFill)
@@ -29,7 +38,7 @@ private val videoPlayerRenderer = WuiRenderer { context, node, env, registry ->
         context = context,
         aspectRatioMode = struct.aspectRatio,
         showControls = struct.showControls,
-        loops = true // VideoPlayer loops by default
+        loops = struct.loops
     )
 
     // Set up video source computed signal (Computed<Str> - a URL string)
@@ -42,7 +51,25 @@ private val videoPlayerRenderer = WuiRenderer { context, node, env, registry ->
         WuiBinding.float(it, env)
     }
 
+    // Playback control signals
+    val playingBinding = struct.playingPtr.takeIf { it != 0L }?.let { WuiBinding.bool(it, env) }
+    val currentTimeBinding = struct.currentTimePtr.takeIf { it != 0L }?.let { WuiBinding.double(it, env) }
+    val durationBinding = struct.durationPtr.takeIf { it != 0L }?.let { WuiBinding.double(it, env) }
+    val rateComputed = struct.ratePtr.takeIf { it != 0L }?.let { WuiComputed.double(it, env) }
+
     var currentUrl: String? = null
+    // Last position written to the binding, so our own updates are not treated as seeks
+    var reportedTime = -1.0
+
+    // Observe playback controls before the source so a paused player never starts
+    playingBinding?.observe { playing -> videoView.setPlaying(playing) }
+    rateComputed?.observe { rate -> videoView.setPlaybackRate(rate.toFloat()) }
+    currentTimeBinding?.observe { seconds ->
+        if (abs(seconds - reportedTime) > SEEK_TOLERANCE_SECONDS) {
+            reportedTime = seconds
+            videoView.seekTo(seconds)
+        }
+    }
 
     // Observe video source changes
     sourceComputed?.observe { url ->
@@ -57,12 +84,74 @@ private val videoPlayerRenderer = WuiRenderer { context, node, env, registry ->
         videoView.setVolume(volume)
     }
 
+    // Publish the position while playing
+    fun reportTime() {
+        val seconds = videoView.currentTimeSeconds()
+        if (seconds != reportedTime) {
+            reportedTime = seconds
+            currentTimeBinding?.set(seconds)
+        }
+    }
+    val positionTicker = object : Runnable {
+        override fun run() {
+            reportTime()
+            videoView.postDelayed(this, POSITION_INTERVAL_MS)
+        }
+    }
+
+    fun reportPlaying(playing: Boolean) {
+        if (playingBinding != null && playingBinding.current() != playing) {
+            playingBinding.set(playing)
+        }
+    }
+
+    videoView.playbackListener = object : WuiVideoTextureView.PlaybackListener {
+        override fun onReady(durationSeconds: Double) {
+            if (durationSeconds > 0.0 && durationBinding?.current() != durationSeconds) {
+                durationBinding?.set(durationSeconds)
+            }
+            NativeBindings.waterui_call_video_event(struct.onEventPtr, VideoEventType.READY_TO_PLAY, null)
+        }
+
+        override fun onPlayingChanged(playing: Boolean) {
+            reportPlaying(playing)
+        }
+
+        override fun onProgressingChanged(progressing: Boolean) {
+            videoView.removeCallbacks(positionTicker)
+            if (currentTimeBinding == null) return
+            if (progressing) videoView.post(positionTicker) else reportTime()
+        }
+
+        override fun onBuffering(buffering: Boolean) {
+            val type = if (buffering) VideoEventType.BUFFERING else VideoEventType.BUFFERING_ENDED
+            NativeBindings.waterui_call_video_event(struct.onEventPtr, type, null)
+        }
+
+        override fun onEnded() {
+            // The player keeps its play intent at the end; a finished video is no longer playing
+            reportPlaying(false)
+            NativeBindings.waterui_call_video_event(struct.onEventPtr, VideoEventType.ENDED, null)
+        }
+
+        override fun onError(message: String) {
+            NativeBindings.waterui_call_video_event(struct.onEventPtr, VideoEventType.ERROR, message)
+        }
+    }
+
     // Clean up resources
     videoView.disposeWith {
+        videoView.removeCallbacks(positionTicker)
+        videoView.playbackListener = null
         videoView.release()
+        NativeBindings.waterui_drop_video_event(struct.onEventPtr)
     }
     sourceComputed?.let { videoView.disposeWith(it) }
     volumeBinding?.let { videoView.disposeWith(it) }
+    playingBinding?.let { videoView.disposeWith(it) }
+    currentTimeBinding?.let { videoView.disposeWith(it) }
+    durationBinding?.let { videoView.disposeWith(it) }
+    rateComputed?.let { videoView.disposeWith(it) }
 
     videoView
 }
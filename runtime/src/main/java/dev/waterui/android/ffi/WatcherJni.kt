@@ -24,20 +24,49 @@ object WatcherJni {
     @JvmStatic external fun init(): Long
     @JvmStatic external fun app(envPtr: Long): dev.waterui.android.runtime.AppStruct
     @JvmStatic external fun envInstallMediaPickerManager(envPtr: Long)
+    @JvmStatic external fun envInstallPermissions(envPtr: Long)
+    @JvmStatic external fun envInstallMainExecutor(envPtr: Long)
+    @JvmStatic external fun envInstallClipboard(envPtr: Long)
+    @JvmStatic external fun envInstallAssets(envPtr: Long, baseUrl: String)
+    @JvmStatic external fun envInstallSettingsStorage(envPtr: Long)
+    @JvmStatic external fun envInstallFeedback(envPtr: Long)
+    @JvmStatic external fun envInstallNotifications(envPtr: Long)
+    @JvmStatic external fun envDeliverNotificationResponse(envPtr: Long, notificationId: String, actionId: String?)
+    @JvmStatic external fun envInstallBackNavigation(envPtr: Long)
+    @JvmStatic external fun envDeliverBackEvent(envPtr: Long, phase: Int, progress: Float)
+    @JvmStatic external fun envInstallLocale(envPtr: Long, languageTag: String)
+    @JvmStatic external fun envSetSafeAreaInsets(
+        envPtr: Long,
+        top: Float,
+        bottom: Float,
+        leading: Float,
+        trailing: Float,
+        keyboard: Float
+    )
+    @JvmStatic external fun envSetSizeClasses(envPtr: Long, horizontal: Int, vertical: Int)
+    @JvmStatic external fun envSetDynamicTypeScale(envPtr: Long, scale: Float)
+    @JvmStatic external fun envSetAppPhase(envPtr: Long, phase: Int)
+    @JvmStatic external fun envRefreshPermissions(envPtr: Long)
+    @JvmStatic external fun envOpenUrl(envPtr: Long, url: String)
     @JvmStatic external fun envInstallWebViewController(envPtr: Long)
     @JvmStatic external fun viewBody(viewPtr: Long, envPtr: Long): Long
     @JvmStatic external fun viewId(viewPtr: Long): dev.waterui.android.runtime.TypeIdStruct
     @JvmStatic external fun viewStretchAxis(viewPtr: Long): Int
+    @JvmStatic external fun viewTypeName(viewPtr: Long): String
     @JvmStatic external fun cloneEnv(envPtr: Long): Long
     @JvmStatic external fun dropEnv(envPtr: Long)
     @JvmStatic external fun dropAnyview(viewPtr: Long)
-    @JvmStatic external fun configureHotReloadEndpoint(host: String, port: Int)
-    @JvmStatic external fun configureHotReloadDirectory(path: String)
+    @JvmStatic external fun debugDump(): String?
+    @JvmStatic external fun setLogLevel(level: Int)
+    @JvmStatic external fun configureHotReloadEndpoint(host: String, port: Int, directory: String)
+    @JvmStatic external fun reloadLibrary(path: String): Boolean
+    @JvmStatic external fun reloadSaveState(): ByteArray
+    @JvmStatic external fun reloadRestoreState(state: ByteArray)
 
     // ========== Force-As Functions ==========
 
     @JvmStatic external fun forceAsPlain(viewPtr: Long): PlainStruct
-    @JvmStatic external fun forceAsText(viewPtr: Long): Long
+    @JvmStatic external fun forceAsText(viewPtr: Long): TextStruct
     @JvmStatic external fun forceAsButton(viewPtr: Long): ButtonStruct
     @JvmStatic external fun forceAsColor(viewPtr: Long): Long
     @JvmStatic external fun forceAsTextField(viewPtr: Long): TextFieldStruct
@@ -48,6 +77,17 @@ object WatcherJni {
     @JvmStatic external fun forceAsColorPicker(viewPtr: Long): ColorPickerStruct
     @JvmStatic external fun forceAsProgress(viewPtr: Long): ProgressStruct
     @JvmStatic external fun forceAsScrollView(viewPtr: Long): ScrollStruct
+    @JvmStatic external fun scrollProxyAttach(proxyPtr: Long, sink: ScrollCommandSink)
+    @JvmStatic external fun scrollProxyReport(
+        proxyPtr: Long,
+        offsetX: Float,
+        offsetY: Float,
+        contentWidth: Float,
+        contentHeight: Float,
+        viewportWidth: Float,
+        viewportHeight: Float
+    )
+    @JvmStatic external fun dropScrollProxy(proxyPtr: Long)
     @JvmStatic external fun forceAsPicker(viewPtr: Long): PickerStruct
     @JvmStatic external fun forceAsSecureField(viewPtr: Long): SecureFieldStruct
     @JvmStatic external fun forceAsLayoutContainer(viewPtr: Long): LayoutContainerStruct
@@ -60,8 +100,15 @@ object WatcherJni {
     @JvmStatic external fun forceAsMetadataGesture(viewPtr: Long): MetadataGestureStruct
     @JvmStatic external fun forceAsMetadataLifeCycleHook(viewPtr: Long): MetadataLifeCycleHookStruct
     @JvmStatic external fun forceAsMetadataOnEvent(viewPtr: Long): MetadataOnEventStruct
+    @JvmStatic external fun forceAsMetadataOnPaste(viewPtr: Long): MetadataOnPasteStruct
+    @JvmStatic external fun forceAsMetadataOnPointer(viewPtr: Long): MetadataOnPointerStruct
+    @JvmStatic external fun forceAsMetadataOnSizeChange(viewPtr: Long): MetadataOnSizeChangeStruct
+    @JvmStatic external fun forceAsMetadataRefreshable(viewPtr: Long): MetadataRefreshableStruct
     @JvmStatic external fun forceAsMetadataCursor(viewPtr: Long): MetadataCursorStruct
     @JvmStatic external fun forceAsMetadataShadow(viewPtr: Long): MetadataShadowStruct
+    @JvmStatic external fun forceAsMetadataOverlay(viewPtr: Long): MetadataLayerStruct
+    @JvmStatic external fun forceAsMetadataBackgroundView(viewPtr: Long): MetadataLayerStruct
+    @JvmStatic external fun forceAsMetadataViewId(viewPtr: Long): MetadataViewIdStruct
     @JvmStatic external fun forceAsMetadataBorder(viewPtr: Long): MetadataBorderStruct
     @JvmStatic external fun forceAsMetadataFocused(viewPtr: Long): MetadataFocusedStruct
     @JvmStatic external fun forceAsMetadataIgnoreSafeArea(viewPtr: Long): MetadataIgnoreSafeAreaStruct
@@ -79,6 +126,10 @@ object WatcherJni {
     @JvmStatic external fun forceAsMetadataClipShape(viewPtr: Long): MetadataClipShapeStruct
     @JvmStatic external fun forceAsMetadataContextMenu(viewPtr: Long): MetadataContextMenuStruct
     @JvmStatic external fun forceAsPhoto(viewPtr: Long): PhotoStruct
+    @JvmStatic external fun callPhotoEvent(handlerPtr: Long, eventType: Int, message: String?)
+    @JvmStatic external fun dropPhotoEvent(handlerPtr: Long)
+    @JvmStatic external fun callVideoEvent(handlerPtr: Long, eventType: Int, message: String?)
+    @JvmStatic external fun dropVideoEvent(handlerPtr: Long)
     @JvmStatic external fun forceAsVideo(viewPtr: Long): VideoStruct2
     @JvmStatic external fun forceAsVideoPlayer(viewPtr: Long): VideoPlayerStruct
     @JvmStatic external fun forceAsWebView(viewPtr: Long): Long
@@ -115,6 +166,7 @@ object WatcherJni {
     @JvmStatic external fun dropWebView(webviewPtr: Long)
     @JvmStatic external fun resolveColor(colorPtr: Long, envPtr: Long): Long
     @JvmStatic external fun resolveFont(fontPtr: Long, envPtr: Long): Long
+    @JvmStatic external fun fontRegistryLookup(envPtr: Long, family: String, weight: Int, width: Int, italic: Boolean): ByteArray?
     @JvmStatic external fun dropWatcherGuard(guardPtr: Long)
 
     // ========== Animation Functions ==========
@@ -302,9 +354,16 @@ object WatcherJni {
     @JvmStatic external fun metadataGestureId(): dev.waterui.android.runtime.TypeIdStruct
     @JvmStatic external fun metadataLifeCycleHookId(): dev.waterui.android.runtime.TypeIdStruct
     @JvmStatic external fun metadataOnEventId(): dev.waterui.android.runtime.TypeIdStruct
+    @JvmStatic external fun metadataOnPasteId(): dev.waterui.android.runtime.TypeIdStruct
+    @JvmStatic external fun metadataOnPointerId(): dev.waterui.android.runtime.TypeIdStruct
+    @JvmStatic external fun metadataOnSizeChangeId(): dev.waterui.android.runtime.TypeIdStruct
+    @JvmStatic external fun metadataRefreshableId(): dev.waterui.android.runtime.TypeIdStruct
     @JvmStatic external fun metadataCursorId(): dev.waterui.android.runtime.TypeIdStruct
     @JvmStatic external fun metadataForegroundId(): dev.waterui.android.runtime.TypeIdStruct
     @JvmStatic external fun metadataShadowId(): dev.waterui.android.runtime.TypeIdStruct
+    @JvmStatic external fun metadataOverlayId(): dev.waterui.android.runtime.TypeIdStruct
+    @JvmStatic external fun metadataBackgroundViewId(): dev.waterui.android.runtime.TypeIdStruct
+    @JvmStatic external fun metadataViewIdId(): dev.waterui.android.runtime.TypeIdStruct
     @JvmStatic external fun metadataBorderId(): dev.waterui.android.runtime.TypeIdStruct
     @JvmStatic external fun metadataFocusedId(): dev.waterui.android.runtime.TypeIdStruct
     @JvmStatic external fun metadataIgnoreSafeAreaId(): dev.waterui.android.runtime.TypeIdStruct
@@ -338,11 +397,30 @@ object WatcherJni {
 
     @JvmStatic external fun listId(): dev.waterui.android.runtime.TypeIdStruct
     @JvmStatic external fun listItemId(): dev.waterui.android.runtime.TypeIdStruct
+    @JvmStatic external fun listSectionId(): dev.waterui.android.runtime.TypeIdStruct
 
     // ========== List Force-As Functions ==========
 
     @JvmStatic external fun forceAsList(viewPtr: Long): dev.waterui.android.runtime.ListStruct
     @JvmStatic external fun forceAsListItem(viewPtr: Long): dev.waterui.android.runtime.ListItemStruct
+    @JvmStatic external fun forceAsListSection(viewPtr: Long): dev.waterui.android.runtime.ListSectionStruct
+
+    // ========== Lazy Stack Functions ==========
+
+    @JvmStatic external fun lazyStackId(): dev.waterui.android.runtime.TypeIdStruct
+    @JvmStatic external fun forceAsLazyStack(viewPtr: Long): dev.waterui.android.runtime.LazyStackStruct
+
+    // ========== Geometry Reader Functions ==========
+
+    @JvmStatic external fun geometryReaderId(): dev.waterui.android.runtime.TypeIdStruct
+    @JvmStatic external fun forceAsGeometryReader(viewPtr: Long): dev.waterui.android.runtime.GeometryReaderStruct
+    @JvmStatic external fun geometryProxyUpdate(proxyPtr: Long, x: Float, y: Float, width: Float, height: Float)
+    @JvmStatic external fun dropGeometryProxy(proxyPtr: Long)
+
+    // ========== Lazy Grid Functions ==========
+
+    @JvmStatic external fun lazyGridId(): dev.waterui.android.runtime.TypeIdStruct
+    @JvmStatic external fun forceAsLazyGrid(viewPtr: Long): dev.waterui.android.runtime.LazyGridStruct
 
     // ========== Navigation Force-As Functions ==========
 
@@ -367,6 +445,34 @@ object WatcherJni {
     @JvmStatic external fun callOnEvent(handlerPtr: Long, envPtr: Long)
     @JvmStatic external fun dropOnEvent(handlerPtr: Long)
 
+    // ========== OnPaste Handler Functions ==========
+
+    @JvmStatic external fun callOnPaste(handlerPtr: Long, envPtr: Long, text: String)
+    @JvmStatic external fun dropOnPaste(handlerPtr: Long)
+
+    // ========== OnPointer Handler Functions ==========
+
+    @JvmStatic external fun callOnPointer(
+        handlerPtr: Long,
+        phase: Int,
+        kind: Int,
+        pointerId: Int,
+        x: Float,
+        y: Float,
+        pressure: Float
+    )
+    @JvmStatic external fun dropOnPointer(handlerPtr: Long)
+
+    // ========== OnSizeChange Handler Functions ==========
+
+    @JvmStatic external fun callOnSizeChange(handlerPtr: Long, width: Float, height: Float)
+    @JvmStatic external fun dropOnSizeChange(handlerPtr: Long)
+
+    // ========== Refreshable Handler Functions ==========
+
+    @JvmStatic external fun callRefreshable(handlerPtr: Long, envPtr: Long, onDone: Runnable)
+    @JvmStatic external fun dropRefreshable(handlerPtr: Long)
+
     // ========== Cursor Style Computed Functions ==========
 
     @JvmStatic external fun readComputedCursorStyle(computedPtr: Long): Int
@@ -382,9 +488,26 @@ object WatcherJni {
 
     @JvmStatic external fun gpuSurfaceId(): dev.waterui.android.runtime.TypeIdStruct
     @JvmStatic external fun forceAsGpuSurface(viewPtr: Long): dev.waterui.android.runtime.GpuSurfaceStruct
-    @JvmStatic external fun gpuSurfaceInit(rendererPtr: Long, surface: android.view.Surface, width: Int, height: Int): Long
-    @JvmStatic external fun gpuSurfaceRender(statePtr: Long, width: Int, height: Int): Boolean
+    @JvmStatic external fun gpuSurfaceInit(
+        rendererPtr: Long,
+        presentMode: Int,
+        sampleCount: Int,
+        surface: android.view.Surface,
+        width: Int,
+        height: Int
+    ): Long
+    @JvmStatic external fun gpuSurfaceRender(statePtr: Long, width: Int, height: Int, frameTimeNanos: Long): Boolean
     @JvmStatic external fun gpuSurfaceDrop(statePtr: Long)
+    @JvmStatic external fun gpuSurfacePointer(
+        statePtr: Long,
+        phase: Int,
+        kind: Int,
+        pointerId: Int,
+        x: Float,
+        y: Float,
+        pressure: Float
+    )
+    @JvmStatic external fun gpuSurfaceKey(statePtr: Long, phase: Int, key: String, modifiers: Int, repeat: Boolean)
 
     // ========== WebView Functions ==========
     @JvmStatic external fun webviewNativeHandle(webviewPtr: Long): Long
@@ -0,0 +1,129 @@
+package dev.waterui.android.layout
+
+import dev.waterui.android.runtime.GridItemStruct
+import kotlin.math.floor
+import kotlin.math.max
+import kotlin.math.roundToInt
+
+/**
+ * A resolved grid track: its size along the cross axis, the spacing that follows it
+ * and how cells are aligned inside it. All values are in dp.
+ */
+data class GridTrack(
+    val size: Float,
+    val spacing: Float,
+    val alignment: Int
+)
+
+/**
+ * Resolves `GridItem` definitions into concrete tracks, following SwiftUI semantics:
+ * fixed tracks are sized first, the remaining space is shared between flexible and
+ * adaptive items, and each adaptive item expands into as many tracks as fit.
+ *
+ * A flexible item clamped to its minimum or maximum takes only what it is allowed,
+ * and the difference is shared among the items that are not clamped.
+ */
+object GridTracks {
+    const val SIZE_FIXED = 0
+    const val SIZE_FLEXIBLE = 1
+    const val SIZE_ADAPTIVE = 2
+
+    fun resolve(items: List<GridItemStruct>, available: Float): List<GridTrack> {
+        if (items.isEmpty()) return emptyList()
+
+        val fixedTotal = items.filter { it.size == SIZE_FIXED }
+            .sumOf { (it.minimum + it.spacing).toDouble() }.toFloat()
+        val extents = shareSlots(items, max(0f, available - fixedTotal))
+
+        val tracks = ArrayList<GridTrack>()
+        for ((index, item) in items.withIndex()) {
+            val extent = extents[index]
+            when (item.size) {
+                SIZE_FIXED -> tracks += GridTrack(item.minimum, item.spacing, item.alignment)
+                SIZE_ADAPTIVE -> {
+                    val step = item.minimum + item.spacing
+                    val count = if (step > 0f) {
+                        max(1, floor((extent + item.spacing) / step).toInt())
+                    } else 1
+                    val size = clamp((extent - item.spacing * count) / count, item)
+                    repeat(count) { tracks += GridTrack(size, item.spacing, item.alignment) }
+                }
+                else -> tracks += GridTrack(clamp(extent - item.spacing, item), item.spacing, item.alignment)
+            }
+        }
+        return tracks
+    }
+
+    /**
+     * Converts [tracks] into whole-pixel spans for a layout [availablePx] wide, each
+     * covering its track and the spacing after it. Track edges are rounded from the
+     * running total so rounding errors do not add up. The last span absorbs any space
+     * left over, so the spans of a line always fill the layout.
+     */
+    fun pixelSpans(tracks: List<GridTrack>, density: Float, availablePx: Int): IntArray {
+        val spans = IntArray(tracks.size)
+        if (tracks.isEmpty()) return spans
+        var edge = 0f
+        var start = 0
+        for ((index, track) in tracks.withIndex()) {
+            edge += (track.size + track.spacing) * density
+            val end = max(start + 1, edge.roundToInt())
+            spans[index] = end - start
+            start = end
+        }
+        if (start < availablePx) {
+            spans[spans.lastIndex] += availablePx - start
+        }
+        return spans
+    }
+
+    /**
+     * Splits [space] between the flexible and adaptive items, returning each item's
+     * extent (track sizes plus spacing; zero for fixed items). Works like flexbox:
+     * the share is computed, the items whose bounds are violated in the dominant
+     * direction are frozen at their bound, and the rest is shared again.
+     */
+    private fun shareSlots(items: List<GridItemStruct>, space: Float): FloatArray {
+        val extents = FloatArray(items.size)
+        val open = items.indices.filterTo(ArrayList()) { items[it].size != SIZE_FIXED }
+        var remaining = space
+        while (open.isNotEmpty()) {
+            val share = max(0f, remaining) / open.size
+            val bounds = open.associateWith { boundExtent(share, items[it]) }
+            val violation = bounds.values.sumOf { ((it ?: share) - share).toDouble() }
+            if (bounds.values.all { it == null }) {
+                open.forEach { extents[it] = share }
+                break
+            }
+            // Freeze only the items pulling the same way as the total violation
+            val frozen = open.filter { index ->
+                val bound = bounds[index] ?: return@filter false
+                if (violation >= 0) bound > share else bound < share
+            }
+            for (index in frozen) {
+                extents[index] = bounds.getValue(index)!!
+                remaining -= extents[index]
+            }
+            open.removeAll(frozen)
+        }
+        return extents
+    }
+
+    /**
+     * Returns the extent [item] is clamped to when offered [share], or null if
+     * [share] is within its bounds.
+     */
+    private fun boundExtent(share: Float, item: GridItemStruct): Float? {
+        val lower = item.minimum + item.spacing
+        if (share < lower) return lower
+        // An adaptive item grows by adding tracks, so only its minimum binds
+        if (item.size == SIZE_ADAPTIVE || !item.maximum.isFinite()) return null
+        val upper = max(item.maximum, item.minimum) + item.spacing
+        return if (share > upper) upper else null
+    }
+
+    private fun clamp(value: Float, item: GridItemStruct): Float {
+        val upper = if (item.maximum.isFinite()) max(item.maximum, item.minimum) else Float.MAX_VALUE
+        return value.coerceIn(item.minimum, upper)
+    }
+}
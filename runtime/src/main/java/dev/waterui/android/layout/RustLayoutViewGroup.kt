@@ -257,7 +257,10 @@ private fun LayoutConstraints.toProposalStruct(density: Float): ProposalStruct =
 )
 
 private fun Float.resolveDimension(min: Int, max: Int): Int {
-    if (isNaN()) {
+    // NaN means "no preference"; infinity comes from frames with `max_width`/`max_height`
+    // of `f32::INFINITY` and means "take everything offered". Both resolve to the
+    // proposal, falling back to the minimum when the proposal is unbounded.
+    if (isNaN() || isInfinite()) {
         return if (max == Int.MAX_VALUE) min else max
     }
     val rounded = roundToInt().coerceAtLeast(0)
@@ -0,0 +1,44 @@
+package dev.waterui.android.layout
+
+import kotlin.math.ceil
+import kotlin.math.floor
+import kotlin.math.roundToInt
+
+/**
+ * Resolves where a scroll view should settle, for paging and for
+ * `scroll_to(id, anchor)`. All values are in pixels along a single axis.
+ */
+object ScrollTargets {
+    const val ANCHOR_START = 0
+    const val ANCHOR_CENTER = 1
+    const val ANCHOR_END = 2
+
+    /**
+     * Offset of the page a scroll at [offset] settles on. A positive [velocity]
+     * advances to the next page, a negative one returns to the previous page,
+     * and zero snaps to the nearest page.
+     */
+    fun pageOffset(offset: Int, pageSize: Int, maxOffset: Int, velocity: Int): Int {
+        if (pageSize <= 0) return offset.coerceIn(0, maxOf(0, maxOffset))
+        val position = offset.toFloat() / pageSize
+        val page = when {
+            velocity > 0 -> ceil(position)
+            velocity < 0 -> floor(position)
+            else -> position.roundToInt().toFloat()
+        }
+        return (page * pageSize).toInt().coerceIn(0, maxOf(0, maxOffset))
+    }
+
+    /**
+     * Offset that places the item spanning [itemStart]..[itemEnd] at [anchor]
+     * within a viewport of [viewport] pixels.
+     */
+    fun anchoredOffset(itemStart: Int, itemEnd: Int, viewport: Int, anchor: Int, maxOffset: Int): Int {
+        val target = when (anchor) {
+            ANCHOR_START -> itemStart
+            ANCHOR_END -> itemEnd - viewport
+            else -> (itemStart + itemEnd - viewport) / 2
+        }
+        return target.coerceIn(0, maxOf(0, maxOffset))
+    }
+}
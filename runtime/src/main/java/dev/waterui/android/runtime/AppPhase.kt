@@ -0,0 +1,21 @@
+package dev.waterui.android.runtime
+
+import androidx.lifecycle.Lifecycle
+
+/**
+ * Maps the hosting activity's lifecycle state to a WaterUI app phase (matches WuiAppPhase in FFI).
+ *
+ * A resumed activity is active, a started but paused one is visible without
+ * input focus, and anything below started is in the background.
+ */
+object AppPhase {
+    const val ACTIVE = 0
+    const val INACTIVE = 1
+    const val BACKGROUND = 2
+
+    fun from(state: Lifecycle.State): Int = when {
+        state.isAtLeast(Lifecycle.State.RESUMED) -> ACTIVE
+        state.isAtLeast(Lifecycle.State.STARTED) -> INACTIVE
+        else -> BACKGROUND
+    }
+}
@@ -0,0 +1,56 @@
+package dev.waterui.android.runtime
+
+import android.content.Context
+import android.content.res.AssetManager
+import android.net.Uri
+import android.util.Log
+import java.io.FileNotFoundException
+import java.io.InputStream
+
+/**
+ * Backs the Rust `Assets` catalog with the APK's `assets/` directory.
+ * This is called from native code via JNI.
+ *
+ * Asset URLs use the `file:///android_asset/` form, which WebView and Media3
+ * open natively; [openUrl] lets other loaders do the same.
+ */
+object AssetBridge {
+    private const val TAG = "AssetBridge"
+    const val BASE_URL = "file:///android_asset/"
+
+    private var assets: AssetManager? = null
+
+    /**
+     * Initialize with application context.
+     * Must be called before the catalog is installed into an environment.
+     */
+    fun init(context: Context) {
+        assets = context.applicationContext.assets
+    }
+
+    /**
+     * Returns the contents of the asset at [name], or null if it does not exist.
+     */
+    @JvmStatic
+    fun read(name: String): ByteArray? {
+        val manager = assets
+        if (manager == null) {
+            Log.e(TAG, "AssetBridge not initialized - call init() first")
+            return null
+        }
+        return try {
+            manager.open(name.removePrefix("/")).use { it.readBytes() }
+        } catch (e: FileNotFoundException) {
+            null
+        }
+    }
+
+    /**
+     * Opens [url] if it points into the bundled assets, otherwise returns null.
+     */
+    fun openUrl(url: String): InputStream? {
+        if (!url.startsWith(BASE_URL)) return null
+        val manager = assets ?: return null
+        return manager.open(Uri.decode(url.removePrefix(BASE_URL)))
+    }
+}
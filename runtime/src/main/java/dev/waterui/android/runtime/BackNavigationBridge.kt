@@ -0,0 +1,42 @@
+package dev.waterui.android.runtime
+
+import android.os.Handler
+import android.os.Looper
+import androidx.activity.OnBackPressedCallback
+
+/**
+ * Backs the Rust `BackNavigation` service with the activity's back dispatcher.
+ * This is called from native code via JNI, possibly off the main thread.
+ *
+ * Each root view registers an [OnBackPressedCallback]. Rust enables them only
+ * while it has something to go back to, so the system handles back otherwise,
+ * including the predictive back-to-home animation.
+ */
+object BackNavigationBridge {
+    private val mainHandler = Handler(Looper.getMainLooper())
+
+    // Accessed on the main thread only
+    private var enabled = false
+    private val callbacks = mutableSetOf<OnBackPressedCallback>()
+
+    /**
+     * Enables or disables app back handling.
+     * Called from native code via JNI.
+     */
+    @JvmStatic
+    fun setEnabled(enabled: Boolean) {
+        mainHandler.post {
+            this.enabled = enabled
+            callbacks.forEach { it.isEnabled = enabled }
+        }
+    }
+
+    internal fun register(callback: OnBackPressedCallback) {
+        callbacks.add(callback)
+        callback.isEnabled = enabled
+    }
+
+    internal fun unregister(callback: OnBackPressedCallback) {
+        callbacks.remove(callback)
+    }
+}
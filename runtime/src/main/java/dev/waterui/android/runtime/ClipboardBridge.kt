@@ -0,0 +1,123 @@
+package dev.waterui.android.runtime
+
+import android.content.ClipData
+import android.content.ClipboardManager
+import android.content.Context
+import android.net.Uri
+import android.os.Handler
+import android.os.Looper
+import android.util.Log
+import androidx.core.content.FileProvider
+import java.io.File
+
+/**
+ * Backs the Rust `Clipboard` extractor with the system [ClipboardManager].
+ * This is called from native code via JNI.
+ *
+ * Rust may call in from any thread, so clipboard access is always posted to the
+ * main thread. Reads complete asynchronously through [nativeCompleteReadText].
+ */
+object ClipboardBridge {
+    private const val TAG = "ClipboardBridge"
+    private const val CLIP_LABEL = "WaterUI"
+    private const val IMAGE_DIR = "waterui_clipboard"
+
+    private val mainHandler = Handler(Looper.getMainLooper())
+    private var appContext: Context? = null
+
+    /**
+     * Initialize with application context.
+     * Must be called before the clipboard is installed into an environment.
+     */
+    fun init(context: Context) {
+        appContext = context.applicationContext
+    }
+
+    private fun clipboard(): ClipboardManager? {
+        val context = appContext
+        if (context == null) {
+            Log.e(TAG, "ClipboardBridge not initialized - call init() first")
+            return null
+        }
+        return context.getSystemService(Context.CLIPBOARD_SERVICE) as? ClipboardManager
+    }
+
+    /**
+     * Replace the clipboard content with plain text.
+     * Called from native code via JNI.
+     */
+    @JvmStatic
+    fun writeText(text: String) {
+        mainHandler.post {
+            clipboard()?.setPrimaryClip(ClipData.newPlainText(CLIP_LABEL, text))
+        }
+    }
+
+    /**
+     * Read the clipboard as text and hand it back to Rust.
+     * Called from native code via JNI.
+     *
+     * @param callbackData Opaque pointer to callback data
+     * @param callbackFn Function pointer to call with the text (consumed exactly once)
+     */
+    @JvmStatic
+    fun readText(callbackData: Long, callbackFn: Long) {
+        mainHandler.post {
+            val text = clipboard()?.let { readPrimaryText(it) }
+            nativeCompleteReadText(callbackData, callbackFn, text)
+        }
+    }
+
+    /**
+     * Replace the clipboard content with a PNG image.
+     * Called from native code via JNI.
+     *
+     * The image is written to the cache directory and shared through the runtime's
+     * [WaterUiFileProvider], since Android clipboards carry images as content URIs.
+     */
+    @JvmStatic
+    fun writeImage(png: ByteArray) {
+        mainHandler.post {
+            val context = appContext ?: return@post
+            val manager = clipboard() ?: return@post
+            try {
+                val dir = File(context.cacheDir, IMAGE_DIR).apply { mkdirs() }
+                val file = File(dir, "clipboard.png")
+                file.writeBytes(png)
+                val uri = fileProviderUri(context, file)
+                manager.setPrimaryClip(ClipData.newUri(context.contentResolver, CLIP_LABEL, uri))
+            } catch (e: Exception) {
+                Log.e(TAG, "Failed to write image to clipboard", e)
+            }
+        }
+    }
+
+    /**
+     * Returns the primary clip coerced to text, or null if the clipboard is empty.
+     */
+    internal fun readPrimaryText(manager: ClipboardManager): String? {
+        val context = appContext ?: return null
+        val clip = manager.primaryClip ?: return null
+        if (clip.itemCount == 0) return null
+        return clip.getItemAt(0).coerceToText(context)?.toString()
+    }
+
+    /**
+     * Returns a content URI for a file under one of the [WaterUiFileProvider] paths.
+     */
+    internal fun fileProviderUri(context: Context, file: File): Uri =
+        FileProvider.getUriForFile(context, authority(context), file)
+
+    private fun authority(context: Context): String =
+        "${context.packageName}.waterui.clipboard"
+
+    /**
+     * Native method to complete a read callback.
+     * This calls the Rust callback with the clipboard text, or null if there is none.
+     */
+    private external fun nativeCompleteReadText(
+        callbackData: Long,
+        callbackFn: Long,
+        text: String?
+    )
+}
@@ -0,0 +1,174 @@
+package dev.waterui.android.runtime
+
+import android.content.Context
+import android.media.AudioAttributes
+import android.media.SoundPool
+import android.net.Uri
+import android.os.Handler
+import android.os.Looper
+import android.util.Log
+import android.view.HapticFeedbackConstants
+import android.view.View
+import java.io.File
+import java.io.IOException
+import java.lang.ref.WeakReference
+
+/**
+ * Backs the Rust `Feedback` service with view haptics and a [SoundPool].
+ * This is called from native code via JNI, possibly off the main thread.
+ *
+ * Haptics go through [View.performHapticFeedback] on the attached root view,
+ * so they respect the user's touch feedback setting. Sounds are bundled assets
+ * loaded once and kept in the pool for low-latency playback. Plays requested
+ * while a sound is still loading are queued until it finishes, and a sound that
+ * fails to load is evicted so the next play tries again.
+ */
+object FeedbackBridge {
+    private const val TAG = "FeedbackBridge"
+    private const val SOUND_CACHE_DIR = "waterui_sounds"
+    private const val MAX_STREAMS = 4
+
+    private const val IMPACT_LIGHT = 0
+    private const val IMPACT_MEDIUM = 1
+    private const val IMPACT_HEAVY = 2
+
+    private val mainHandler = Handler(Looper.getMainLooper())
+    private var appContext: Context? = null
+    private var hostView: WeakReference<View>? = null
+
+    // Accessed on the main thread only
+    private var soundPool: SoundPool? = null
+    private val soundIds = mutableMapOf<String, Int>()
+    private val loadedSounds = mutableSetOf<Int>()
+    // Plays queued per sound that is still loading
+    private val queuedPlays = mutableMapOf<Int, Int>()
+
+    /**
+     * Initialize with application context.
+     * Must be called before the service is installed into an environment.
+     */
+    fun init(context: Context) {
+        appContext = context.applicationContext
+    }
+
+    /**
+     * Routes haptics through [view] while it is attached to a window.
+     */
+    fun attach(view: View) {
+        hostView = WeakReference(view)
+    }
+
+    /**
+     * Stops routing haptics through [view].
+     */
+    fun detach(view: View) {
+        if (hostView?.get() === view) {
+            hostView = null
+        }
+    }
+
+    /**
+     * Plays an impact haptic.
+     *
+     * @param style The impact style (0=Light, 1=Medium, 2=Heavy)
+     */
+    @JvmStatic
+    fun impact(style: Int) {
+        val constant = when (style) {
+            IMPACT_LIGHT -> HapticFeedbackConstants.KEYBOARD_TAP
+            IMPACT_HEAVY -> HapticFeedbackConstants.LONG_PRESS
+            else -> HapticFeedbackConstants.VIRTUAL_KEY
+        }
+        performHaptic(constant)
+    }
+
+    /**
+     * Plays the tick used when a selection changes, such as a stepper value.
+     */
+    @JvmStatic
+    fun selectionChanged() {
+        performHaptic(HapticFeedbackConstants.CLOCK_TICK)
+    }
+
+    /**
+     * Plays the bundled asset [asset] as a sound effect.
+     * Accepts asset names as well as `file:///android_asset/` URLs.
+     */
+    @JvmStatic
+    fun playSound(asset: String) {
+        mainHandler.post {
+            val pool = soundPool ?: createSoundPool().also { soundPool = it }
+            val id = soundIds[asset] ?: loadSound(pool, asset)?.also { soundIds[asset] = it } ?: return@post
+            if (id in loadedSounds) {
+                pool.play(id, 1f, 1f, 1, 0, 1f)
+            } else {
+                // Playback starts once the pool finishes decoding the sound
+                queuedPlays[id] = (queuedPlays[id] ?: 0) + 1
+            }
+        }
+    }
+
+    private fun performHaptic(constant: Int) {
+        mainHandler.post {
+            hostView?.get()?.performHapticFeedback(constant)
+        }
+    }
+
+    private fun createSoundPool(): SoundPool {
+        val attributes = AudioAttributes.Builder()
+            .setUsage(AudioAttributes.USAGE_ASSISTANCE_SONIFICATION)
+            .setContentType(AudioAttributes.CONTENT_TYPE_SONIFICATION)
+            .build()
+        return SoundPool.Builder()
+            .setMaxStreams(MAX_STREAMS)
+            .setAudioAttributes(attributes)
+            .build()
+            .apply {
+                // Created on the main looper, so completions arrive on the main thread
+                setOnLoadCompleteListener { pool, id, status ->
+                    val plays = queuedPlays.remove(id) ?: 0
+                    if (status != 0) {
+                        Log.e(TAG, "Failed to decode sound $id (status $status)")
+                        soundIds.values.remove(id)
+                        pool.unload(id)
+                        return@setOnLoadCompleteListener
+                    }
+                    loadedSounds.add(id)
+                    repeat(plays.coerceAtMost(MAX_STREAMS)) { pool.play(id, 1f, 1f, 1, 0, 1f) }
+                }
+            }
+    }
+
+    private fun loadSound(pool: SoundPool, asset: String): Int? {
+        val context = appContext
+        if (context == null) {
+            Log.e(TAG, "FeedbackBridge not initialized - call init() first")
+            return null
+        }
+        val name = Uri.decode(asset.removePrefix(AssetBridge.BASE_URL)).removePrefix("/")
+        return try {
+            context.assets.openFd(name).use { pool.load(it, 1) }
+        } catch (e: IOException) {
+            // Compressed assets have no file descriptor; load them from a cached copy
+            try {
+                val file = File(File(context.cacheDir, SOUND_CACHE_DIR), name)
+                if (!file.exists()) {
+                    file.parentFile?.mkdirs()
+                    // Copy through a temporary file so an interrupted copy is never loaded
+                    val partial = File(file.parentFile, "${file.name}.partial")
+                    context.assets.open(name).use { input ->
+                        partial.outputStream().use { input.copyTo(it) }
+                    }
+                    if (!partial.renameTo(file)) {
+                        partial.delete()
+                        throw IOException("Failed to move $partial into place")
+                    }
+                }
+                pool.load(file.path, 1)
+            } catch (e: IOException) {
+                Log.e(TAG, "Failed to load sound $asset", e)
+                null
+            }
+        }
+    }
+}
@@ -166,7 +166,22 @@ data class WatcherStruct(val dataPtr: Long, val callPtr: Long, val dropPtr: Long
 
 data class ButtonStruct(val labelPtr: Long, val actionPtr: Long, val style: Int)
 
-data class TextStruct(val contentPtr: Long)
+/**
+ * Text component data.
+ * - lineLimit: maximum number of lines (0 = unlimited)
+ * - truncationMode: 0=head, 1=middle, 2=tail
+ * - multilineAlignment: 0=leading, 1=center, 2=trailing
+ * - lineSpacing / kerning: extra spacing in points
+ */
+data class TextStruct(
+    val contentPtr: Long,
+    val lineLimit: Int,
+    val truncationMode: Int,
+    val multilineAlignment: Int,
+    val lineSpacing: Float,
+    val kerning: Float,
+    val monospacedDigits: Boolean
+)
 
 data class PlainStruct(val textBytes: ByteArray) {
     override fun equals(other: Any?): Boolean {
@@ -179,7 +194,18 @@ data class PlainStruct(val textBytes: ByteArray) {
 
 data class ColorStruct(val colorPtr: Long)
 
-data class TextFieldStruct(val labelPtr: Long, val valuePtr: Long, val promptPtr: Long, val keyboardType: Int)
+/**
+ * TextField component data.
+ * - keyboardType: WuiKeyboardType value
+ * - onSubmitPtr: WuiAction run on keyboard submit (0 = none)
+ */
+data class TextFieldStruct(
+    val labelPtr: Long,
+    val valuePtr: Long,
+    val promptPtr: Long,
+    val keyboardType: Int,
+    val onSubmitPtr: Long
+)
 
 data class SecureFieldStruct(val labelPtr: Long, val valuePtr: Long)
 
@@ -265,9 +291,32 @@ data class DatePickerStruct(
     fun type(): DatePickerType = DatePickerType.fromInt(pickerType)
 }
 
-data class ProgressStruct(val labelPtr: Long, val valueLabelPtr: Long, val valuePtr: Long, val style: Int)
+/**
+ * Progress component data.
+ * - valuePtr: Computed<f64> completed fraction (non-finite = indeterminate)
+ * - tintPtr: WuiColor pointer (0 = theme accent)
+ */
+data class ProgressStruct(
+    val labelPtr: Long,
+    val valueLabelPtr: Long,
+    val valuePtr: Long,
+    val style: Int,
+    val tintPtr: Long
+)
 
-data class ScrollStruct(val axis: Int, val contentPtr: Long)
+/**
+ * ScrollView component data.
+ * - proxyPtr: WuiScrollProxy pointer (0 when the scroll view is not controlled)
+ * - paging: settle on multiples of the viewport size
+ * - lockAxis: lock two-axis drags to their dominant axis
+ */
+data class ScrollStruct(
+    val axis: Int,
+    val contentPtr: Long,
+    val proxyPtr: Long,
+    val paging: Boolean,
+    val lockAxis: Boolean
+)
 
 data class DynamicStruct(val dynamicPtr: Long)
 
@@ -432,6 +481,101 @@ data class MetadataOnEventStruct(
     val handlerPtr: Long
 )
 
+/**
+ * Metadata<OnPaste> struct for paste handlers.
+ * The handler can be called multiple times (Fn) with the pasted text.
+ */
+data class MetadataOnPasteStruct(
+    val contentPtr: Long,
+    val handlerPtr: Long
+)
+
+/**
+ * Metadata<OnPointer> struct for raw pointer events.
+ * The handler is called for every pointer event with coordinates in points.
+ */
+data class MetadataOnPointerStruct(
+    val contentPtr: Long,
+    val handlerPtr: Long
+)
+
+/**
+ * Pointer event phases (matches WuiPointerPhase in FFI).
+ */
+object PointerPhase {
+    const val DOWN = 0
+    const val MOVE = 1
+    const val UP = 2
+    const val CANCEL = 3
+    const val HOVER = 4
+}
+
+/**
+ * Pointer device kinds (matches WuiPointerKind in FFI).
+ */
+object PointerKind {
+    const val TOUCH = 0
+    const val MOUSE = 1
+    const val PEN = 2
+}
+
+/**
+ * Key phases (matches WuiKeyPhase in FFI).
+ */
+object KeyPhase {
+    const val DOWN = 0
+    const val UP = 1
+}
+
+/**
+ * Modifier key bits passed with key events.
+ */
+object KeyModifiers {
+    const val SHIFT = 1
+    const val CONTROL = 2
+    const val ALT = 4
+    const val META = 8
+}
+
+/**
+ * Back gesture phases (matches WuiBackPhase in FFI).
+ */
+object BackPhase {
+    const val STARTED = 0
+    const val PROGRESSED = 1
+    const val CANCELLED = 2
+    const val INVOKED = 3
+}
+
+/**
+ * Log levels (matches WuiLogLevel in FFI).
+ */
+object LogLevel {
+    const val ERROR = 0
+    const val WARN = 1
+    const val INFO = 2
+    const val DEBUG = 3
+    const val TRACE = 4
+}
+
+/**
+ * Metadata<OnSizeChange> struct for size reporting.
+ * The handler receives the measured content size (in points) after layout.
+ */
+data class MetadataOnSizeChangeStruct(
+    val contentPtr: Long,
+    val handlerPtr: Long
+)
+
+/**
+ * Metadata<Refreshable> struct for pull-to-refresh.
+ * The handler starts an async refresh and signals its completion through a callback.
+ */
+data class MetadataRefreshableStruct(
+    val contentPtr: Long,
+    val handlerPtr: Long
+)
+
 /**
  * Metadata<Cursor> struct for cursor style.
  * Contains a Computed<CursorStyle> for reactive cursor updates.
@@ -452,6 +596,25 @@ data class MetadataShadowStruct(
     val radius: Float
 )
 
+/**
+ * Metadata<Overlay> / Metadata<BackgroundView> struct for layered views.
+ * - layerPtr: AnyView pointer for the layered view
+ * - alignment: 0..8 from top-leading to bottom-trailing, row by row
+ */
+data class MetadataLayerStruct(
+    val contentPtr: Long,
+    val layerPtr: Long,
+    val alignment: Int
+)
+
+/**
+ * Metadata<ViewId> struct identifying a view as a scroll target.
+ */
+data class MetadataViewIdStruct(
+    val contentPtr: Long,
+    val id: Int
+)
+
 /**
  * Metadata<Border> struct for border effects.
  * Contains border color, width, corner radius, and which edges to draw.
@@ -660,14 +823,31 @@ data class MetadataClipShapeStruct(
 }
 
 /**
- * MenuItem struct for context menu items.
- * Contains a label (styled text pointer) and an action pointer.
+ * MenuItem struct for menu and context menu items.
+ * - labelPtr: styled text pointer
+ * - actionPtr: shared action pointer (0 for dividers and submenus)
+ * - kind: MenuItemKind value
+ * - checked: state of a toggle item
+ * - submenuPtr: borrowed Computed<MenuItems> of a submenu item (0 otherwise)
  */
 data class MenuItemStruct(
     val labelPtr: Long,
-    val actionPtr: Long
+    val actionPtr: Long,
+    val kind: Int,
+    val checked: Boolean,
+    val submenuPtr: Long
 )
 
+/**
+ * Menu item kinds (matches WuiMenuItemKind in FFI).
+ */
+object MenuItemKind {
+    const val ACTION = 0
+    const val TOGGLE = 1
+    const val SUBMENU = 2
+    const val DIVIDER = 3
+}
+
 /**
  * Metadata<ContextMenu> struct for context menu.
  * Contains the content view and a computed list of menu items.
@@ -697,15 +877,23 @@ data class StyledStrStruct(val chunks: Array<StyledChunkStruct>) {
     override fun hashCode(): Int = chunks.contentHashCode()
 }
 
-data class StyledChunkStruct(val text: String, val style: TextStyleStruct)
+/**
+ * A run of styled text.
+ * - attachmentPtr: AnyView pointer for an inline view (0 for plain text); `text` is then a U+FFFC placeholder
+ */
+data class StyledChunkStruct(val text: String, val style: TextStyleStruct, val attachmentPtr: Long)
 
+/**
+ * - tapPtr: WuiSharedAction pointer fired when the range is tapped (0 if none)
+ */
 data class TextStyleStruct(
     val fontPtr: Long,
     val italic: Boolean,
     val underline: Boolean,
     val strikethrough: Boolean,
     val foregroundPtr: Long,
-    val backgroundPtr: Long
+    val backgroundPtr: Long,
+    val tapPtr: Long
 )
 
 data class PickerItemStruct(val tag: Int, val label: StyledStrStruct)
@@ -715,8 +903,29 @@ data class PickerItemStruct(val tag: Int, val label: StyledStrStruct)
 /**
  * Photo component data.
  * - source: URL of the image to display
+ * - onEventPtr: Boxed event callback, released with waterui_drop_photo_event
+ * - resizable: Scale to the frame instead of using the natural size
+ * - contentMode: Matches WuiAspectRatio (0=Fit, 1=Fill, 2=Stretch)
+ * - cornerRadius: In points; rounded images are always clipped
+ * - tintPtr: WuiColor pointer (owned), or 0 for no tint
+ */
+data class PhotoStruct(
+    val source: String,
+    val onEventPtr: Long,
+    val resizable: Boolean,
+    val contentMode: Int,
+    val clipped: Boolean,
+    val cornerRadius: Float,
+    val tintPtr: Long
+)
+
+/**
+ * Photo event types (matches WuiPhotoEventType in FFI).
  */
-data class PhotoStruct(val source: String)
+object PhotoEventType {
+    const val LOADED = 0
+    const val ERROR = 1
+}
 
 // ========== Video Structs ==========
 
@@ -747,14 +956,37 @@ data class VideoStruct2(
  * - volumePtr: Binding<Volume> pointer (f32)
  * - aspectRatio: 0=Fit, 1=Fill, 2=Stretch
  * - showControls: Whether to show native playback controls
+ * - playingPtr: Binding<bool> pointer for play/pause (0 = autoplay)
+ * - currentTimePtr: Binding<f64> pointer for the position in seconds (0 = none)
+ * - durationPtr: Binding<f64> pointer the duration in seconds is written to (0 = none)
+ * - ratePtr: Computed<f64> pointer for the playback rate (0 = normal speed)
+ * - loops: Whether to loop playback
+ * - onEventPtr: Boxed event callback, released with waterui_drop_video_event
  */
 data class VideoPlayerStruct(
     val sourcePtr: Long,
     val volumePtr: Long,
     val aspectRatio: Int,
-    val showControls: Boolean
+    val showControls: Boolean,
+    val playingPtr: Long,
+    val currentTimePtr: Long,
+    val durationPtr: Long,
+    val ratePtr: Long,
+    val loops: Boolean,
+    val onEventPtr: Long
 )
 
+/**
+ * Video event types (matches WuiVideoEventType in FFI).
+ */
+object VideoEventType {
+    const val READY_TO_PLAY = 0
+    const val ENDED = 1
+    const val ERROR = 2
+    const val BUFFERING = 3
+    const val BUFFERING_ENDED = 4
+}
+
 /**
  * WebView raw component pointer.
  * Holds the opaque WuiWebView pointer for lifecycle management.
@@ -765,8 +997,23 @@ data class WebViewStruct(val webviewPtr: Long)
  * GpuSurface component data.
  * - rendererPtr: Opaque pointer to the boxed GpuRenderer trait object.
  *                This is consumed during init and should not be used after.
+ * - presentMode: How frames are presented (see [PresentMode])
+ * - sampleCount: MSAA sample count; 1 disables multisampling
  */
-data class GpuSurfaceStruct(val rendererPtr: Long)
+data class GpuSurfaceStruct(
+    val rendererPtr: Long,
+    val presentMode: Int,
+    val sampleCount: Int
+)
+
+/**
+ * GpuSurface present modes (matches WuiPresentMode in FFI).
+ */
+object PresentMode {
+    const val FIFO = 0
+    const val MAILBOX = 1
+    const val IMMEDIATE = 2
+}
 
 // ========== MediaPicker Structs ==========
 
@@ -812,7 +1059,18 @@ data class ResolvedColorStruct(
     val headroom: Float
 )
 
-data class ResolvedFontStruct(val size: Float, val weight: Int)
+/**
+ * Resolved font from Rust (matches WuiResolvedFont in FFI).
+ * - family: Family name, empty for the system default
+ * - width: Matches WuiFontWidth (0=Condensed, 1=Normal, 2=Expanded)
+ */
+data class ResolvedFontStruct(
+    val size: Float,
+    val weight: Int,
+    val family: String,
+    val width: Int,
+    val italic: Boolean
+)
 
 // ========== Type ID Struct ==========
 
@@ -928,24 +1186,150 @@ data class TabsStruct(
  * - editingPtr: WuiComputed<bool> pointer for edit mode state (0 if not provided)
  * - onDeletePtr: WuiIndexAction pointer for delete callback (0 if not provided)
  * - onMovePtr: WuiMoveAction pointer for move/reorder callback (0 if not provided)
+ * - separator: 0=automatic, 1=visible, 2=hidden
+ * - selectionPtr: WuiBinding<Id> pointer for the selected row id (0 if not selectable)
  */
 data class ListStruct(
     val contentsPtr: Long,
     val editingPtr: Long,
     val onDeletePtr: Long,
-    val onMovePtr: Long
+    val onMovePtr: Long,
+    val separator: Int,
+    val selectionPtr: Long
 )
 
 /**
  * ListItem component data.
  * - contentPtr: AnyView pointer for item content
  * - deletablePtr: WuiComputed<bool> pointer for deletable state (0 if not provided)
+ * - leadingActions / trailingActions: swipe actions revealed from each edge
  */
 data class ListItemStruct(
     val contentPtr: Long,
-    val deletablePtr: Long
+    val deletablePtr: Long,
+    val leadingActions: Array<SwipeActionStruct>,
+    val trailingActions: Array<SwipeActionStruct>
+) {
+    override fun equals(other: Any?): Boolean {
+        if (this === other) return true
+        if (other !is ListItemStruct) return false
+        return contentPtr == other.contentPtr &&
+               deletablePtr == other.deletablePtr &&
+               leadingActions.contentEquals(other.leadingActions) &&
+               trailingActions.contentEquals(other.trailingActions)
+    }
+    override fun hashCode(): Int {
+        var result = contentPtr.hashCode()
+        result = 31 * result + deletablePtr.hashCode()
+        result = 31 * result + leadingActions.contentHashCode()
+        result = 31 * result + trailingActions.contentHashCode()
+        return result
+    }
+}
+
+/**
+ * Swipe action on a list row.
+ * - labelPtr: WuiComputed<StyledStr> pointer for the label
+ * - role: 0=normal, 1=destructive
+ * - actionPtr: WuiSharedAction pointer invoked when the action fires
+ */
+data class SwipeActionStruct(
+    val labelPtr: Long,
+    val role: Int,
+    val actionPtr: Long
+)
+
+/**
+ * ListSection component data.
+ * - headerPtr: AnyView pointer for the pinned header (0 if none)
+ * - footerPtr: AnyView pointer for the footer (0 if none)
+ * - contentsPtr: WuiAnyViews pointer containing ListItem views
+ * - onDeletePtr: IndexAction pointer for deleting a row of the section (0 if none)
+ * - onMovePtr: MoveAction pointer for reordering rows of the section (0 if none)
+ */
+data class ListSectionStruct(
+    val headerPtr: Long,
+    val footerPtr: Long,
+    val contentsPtr: Long,
+    val onDeletePtr: Long,
+    val onMovePtr: Long
+)
+
+// ========== Lazy Stack Structs ==========
+
+/**
+ * LazyVStack / LazyHStack component data.
+ * - axis: 0=horizontal, 1=vertical
+ * - spacing: spacing between items in points
+ * - estimatedItemSize: estimated main-axis item size in points (0 if unknown)
+ * - contentsPtr: WuiAnyViews pointer; items are materialized on demand
+ */
+data class LazyStackStruct(
+    val axis: Int,
+    val spacing: Float,
+    val estimatedItemSize: Float,
+    val contentsPtr: Long
 )
 
+// ========== Geometry Reader Structs ==========
+
+/**
+ * GeometryReader component data.
+ * - proxyPtr: WuiGeometryProxy pointer fed with the resolved frame after layout
+ * - contentPtr: AnyView pointer for the content built from the proxy
+ */
+data class GeometryReaderStruct(
+    val proxyPtr: Long,
+    val contentPtr: Long
+)
+
+// ========== Lazy Grid Structs ==========
+
+/**
+ * GridItem track definition.
+ * - size: 0=fixed, 1=flexible, 2=adaptive
+ * - minimum / maximum: track bounds in points (maximum may be infinite)
+ * - spacing: spacing after this track in points
+ * - alignment: 0=leading, 1=center, 2=trailing
+ */
+data class GridItemStruct(
+    val size: Int,
+    val minimum: Float,
+    val maximum: Float,
+    val spacing: Float,
+    val alignment: Int
+)
+
+/**
+ * LazyVGrid / LazyHGrid component data.
+ * - axis: 0=horizontal (items define rows), 1=vertical (items define columns)
+ * - items: track definitions across the cross axis
+ * - spacing: spacing between cells along the scrolling axis in points
+ * - contentsPtr: WuiAnyViews pointer; cells are materialized on demand
+ */
+data class LazyGridStruct(
+    val axis: Int,
+    val items: Array<GridItemStruct>,
+    val spacing: Float,
+    val contentsPtr: Long
+) {
+    override fun equals(other: Any?): Boolean {
+        if (this === other) return true
+        if (other !is LazyGridStruct) return false
+        return axis == other.axis &&
+               items.contentEquals(other.items) &&
+               spacing == other.spacing &&
+               contentsPtr == other.contentsPtr
+    }
+    override fun hashCode(): Int {
+        var result = axis
+        result = 31 * result + items.contentHashCode()
+        result = 31 * result + spacing.hashCode()
+        result = 31 * result + contentsPtr.hashCode()
+        return result
+    }
+}
+
 // ========== Window and App Structs ==========
 
 /**
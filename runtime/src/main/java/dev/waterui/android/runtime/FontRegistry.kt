@@ -0,0 +1,66 @@
+package dev.waterui.android.runtime
+
+import android.content.Context
+import android.graphics.Typeface
+import android.util.Log
+import java.io.File
+
+/**
+ * Loads app-provided fonts registered in the Rust `FontRegistry`.
+ *
+ * Font data is looked up through the environment by descriptor, written once
+ * to the cache directory and loaded as a [Typeface]. Loaded typefaces are kept
+ * for the lifetime of the process; misses are not cached because fonts
+ * registered from a URL only become available once they have been downloaded.
+ */
+object FontRegistry {
+    private const val TAG = "FontRegistry"
+    private const val FONT_DIR = "waterui_fonts"
+
+    private data class FontKey(val family: String, val weight: Int, val width: Int, val italic: Boolean)
+
+    private var appContext: Context? = null
+    private val typefaces = HashMap<FontKey, Typeface>()
+
+    /**
+     * Initialize with application context.
+     * Must be called before any text is rendered.
+     */
+    fun init(context: Context) {
+        appContext = context.applicationContext
+    }
+
+    /**
+     * Returns the registered face closest to [font], or null when the font uses
+     * the system family or nothing is registered under its family.
+     */
+    internal fun typeface(env: WuiEnvironment, font: ResolvedFontStruct): Typeface? {
+        if (font.family.isEmpty()) return null
+        val key = FontKey(font.family, font.weight, font.width, font.italic)
+        typefaces[key]?.let { return it }
+
+        val context = appContext
+        if (context == null) {
+            Log.e(TAG, "FontRegistry not initialized - call init() first")
+            return null
+        }
+        val data = NativeBindings.waterui_font_registry_lookup(
+            env.raw(), key.family, key.weight, key.width, key.italic
+        ) ?: return null
+
+        val typeface = try {
+            val dir = File(context.cacheDir, FONT_DIR).apply { mkdirs() }
+            // Named by content so descriptors resolving to the same face share a file
+            val file = File(dir, "${data.size}-${Integer.toHexString(data.contentHashCode())}.font")
+            if (!file.exists()) {
+                file.writeBytes(data)
+            }
+            Typeface.createFromFile(file)
+        } catch (e: Exception) {
+            Log.e(TAG, "Failed to load font '${font.family}'", e)
+            return null
+        }
+        typefaces[key] = typeface
+        return typeface
+    }
+}
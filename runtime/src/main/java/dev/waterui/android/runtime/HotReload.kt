@@ -0,0 +1,114 @@
+package dev.waterui.android.runtime
+
+import android.os.FileObserver
+import android.os.Handler
+import android.os.Looper
+import android.util.Log
+import java.io.File
+
+/**
+ * Swaps a rebuilt application library into the running app.
+ *
+ * The CLI either copies each rebuild into a watched directory under a new file
+ * name, or streams it over the live reload channel. Once it is written, every
+ * attached [WaterUiRootView] is torn down, the state of hot-reload bindings is
+ * carried over from the old library to the new one, and the roots are rebuilt
+ * in place. A library built against a different reload ABI is rejected and the
+ * running one is kept.
+ */
+object HotReload {
+    private const val TAG = "WaterUI.HotReload"
+
+    private val mainHandler = Handler(Looper.getMainLooper())
+
+    // Accessed on the main thread only
+    private val roots = mutableSetOf<WaterUiRootView>()
+    private var observer: FileObserver? = null
+    private var pendingEndpoint: Pair<String, Int>? = null
+
+    /**
+     * Watches [directory] for rebuilt libraries (`*.so`) and reloads each one.
+     */
+    fun watch(directory: String) {
+        val dir = File(directory).apply { mkdirs() }
+        observer?.stopWatching()
+        @Suppress("DEPRECATION") // FileObserver(File, Int) requires API 29
+        observer = object : FileObserver(dir.absolutePath, CLOSE_WRITE or MOVED_TO) {
+            override fun onEvent(event: Int, path: String?) {
+                if (path == null || !path.endsWith(".so")) return
+                val library = File(dir, path)
+                mainHandler.post { reload(library) }
+            }
+        }.also { it.startWatching() }
+        Log.i(TAG, "Watching $directory for rebuilt libraries")
+    }
+
+    /**
+     * Connects to the CLI's live reload channel at [host]:[port]. Rebuilt
+     * libraries are received into [directory] and reloaded as they arrive.
+     */
+    fun connect(host: String, port: Int, directory: String) {
+        File(directory).mkdirs()
+        NativeBindings.waterui_configure_hot_reload_endpoint(host, port, directory)
+        Log.i(TAG, "Listening for rebuilds from $host:$port")
+    }
+
+    /**
+     * Connects like [connect], receiving into the app's files dir. Without a
+     * context at hand, the connection waits for the first [WaterUiRootView].
+     */
+    fun connectToFilesDir(host: String, port: Int) {
+        mainHandler.post {
+            val root = roots.firstOrNull()
+            if (root != null) {
+                connect(host, port, root.context.filesDir.absolutePath)
+            } else {
+                pendingEndpoint = host to port
+            }
+        }
+    }
+
+    /**
+     * Reloads a library received over the live reload channel.
+     * Called from native code via JNI on a background thread.
+     */
+    @JvmStatic
+    fun onRebuilt(path: String) {
+        mainHandler.post { reload(File(path)) }
+    }
+
+    /**
+     * Replaces the running library with [library] and rebuilds every attached root.
+     * Must be called on the main thread.
+     *
+     * @return true if the library was swapped in
+     */
+    fun reload(library: File): Boolean {
+        Log.i(TAG, "Reloading from ${library.name}")
+        val state = NativeBindings.waterui_reload_save_state()
+        val active = roots.toList()
+
+        // Nothing built from the old library may outlive the swap
+        active.forEach { it.teardownApp() }
+        val swapped = NativeBindings.reloadLibrary(library.absolutePath)
+        if (swapped) {
+            NativeBindings.waterui_reload_restore_state(state)
+        } else {
+            Log.w(TAG, "Keeping the running library; restart the app to pick up ${library.name}")
+        }
+        active.forEach { it.restartApp() }
+        return swapped
+    }
+
+    internal fun register(root: WaterUiRootView) {
+        roots.add(root)
+        pendingEndpoint?.let { (host, port) ->
+            pendingEndpoint = null
+            connect(host, port, root.context.filesDir.absolutePath)
+        }
+    }
+
+    internal fun unregister(root: WaterUiRootView) {
+        roots.remove(root)
+    }
+}
@@ -0,0 +1,113 @@
+package dev.waterui.android.runtime
+
+import android.content.Context
+import android.graphics.Bitmap
+import android.graphics.BitmapFactory
+import android.net.Uri
+import android.os.Handler
+import android.os.Looper
+import android.util.LruCache
+import java.io.File
+import java.io.IOException
+import java.net.URL
+import java.security.MessageDigest
+import java.util.concurrent.Executors
+import java.util.concurrent.Future
+
+/**
+ * Loads images for `Photo` views.
+ *
+ * Images are decoded off the main thread and downsampled to the size they are
+ * displayed at. Decoded bitmaps are kept in an LRU memory cache sized to an
+ * eighth of the heap, and remote images are stored in a disk cache so they are
+ * only downloaded once.
+ */
+object ImageLoader {
+    private const val DISK_CACHE_DIR = "waterui_images"
+    private const val TIMEOUT_MS = 10000
+
+    private val mainHandler = Handler(Looper.getMainLooper())
+    private val executor = Executors.newFixedThreadPool(4)
+    private val memoryCache = object : LruCache<String, Bitmap>(
+        (Runtime.getRuntime().maxMemory() / 1024 / 8).toInt()
+    ) {
+        override fun sizeOf(key: String, value: Bitmap): Int = value.byteCount / 1024
+    }
+
+    /**
+     * Loads [source] for display at [targetWidth] x [targetHeight] pixels and
+     * invokes [callback] on the main thread with the bitmap, or with the error
+     * that prevented loading it. The returned handle cancels the load.
+     */
+    fun load(
+        context: Context,
+        source: String,
+        targetWidth: Int,
+        targetHeight: Int,
+        callback: (Result<Bitmap>) -> Unit
+    ): Future<*>? {
+        val key = "$source@${targetWidth}x$targetHeight"
+        memoryCache.get(key)?.let { cached ->
+            callback(Result.success(cached))
+            return null
+        }
+
+        val appContext = context.applicationContext
+        return executor.submit {
+            val result = runCatching {
+                val bytes = readSource(appContext, source)
+                decode(bytes, targetWidth, targetHeight)
+                    ?: throw IOException("Unsupported image format: $source")
+            }
+            result.onSuccess { memoryCache.put(key, it) }
+            if (!Thread.currentThread().isInterrupted) {
+                mainHandler.post { callback(result) }
+            }
+        }
+    }
+
+    private fun readSource(context: Context, source: String): ByteArray {
+        AssetBridge.openUrl(source)?.use { return it.readBytes() }
+        val uri = Uri.parse(source)
+        return when (uri.scheme) {
+            "http", "https" -> readRemote(context, source)
+            "content", "android.resource" -> context.contentResolver.openInputStream(uri)?.use { it.readBytes() }
+                ?: throw IOException("Cannot open $source")
+            "file" -> File(uri.path ?: source).readBytes()
+            else -> File(source).readBytes()
+        }
+    }
+
+    private fun readRemote(context: Context, url: String): ByteArray {
+        val dir = File(context.cacheDir, DISK_CACHE_DIR).apply { mkdirs() }
+        val file = File(dir, sha256(url))
+        if (file.exists()) {
+            return file.readBytes()
+        }
+        val connection = URL(url).openConnection()
+        connection.connectTimeout = TIMEOUT_MS
+        connection.readTimeout = TIMEOUT_MS
+        val bytes = connection.getInputStream().use { it.readBytes() }
+        // Write through a temporary file so a partial download is never served
+        val partial = File(dir, "${file.name}.partial")
+        partial.writeBytes(bytes)
+        partial.renameTo(file)
+        return bytes
+    }
+
+    private fun decode(bytes: ByteArray, targetWidth: Int, targetHeight: Int): Bitmap? {
+        val bounds = BitmapFactory.Options().apply { inJustDecodeBounds = true }
+        BitmapFactory.decodeByteArray(bytes, 0, bytes.size, bounds)
+        if (bounds.outWidth <= 0 || bounds.outHeight <= 0) return null
+
+        val options = BitmapFactory.Options().apply {
+            inSampleSize = ImageSampling.sampleSize(bounds.outWidth, bounds.outHeight, targetWidth, targetHeight)
+        }
+        return BitmapFactory.decodeByteArray(bytes, 0, bytes.size, options)
+    }
+
+    private fun sha256(value: String): String =
+        MessageDigest.getInstance("SHA-256")
+            .digest(value.toByteArray())
+            .joinToString("") { "%02x".format(it) }
+}
@@ -0,0 +1,22 @@
+package dev.waterui.android.runtime
+
+/**
+ * Downsampling math for image decoding.
+ */
+object ImageSampling {
+    /**
+     * Returns the largest power-of-two sample size that keeps the decoded image
+     * at least as large as the target in both dimensions. A non-positive target
+     * dimension leaves that dimension unconstrained.
+     */
+    fun sampleSize(sourceWidth: Int, sourceHeight: Int, targetWidth: Int, targetHeight: Int): Int {
+        if (targetWidth <= 0 && targetHeight <= 0) return 1
+        var sample = 1
+        while (fits(sourceWidth / (sample * 2), targetWidth) && fits(sourceHeight / (sample * 2), targetHeight)) {
+            sample *= 2
+        }
+        return sample
+    }
+
+    private fun fits(size: Int, target: Int): Boolean = target <= 0 || size >= target
+}
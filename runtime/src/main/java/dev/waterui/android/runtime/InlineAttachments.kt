@@ -0,0 +1,130 @@
+package dev.waterui.android.runtime
+
+import android.graphics.Canvas
+import android.graphics.Paint
+import android.text.Spanned
+import android.text.style.ReplacementSpan
+import android.view.View
+import android.view.ViewGroup
+import android.widget.TextView
+
+/**
+ * Hosts the inline views and tap actions of a styled string shown in a [TextView].
+ *
+ * A TextView cannot have children, so attachment views live in the overlay of
+ * its parent: they are attached to the window (and dispose their watchers when
+ * removed) and are positioned over their placeholder span after every layout.
+ *
+ * Tap actions stay alive while their text can still be tapped: they are
+ * released once [shown] reports that newer text replaced it.
+ */
+class InlineAttachmentHost(
+    private val textView: TextView,
+    private val env: WuiEnvironment,
+    private val registry: RenderRegistry
+) {
+    private val views = mutableListOf<View>()
+    private var overlayParent: ViewGroup? = null
+
+    // Tap actions of the text on screen, and of text built but not shown yet
+    private var shownTaps = mutableListOf<TapSpan>()
+    private var pendingTaps = mutableListOf<TapSpan>()
+
+    init {
+        textView.addOnLayoutChangeListener { _, _, _, _, _, _, _, _, _ -> position() }
+        textView.disposeWith {
+            reset()
+            shownTaps.forEach { it.close() }
+            shownTaps.clear()
+        }
+    }
+
+    /**
+     * Removes the attachments of the previous text. Call before building new text.
+     */
+    fun reset() {
+        overlayParent?.let { parent -> views.forEach { parent.overlay.remove(it) } }
+        overlayParent = null
+        views.clear()
+        // Text built earlier but never shown, such as one whose animation was cancelled
+        pendingTaps.forEach { it.close() }
+        pendingTaps.clear()
+    }
+
+    /**
+     * Reports that the text built since the last [reset] is now displayed, releasing
+     * the tap actions of the text it replaced.
+     */
+    fun shown() {
+        shownTaps.forEach { it.close() }
+        shownTaps = pendingTaps
+        pendingTaps = mutableListOf()
+    }
+
+    internal fun tapSpan(actionPtr: Long): TapSpan =
+        TapSpan(actionPtr, env).also { pendingTaps += it }
+
+    internal fun span(attachmentPtr: Long): ReplacementSpan {
+        val view = inflateAnyView(textView.context, attachmentPtr, env, registry)
+        val unspecified = View.MeasureSpec.makeMeasureSpec(0, View.MeasureSpec.UNSPECIFIED)
+        view.measure(unspecified, unspecified)
+        view.layout(0, 0, view.measuredWidth, view.measuredHeight)
+        views += view
+        return AttachmentSpan(view)
+    }
+
+    private fun position() {
+        val layout = textView.layout ?: return
+        val text = textView.text as? Spanned ?: return
+        val parent = textView.parent as? ViewGroup ?: return
+        if (overlayParent !== parent) {
+            overlayParent?.let { old -> views.forEach { old.overlay.remove(it) } }
+            views.forEach { parent.overlay.add(it) }
+            overlayParent = parent
+        }
+
+        for (span in text.getSpans(0, text.length, AttachmentSpan::class.java)) {
+            val offset = text.getSpanStart(span)
+            val line = layout.getLineForOffset(offset)
+            val x = textView.left + textView.totalPaddingLeft - textView.scrollX +
+                layout.getPrimaryHorizontal(offset).toInt()
+            val baseline = textView.top + textView.totalPaddingTop - textView.scrollY +
+                layout.getLineBaseline(line)
+            val view = span.view
+            view.layout(x, baseline - view.measuredHeight, x + view.measuredWidth, baseline)
+        }
+    }
+}
+
+/**
+ * Reserves room in the text for an inline view sitting on the baseline.
+ * The view itself is drawn by the overlay of the text's parent.
+ */
+private class AttachmentSpan(val view: View) : ReplacementSpan() {
+    override fun getSize(
+        paint: Paint,
+        text: CharSequence?,
+        start: Int,
+        end: Int,
+        fm: Paint.FontMetricsInt?
+    ): Int {
+        if (fm != null) {
+            val height = view.measuredHeight
+            fm.ascent = minOf(fm.ascent, -height)
+            fm.top = minOf(fm.top, fm.ascent)
+        }
+        return view.measuredWidth
+    }
+
+    override fun draw(
+        canvas: Canvas,
+        text: CharSequence?,
+        start: Int,
+        end: Int,
+        x: Float,
+        top: Int,
+        y: Int,
+        bottom: Int,
+        paint: Paint
+    ) {}
+}
@@ -0,0 +1,74 @@
+package dev.waterui.android.runtime
+
+import android.view.KeyCharacterMap
+import android.view.KeyEvent
+
+/**
+ * Maps Android key events to the portable key names Rust receives: W3C
+ * `KeyboardEvent.key` values.
+ */
+internal object KeyNames {
+    private val named = mapOf(
+        KeyEvent.KEYCODE_ENTER to "Enter",
+        KeyEvent.KEYCODE_NUMPAD_ENTER to "Enter",
+        KeyEvent.KEYCODE_TAB to "Tab",
+        KeyEvent.KEYCODE_ESCAPE to "Escape",
+        KeyEvent.KEYCODE_DEL to "Backspace",
+        KeyEvent.KEYCODE_FORWARD_DEL to "Delete",
+        KeyEvent.KEYCODE_INSERT to "Insert",
+        KeyEvent.KEYCODE_DPAD_UP to "ArrowUp",
+        KeyEvent.KEYCODE_DPAD_DOWN to "ArrowDown",
+        KeyEvent.KEYCODE_DPAD_LEFT to "ArrowLeft",
+        KeyEvent.KEYCODE_DPAD_RIGHT to "ArrowRight",
+        KeyEvent.KEYCODE_MOVE_HOME to "Home",
+        KeyEvent.KEYCODE_MOVE_END to "End",
+        KeyEvent.KEYCODE_PAGE_UP to "PageUp",
+        KeyEvent.KEYCODE_PAGE_DOWN to "PageDown",
+        KeyEvent.KEYCODE_SHIFT_LEFT to "Shift",
+        KeyEvent.KEYCODE_SHIFT_RIGHT to "Shift",
+        KeyEvent.KEYCODE_CTRL_LEFT to "Control",
+        KeyEvent.KEYCODE_CTRL_RIGHT to "Control",
+        KeyEvent.KEYCODE_ALT_LEFT to "Alt",
+        KeyEvent.KEYCODE_ALT_RIGHT to "Alt",
+        KeyEvent.KEYCODE_META_LEFT to "Meta",
+        KeyEvent.KEYCODE_META_RIGHT to "Meta",
+        KeyEvent.KEYCODE_CAPS_LOCK to "CapsLock",
+        KeyEvent.KEYCODE_F1 to "F1",
+        KeyEvent.KEYCODE_F2 to "F2",
+        KeyEvent.KEYCODE_F3 to "F3",
+        KeyEvent.KEYCODE_F4 to "F4",
+        KeyEvent.KEYCODE_F5 to "F5",
+        KeyEvent.KEYCODE_F6 to "F6",
+        KeyEvent.KEYCODE_F7 to "F7",
+        KeyEvent.KEYCODE_F8 to "F8",
+        KeyEvent.KEYCODE_F9 to "F9",
+        KeyEvent.KEYCODE_F10 to "F10",
+        KeyEvent.KEYCODE_F11 to "F11",
+        KeyEvent.KEYCODE_F12 to "F12"
+    )
+
+    /**
+     * Returns the key name for [keyCode], or null for keys that are not forwarded,
+     * such as back, volume and dead keys.
+     *
+     * @param unicodeChar The character the key produces with the current modifiers, or 0
+     */
+    fun name(keyCode: Int, unicodeChar: Int): String? {
+        named[keyCode]?.let { return it }
+        if (unicodeChar == 0 || (unicodeChar and KeyCharacterMap.COMBINING_ACCENT) != 0) return null
+        if (Character.isISOControl(unicodeChar)) return null
+        return String(Character.toChars(unicodeChar))
+    }
+
+    /**
+     * Converts a [KeyEvent.getMetaState] into [KeyModifiers] bits.
+     */
+    fun modifiers(metaState: Int): Int {
+        var modifiers = 0
+        if ((metaState and KeyEvent.META_SHIFT_ON) != 0) modifiers = modifiers or KeyModifiers.SHIFT
+        if ((metaState and KeyEvent.META_CTRL_ON) != 0) modifiers = modifiers or KeyModifiers.CONTROL
+        if ((metaState and KeyEvent.META_ALT_ON) != 0) modifiers = modifiers or KeyModifiers.ALT
+        if ((metaState and KeyEvent.META_META_ON) != 0) modifiers = modifiers or KeyModifiers.META
+        return modifiers
+    }
+}
@@ -0,0 +1,32 @@
+package dev.waterui.android.runtime
+
+import android.os.Handler
+import android.os.Looper
+
+/**
+ * Backs the Rust `MainExecutor` with the Android main looper.
+ * This is called from native code via JNI.
+ *
+ * Rust posts tasks from any thread; each one runs exactly once on the main
+ * thread, in posting order.
+ */
+object MainExecutorBridge {
+    private val mainHandler = Handler(Looper.getMainLooper())
+
+    /**
+     * Schedule a Rust task on the main thread.
+     * Called from native code via JNI.
+     *
+     * @param taskData Opaque pointer to the task state
+     * @param taskFn Function pointer that runs and consumes the task
+     */
+    @JvmStatic
+    fun post(taskData: Long, taskFn: Long) {
+        mainHandler.post { nativeRun(taskData, taskFn) }
+    }
+
+    /**
+     * Native method to run a posted task.
+     */
+    private external fun nativeRun(taskData: Long, taskFn: Long)
+}
@@ -1,22 +1,43 @@
 package dev.waterui.android.runtime
 
+import android.net.Uri
 import android.util.Log
 import androidx.activity.ComponentActivity
 import androidx.activity.result.ActivityResultLauncher
 import androidx.activity.result.PickVisualMediaRequest
 import androidx.activity.result.contract.ActivityResultContracts
+import java.io.File
 
 /**
- * Manages presenting the Android photo picker and handling results.
+ * Manages presenting the Android photo picker and camera, and handling results.
  * This is called from native code via JNI.
+ *
+ * Captured photos and videos are registered with [MediaLoader] like picked
+ * media, so both load into the same `Media` values on the Rust side.
+ *
+ * The picker and the camera each hold at most one pending request, and every
+ * request is completed exactly once. A cancelled or failed request, or one made
+ * while another is pending, completes with [NO_SELECTION].
  */
 object MediaPickerManager {
     private const val TAG = "MediaPickerManager"
+    private const val CAPTURE_DIR = "waterui_capture"
+    private const val CAPTURE_MODE_VIDEO = 1
+
+    /** Reported to Rust when nothing was picked or captured; [MediaLoader] ids start at 1. */
+    private const val NO_SELECTION = 0
+
+    private class PendingCallback(val data: Long, val fn: Long)
 
     private var currentActivity: ComponentActivity? = null
     private var pickerLauncher: ActivityResultLauncher<PickVisualMediaRequest>? = null
-    private var pendingCallbackData: Long = 0
-    private var pendingCallbackFn: Long = 0
+    private var photoCaptureLauncher: ActivityResultLauncher<Uri>? = null
+    private var videoCaptureLauncher: ActivityResultLauncher<Uri>? = null
+    private var pickerCallback: PendingCallback? = null
+    private var captureCallback: PendingCallback? = null
+    private var pendingCaptureFile: File? = null
+    private var pendingCaptureUri: Uri? = null
+    private var pendingCaptureMimeType: String? = null
 
     /**
      * Initialize the media picker for the given activity.
@@ -25,29 +46,31 @@ object MediaPickerManager {
     fun initialize(activity: ComponentActivity) {
         currentActivity = activity
         MediaLoader.init(activity)
+        PermissionsBridge.initialize(activity)
 
         // Register the photo picker launcher
         pickerLauncher = activity.registerForActivityResult(
             ActivityResultContracts.PickVisualMedia()
         ) { uri ->
-            if (uri != null) {
+            val callback = pickerCallback
+            pickerCallback = null
+            val id = if (uri != null) {
                 // Register the URI and get a unique ID for Selected::load()
-                val mimeType = activity.contentResolver.getType(uri)
-                val id = MediaLoader.register(uri, mimeType)
-
-                // Call the native callback
-                if (pendingCallbackFn != 0L) {
-                    nativeCompletePresentCallback(pendingCallbackData, pendingCallbackFn, id)
-                    pendingCallbackData = 0
-                    pendingCallbackFn = 0
-                }
+                MediaLoader.register(uri, activity.contentResolver.getType(uri))
             } else {
                 Log.d(TAG, "No media selected")
-                // Clear pending callback
-                pendingCallbackData = 0
-                pendingCallbackFn = 0
+                NO_SELECTION
             }
+            callback?.let { complete(it, id) }
         }
+
+        // Register the camera launchers; both write into a FileProvider URI
+        photoCaptureLauncher = activity.registerForActivityResult(
+            ActivityResultContracts.TakePicture()
+        ) { success -> completeCapture(success) }
+        videoCaptureLauncher = activity.registerForActivityResult(
+            ActivityResultContracts.CaptureVideo()
+        ) { success -> completeCapture(success) }
     }
 
     /**
@@ -60,15 +83,21 @@ object MediaPickerManager {
      */
     @JvmStatic
     fun presentPicker(filter: Int, callbackData: Long, callbackFn: Long) {
+        val callback = PendingCallback(callbackData, callbackFn)
         val launcher = pickerLauncher
         if (launcher == null) {
             Log.e(TAG, "MediaPicker not initialized - call initialize() in Activity.onCreate()")
+            complete(callback, NO_SELECTION)
+            return
+        }
+        if (pickerCallback != null) {
+            Log.w(TAG, "Media picker already presented")
+            complete(callback, NO_SELECTION)
             return
         }
 
         // Store callback for later
-        pendingCallbackData = callbackData
-        pendingCallbackFn = callbackFn
+        pickerCallback = callback
 
         // Map filter to Android photo picker type
         val mediaType = when (filter) {
@@ -80,7 +109,84 @@ object MediaPickerManager {
         }
 
         // Launch the picker
-        launcher.launch(PickVisualMediaRequest(mediaType))
+        try {
+            launcher.launch(PickVisualMediaRequest(mediaType))
+        } catch (e: Exception) {
+            Log.e(TAG, "Failed to launch media picker", e)
+            pickerCallback = null
+            complete(callback, NO_SELECTION)
+        }
+    }
+
+    /**
+     * Present the camera to capture a photo or video.
+     * Called from native code via JNI.
+     *
+     * The Rust side requests camera permission through `Permissions` before
+     * calling this, since apps declaring CAMERA cannot launch the camera without it.
+     *
+     * @param mode The capture mode (0=Photo, 1=Video)
+     * @param callbackData Opaque pointer to callback data
+     * @param callbackFn Function pointer to call when capture completes
+     */
+    @JvmStatic
+    fun presentCamera(mode: Int, callbackData: Long, callbackFn: Long) {
+        val callback = PendingCallback(callbackData, callbackFn)
+        val activity = currentActivity
+        val launcher = if (mode == CAPTURE_MODE_VIDEO) videoCaptureLauncher else photoCaptureLauncher
+        if (activity == null || launcher == null) {
+            Log.e(TAG, "MediaPicker not initialized - call initialize() in Activity.onCreate()")
+            complete(callback, NO_SELECTION)
+            return
+        }
+        if (captureCallback != null) {
+            Log.w(TAG, "Camera already presented")
+            complete(callback, NO_SELECTION)
+            return
+        }
+
+        val isVideo = mode == CAPTURE_MODE_VIDEO
+        val dir = File(activity.cacheDir, CAPTURE_DIR).apply { mkdirs() }
+        val file = File(dir, "capture_${System.currentTimeMillis()}.${if (isVideo) "mp4" else "jpg"}")
+        val uri = ClipboardBridge.fileProviderUri(activity, file)
+
+        // Store callback and destination for later
+        captureCallback = callback
+        pendingCaptureFile = file
+        pendingCaptureUri = uri
+        pendingCaptureMimeType = if (isVideo) "video/mp4" else "image/jpeg"
+
+        try {
+            launcher.launch(uri)
+        } catch (e: Exception) {
+            Log.e(TAG, "Failed to launch camera", e)
+            completeCapture(false)
+        }
+    }
+
+    private fun completeCapture(success: Boolean) {
+        val callback = captureCallback
+        val file = pendingCaptureFile
+        val uri = pendingCaptureUri
+        val mimeType = pendingCaptureMimeType
+        captureCallback = null
+        pendingCaptureFile = null
+        pendingCaptureUri = null
+        pendingCaptureMimeType = null
+
+        val id = if (success && uri != null) {
+            MediaLoader.register(uri, mimeType)
+        } else {
+            Log.d(TAG, "No media captured")
+            // The camera may have created the file before the user cancelled
+            file?.delete()
+            NO_SELECTION
+        }
+        callback?.let { complete(it, id) }
+    }
+
+    private fun complete(callback: PendingCallback, id: Int) {
+        nativeCompletePresentCallback(callback.data, callback.fn, id)
     }
 
     /**
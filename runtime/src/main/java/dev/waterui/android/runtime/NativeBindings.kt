@@ -25,12 +25,46 @@ internal object NativeBindings {
     fun waterui_init(): Long = WatcherJni.init()
     fun waterui_app(envPtr: Long): AppStruct = WatcherJni.app(envPtr)
     fun waterui_env_install_media_picker_manager(envPtr: Long) = WatcherJni.envInstallMediaPickerManager(envPtr)
+    fun waterui_env_install_permissions(envPtr: Long) = WatcherJni.envInstallPermissions(envPtr)
+    fun waterui_env_install_main_executor(envPtr: Long) = WatcherJni.envInstallMainExecutor(envPtr)
+    fun waterui_env_install_clipboard(envPtr: Long) = WatcherJni.envInstallClipboard(envPtr)
+    fun waterui_env_install_assets(envPtr: Long, baseUrl: String) = WatcherJni.envInstallAssets(envPtr, baseUrl)
+    fun waterui_env_install_settings_storage(envPtr: Long) = WatcherJni.envInstallSettingsStorage(envPtr)
+    fun waterui_env_install_feedback(envPtr: Long) = WatcherJni.envInstallFeedback(envPtr)
+    fun waterui_env_install_notifications(envPtr: Long) = WatcherJni.envInstallNotifications(envPtr)
+    fun waterui_env_deliver_notification_response(envPtr: Long, notificationId: String, actionId: String?) =
+        WatcherJni.envDeliverNotificationResponse(envPtr, notificationId, actionId)
+    fun waterui_env_install_back_navigation(envPtr: Long) = WatcherJni.envInstallBackNavigation(envPtr)
+    fun waterui_env_deliver_back_event(envPtr: Long, phase: Int, progress: Float) =
+        WatcherJni.envDeliverBackEvent(envPtr, phase, progress)
+    fun waterui_env_install_locale_string(envPtr: Long, languageTag: String) = WatcherJni.envInstallLocale(envPtr, languageTag)
+    fun waterui_env_set_safe_area_insets(envPtr: Long, insets: SafeAreaInsetsStruct, keyboard: Float) =
+        WatcherJni.envSetSafeAreaInsets(envPtr, insets.top, insets.bottom, insets.leading, insets.trailing, keyboard)
+    fun waterui_env_set_size_classes(envPtr: Long, horizontal: Int, vertical: Int) =
+        WatcherJni.envSetSizeClasses(envPtr, horizontal, vertical)
+    fun waterui_env_set_dynamic_type_scale(envPtr: Long, scale: Float) = WatcherJni.envSetDynamicTypeScale(envPtr, scale)
+    fun waterui_env_set_app_phase(envPtr: Long, phase: Int) = WatcherJni.envSetAppPhase(envPtr, phase)
+    fun waterui_env_refresh_permissions(envPtr: Long) = WatcherJni.envRefreshPermissions(envPtr)
+    fun waterui_env_open_url(envPtr: Long, url: String) = WatcherJni.envOpenUrl(envPtr, url)
     fun waterui_env_install_webview_controller(envPtr: Long) = WatcherJni.envInstallWebViewController(envPtr)
     fun waterui_view_id(anyViewPtr: Long): TypeIdStruct = WatcherJni.viewId(anyViewPtr)
     fun waterui_view_body(anyViewPtr: Long, envPtr: Long): Long = WatcherJni.viewBody(anyViewPtr, envPtr)
     fun waterui_view_stretch_axis(anyViewPtr: Long): Int = WatcherJni.viewStretchAxis(anyViewPtr)
-    fun waterui_configure_hot_reload_endpoint(host: String, port: Int) = WatcherJni.configureHotReloadEndpoint(host, port)
-    fun waterui_configure_hot_reload_directory(path: String) = WatcherJni.configureHotReloadDirectory(path)
+    fun waterui_view_type_name(anyViewPtr: Long): String = WatcherJni.viewTypeName(anyViewPtr)
+    fun waterui_configure_hot_reload_endpoint(host: String, port: Int, directory: String) =
+        WatcherJni.configureHotReloadEndpoint(host, port, directory)
+    fun waterui_debug_dump(): String? = WatcherJni.debugDump()
+    fun setLogLevel(level: Int) = WatcherJni.setLogLevel(level)
+
+    // ========== Hot Reload ==========
+
+    /**
+     * Swaps in the symbols of the rebuilt library at [path].
+     * Returns false, keeping the running library, if it cannot be loaded or its ABI differs.
+     */
+    fun reloadLibrary(path: String): Boolean = WatcherJni.reloadLibrary(path)
+    fun waterui_reload_save_state(): ByteArray = WatcherJni.reloadSaveState()
+    fun waterui_reload_restore_state(state: ByteArray) = WatcherJni.reloadRestoreState(state)
 
     // ========== Type Identifiers ==========
 
@@ -211,6 +245,8 @@ internal object NativeBindings {
 
     fun waterui_resolve_font(fontPtr: Long, envPtr: Long): Long = WatcherJni.resolveFont(fontPtr, envPtr)
     fun waterui_drop_font(fontPtr: Long) = WatcherJni.dropFont(fontPtr)
+    fun waterui_font_registry_lookup(envPtr: Long, family: String, weight: Int, width: Int, italic: Boolean): ByteArray? =
+        WatcherJni.fontRegistryLookup(envPtr, family, weight, width, italic)
 
     // ========== Action ==========
 
@@ -252,7 +288,7 @@ internal object NativeBindings {
     // ========== Force-as functions ==========
 
     fun waterui_force_as_plain(viewPtr: Long): PlainStruct = WatcherJni.forceAsPlain(viewPtr)
-    fun waterui_force_as_text(viewPtr: Long): TextStruct = TextStruct(WatcherJni.forceAsText(viewPtr))
+    fun waterui_force_as_text(viewPtr: Long): TextStruct = WatcherJni.forceAsText(viewPtr)
     fun waterui_force_as_button(viewPtr: Long): ButtonStruct = WatcherJni.forceAsButton(viewPtr)
     fun waterui_force_as_color(viewPtr: Long): ColorStruct = ColorStruct(WatcherJni.forceAsColor(viewPtr))
     fun waterui_force_as_text_field(viewPtr: Long): TextFieldStruct = WatcherJni.forceAsTextField(viewPtr)
@@ -263,6 +299,17 @@ internal object NativeBindings {
     fun waterui_force_as_color_picker(viewPtr: Long): ColorPickerStruct = WatcherJni.forceAsColorPicker(viewPtr)
     fun waterui_force_as_progress(viewPtr: Long): ProgressStruct = WatcherJni.forceAsProgress(viewPtr)
     fun waterui_force_as_scroll(viewPtr: Long): ScrollStruct = WatcherJni.forceAsScrollView(viewPtr)
+    fun waterui_scroll_proxy_attach(proxyPtr: Long, sink: ScrollCommandSink) = WatcherJni.scrollProxyAttach(proxyPtr, sink)
+    fun waterui_scroll_proxy_report(
+        proxyPtr: Long,
+        offsetX: Float,
+        offsetY: Float,
+        contentWidth: Float,
+        contentHeight: Float,
+        viewportWidth: Float,
+        viewportHeight: Float
+    ) = WatcherJni.scrollProxyReport(proxyPtr, offsetX, offsetY, contentWidth, contentHeight, viewportWidth, viewportHeight)
+    fun waterui_drop_scroll_proxy(proxyPtr: Long) = WatcherJni.dropScrollProxy(proxyPtr)
     fun waterui_force_as_picker(viewPtr: Long): PickerStruct = WatcherJni.forceAsPicker(viewPtr)
     fun waterui_force_as_secure_field(viewPtr: Long): SecureFieldStruct = WatcherJni.forceAsSecureField(viewPtr)
     fun waterui_force_as_metadata_env(viewPtr: Long): MetadataEnvStruct = WatcherJni.forceAsMetadataEnv(viewPtr)
@@ -274,8 +321,15 @@ internal object NativeBindings {
     fun waterui_force_as_metadata_gesture(viewPtr: Long): MetadataGestureStruct = WatcherJni.forceAsMetadataGesture(viewPtr)
     fun waterui_force_as_metadata_lifecycle_hook(viewPtr: Long): MetadataLifeCycleHookStruct = WatcherJni.forceAsMetadataLifeCycleHook(viewPtr)
     fun waterui_force_as_metadata_on_event(viewPtr: Long): MetadataOnEventStruct = WatcherJni.forceAsMetadataOnEvent(viewPtr)
+    fun waterui_force_as_metadata_on_paste(viewPtr: Long): MetadataOnPasteStruct = WatcherJni.forceAsMetadataOnPaste(viewPtr)
+    fun waterui_force_as_metadata_on_pointer(viewPtr: Long): MetadataOnPointerStruct = WatcherJni.forceAsMetadataOnPointer(viewPtr)
+    fun waterui_force_as_metadata_on_size_change(viewPtr: Long): MetadataOnSizeChangeStruct = WatcherJni.forceAsMetadataOnSizeChange(viewPtr)
+    fun waterui_force_as_metadata_refreshable(viewPtr: Long): MetadataRefreshableStruct = WatcherJni.forceAsMetadataRefreshable(viewPtr)
     fun waterui_force_as_metadata_cursor(viewPtr: Long): MetadataCursorStruct = WatcherJni.forceAsMetadataCursor(viewPtr)
     fun waterui_force_as_metadata_shadow(viewPtr: Long): MetadataShadowStruct = WatcherJni.forceAsMetadataShadow(viewPtr)
+    fun waterui_force_as_metadata_overlay(viewPtr: Long): MetadataLayerStruct = WatcherJni.forceAsMetadataOverlay(viewPtr)
+    fun waterui_force_as_metadata_background_view(viewPtr: Long): MetadataLayerStruct = WatcherJni.forceAsMetadataBackgroundView(viewPtr)
+    fun waterui_force_as_metadata_view_id(viewPtr: Long): MetadataViewIdStruct = WatcherJni.forceAsMetadataViewId(viewPtr)
     fun waterui_force_as_metadata_border(viewPtr: Long): MetadataBorderStruct = WatcherJni.forceAsMetadataBorder(viewPtr)
     fun waterui_metadata_border_id(): TypeIdStruct = WatcherJni.metadataBorderId()
     fun waterui_force_as_metadata_focused(viewPtr: Long): MetadataFocusedStruct = WatcherJni.forceAsMetadataFocused(viewPtr)
@@ -299,8 +353,12 @@ internal object NativeBindings {
     fun waterui_call_shared_action(actionPtr: Long, envPtr: Long) = WatcherJni.callSharedAction(actionPtr, envPtr)
     fun waterui_drop_shared_action(actionPtr: Long) = WatcherJni.dropSharedAction(actionPtr)
     fun waterui_force_as_photo(viewPtr: Long): PhotoStruct = WatcherJni.forceAsPhoto(viewPtr)
+    fun waterui_call_photo_event(handlerPtr: Long, eventType: Int, message: String?) = WatcherJni.callPhotoEvent(handlerPtr, eventType, message)
+    fun waterui_drop_photo_event(handlerPtr: Long) = WatcherJni.dropPhotoEvent(handlerPtr)
     fun waterui_force_as_video(viewPtr: Long): VideoStruct2 = WatcherJni.forceAsVideo(viewPtr)
     fun waterui_force_as_video_player(viewPtr: Long): VideoPlayerStruct = WatcherJni.forceAsVideoPlayer(viewPtr)
+    fun waterui_call_video_event(handlerPtr: Long, eventType: Int, message: String?) = WatcherJni.callVideoEvent(handlerPtr, eventType, message)
+    fun waterui_drop_video_event(handlerPtr: Long) = WatcherJni.dropVideoEvent(handlerPtr)
     fun waterui_force_as_webview(viewPtr: Long): WebViewStruct = WebViewStruct(WatcherJni.forceAsWebView(viewPtr))
     fun waterui_webview_native_handle(webviewPtr: Long): Long = WatcherJni.webviewNativeHandle(webviewPtr)
     fun waterui_webview_native_view(handlePtr: Long): android.webkit.WebView? = WatcherJni.webviewNativeView(handlePtr)
@@ -322,11 +380,31 @@ internal object NativeBindings {
 
     fun waterui_list_id(): TypeIdStruct = WatcherJni.listId()
     fun waterui_list_item_id(): TypeIdStruct = WatcherJni.listItemId()
+    fun waterui_list_section_id(): TypeIdStruct = WatcherJni.listSectionId()
 
     // ========== List Force-As Functions ==========
 
     fun waterui_force_as_list(viewPtr: Long): ListStruct = WatcherJni.forceAsList(viewPtr)
     fun waterui_force_as_list_item(viewPtr: Long): ListItemStruct = WatcherJni.forceAsListItem(viewPtr)
+    fun waterui_force_as_list_section(viewPtr: Long): ListSectionStruct = WatcherJni.forceAsListSection(viewPtr)
+
+    // ========== Lazy Stack Functions ==========
+
+    fun waterui_lazy_stack_id(): TypeIdStruct = WatcherJni.lazyStackId()
+    fun waterui_force_as_lazy_stack(viewPtr: Long): LazyStackStruct = WatcherJni.forceAsLazyStack(viewPtr)
+
+    // ========== Geometry Reader Functions ==========
+
+    fun waterui_geometry_reader_id(): TypeIdStruct = WatcherJni.geometryReaderId()
+    fun waterui_force_as_geometry_reader(viewPtr: Long): GeometryReaderStruct = WatcherJni.forceAsGeometryReader(viewPtr)
+    fun waterui_geometry_proxy_update(proxyPtr: Long, x: Float, y: Float, width: Float, height: Float) =
+        WatcherJni.geometryProxyUpdate(proxyPtr, x, y, width, height)
+    fun waterui_drop_geometry_proxy(proxyPtr: Long) = WatcherJni.dropGeometryProxy(proxyPtr)
+
+    // ========== Lazy Grid Functions ==========
+
+    fun waterui_lazy_grid_id(): TypeIdStruct = WatcherJni.lazyGridId()
+    fun waterui_force_as_lazy_grid(viewPtr: Long): LazyGridStruct = WatcherJni.forceAsLazyGrid(viewPtr)
 
     // ========== Navigation Force-As Functions ==========
 
@@ -352,9 +430,16 @@ internal object NativeBindings {
     fun waterui_metadata_gesture_id(): TypeIdStruct = WatcherJni.metadataGestureId()
     fun waterui_metadata_lifecycle_hook_id(): TypeIdStruct = WatcherJni.metadataLifeCycleHookId()
     fun waterui_metadata_on_event_id(): TypeIdStruct = WatcherJni.metadataOnEventId()
+    fun waterui_metadata_on_paste_id(): TypeIdStruct = WatcherJni.metadataOnPasteId()
+    fun waterui_metadata_on_pointer_id(): TypeIdStruct = WatcherJni.metadataOnPointerId()
+    fun waterui_metadata_on_size_change_id(): TypeIdStruct = WatcherJni.metadataOnSizeChangeId()
+    fun waterui_metadata_refreshable_id(): TypeIdStruct = WatcherJni.metadataRefreshableId()
     fun waterui_metadata_cursor_id(): TypeIdStruct = WatcherJni.metadataCursorId()
     fun waterui_metadata_foreground_id(): TypeIdStruct = WatcherJni.metadataForegroundId()
     fun waterui_metadata_shadow_id(): TypeIdStruct = WatcherJni.metadataShadowId()
+    fun waterui_metadata_overlay_id(): TypeIdStruct = WatcherJni.metadataOverlayId()
+    fun waterui_metadata_background_view_id(): TypeIdStruct = WatcherJni.metadataBackgroundViewId()
+    fun waterui_metadata_view_id_id(): TypeIdStruct = WatcherJni.metadataViewIdId()
     fun waterui_metadata_focused_id(): TypeIdStruct = WatcherJni.metadataFocusedId()
     fun waterui_metadata_ignore_safe_area_id(): TypeIdStruct = WatcherJni.metadataIgnoreSafeAreaId()
     fun waterui_metadata_retain_id(): TypeIdStruct = WatcherJni.metadataRetainId()
@@ -390,6 +475,28 @@ internal object NativeBindings {
     fun waterui_call_on_event(handlerPtr: Long, envPtr: Long) = WatcherJni.callOnEvent(handlerPtr, envPtr)
     fun waterui_drop_on_event(handlerPtr: Long) = WatcherJni.dropOnEvent(handlerPtr)
 
+    // ========== OnPaste Handler ==========
+
+    fun waterui_call_on_paste(handlerPtr: Long, envPtr: Long, text: String) = WatcherJni.callOnPaste(handlerPtr, envPtr, text)
+    fun waterui_drop_on_paste(handlerPtr: Long) = WatcherJni.dropOnPaste(handlerPtr)
+
+    // ========== OnPointer Handler ==========
+
+    fun waterui_call_on_pointer(handlerPtr: Long, phase: Int, kind: Int, pointerId: Int, x: Float, y: Float, pressure: Float) =
+        WatcherJni.callOnPointer(handlerPtr, phase, kind, pointerId, x, y, pressure)
+    fun waterui_drop_on_pointer(handlerPtr: Long) = WatcherJni.dropOnPointer(handlerPtr)
+
+    // ========== OnSizeChange Handler ==========
+
+    fun waterui_call_on_size_change(handlerPtr: Long, width: Float, height: Float) = WatcherJni.callOnSizeChange(handlerPtr, width, height)
+    fun waterui_drop_on_size_change(handlerPtr: Long) = WatcherJni.dropOnSizeChange(handlerPtr)
+
+    // ========== Refreshable Handler ==========
+
+    fun waterui_call_refreshable(handlerPtr: Long, envPtr: Long, onDone: Runnable) =
+        WatcherJni.callRefreshable(handlerPtr, envPtr, onDone)
+    fun waterui_drop_refreshable(handlerPtr: Long) = WatcherJni.dropRefreshable(handlerPtr)
+
     // ========== Cursor Style Computed ==========
 
     fun waterui_read_computed_cursor_style(computedPtr: Long): Int = WatcherJni.readComputedCursorStyle(computedPtr)
@@ -405,11 +512,29 @@ internal object NativeBindings {
 
     fun waterui_gpu_surface_id(): TypeIdStruct = WatcherJni.gpuSurfaceId()
     fun waterui_force_as_gpu_surface(viewPtr: Long): GpuSurfaceStruct = WatcherJni.forceAsGpuSurface(viewPtr)
-    fun waterui_gpu_surface_init(rendererPtr: Long, surface: android.view.Surface, width: Int, height: Int): Long =
-        WatcherJni.gpuSurfaceInit(rendererPtr, surface, width, height)
-    fun waterui_gpu_surface_render(statePtr: Long, width: Int, height: Int): Boolean =
-        WatcherJni.gpuSurfaceRender(statePtr, width, height)
+    fun waterui_gpu_surface_init(gpuSurface: GpuSurfaceStruct, surface: android.view.Surface, width: Int, height: Int): Long =
+        WatcherJni.gpuSurfaceInit(
+            gpuSurface.rendererPtr,
+            gpuSurface.presentMode,
+            gpuSurface.sampleCount,
+            surface,
+            width,
+            height
+        )
+    fun waterui_gpu_surface_render(statePtr: Long, width: Int, height: Int, frameTimeNanos: Long): Boolean =
+        WatcherJni.gpuSurfaceRender(statePtr, width, height, frameTimeNanos)
     fun waterui_gpu_surface_drop(statePtr: Long) = WatcherJni.gpuSurfaceDrop(statePtr)
+    fun waterui_gpu_surface_pointer(
+        statePtr: Long,
+        phase: Int,
+        kind: Int,
+        pointerId: Int,
+        x: Float,
+        y: Float,
+        pressure: Float
+    ) = WatcherJni.gpuSurfacePointer(statePtr, phase, kind, pointerId, x, y, pressure)
+    fun waterui_gpu_surface_key(statePtr: Long, phase: Int, key: String, modifiers: Int, repeat: Boolean) =
+        WatcherJni.gpuSurfaceKey(statePtr, phase, key, modifiers, repeat)
 
     // ========== Reactive State Creation (for theme) ==========
 
@@ -444,10 +569,38 @@ fun bootstrapWaterUiRuntime() {
     NativeBindings.bootstrapNativeBindings()
 }
 
+fun configureHotReloadEndpoint(host: String, port: Int, directory: String) {
+    HotReload.connect(host, port, directory)
+}
+
+@Deprecated(
+    "Pass the directory rebuilds are received into",
+    ReplaceWith("configureHotReloadEndpoint(host, port, context.filesDir.absolutePath)")
+)
 fun configureHotReloadEndpoint(host: String, port: Int) {
-    NativeBindings.waterui_configure_hot_reload_endpoint(host, port)
+    HotReload.connectToFilesDir(host, port)
 }
 
 fun configureHotReloadDirectory(path: String) {
-    NativeBindings.waterui_configure_hot_reload_directory(path)
+    HotReload.watch(path)
+}
+
+/**
+ * Sets the most verbose [LogLevel] of Rust log output forwarded to logcat under the
+ * `WaterUI` tag. Defaults to [LogLevel.INFO].
+ */
+fun setWaterUiLogLevel(level: Int) {
+    NativeBindings.setLogLevel(level)
 }
+
+/**
+ * Returns a JSON snapshot of the Rust-side UI state to attach to bug and crash reports,
+ * or null if the app library is not loaded, for example from an uncaught exception handler.
+ */
+fun dumpWaterUiState(): String? =
+    try {
+        NativeBindings.waterui_debug_dump()
+    } catch (e: LinkageError) {
+        // The runtime failed to load; there is no Rust state to report
+        null
+    }
@@ -0,0 +1,215 @@
+package dev.waterui.android.runtime
+
+import android.app.AlarmManager
+import android.app.PendingIntent
+import android.content.BroadcastReceiver
+import android.content.Context
+import android.content.Intent
+import android.content.pm.PackageManager
+import android.os.Build
+import android.util.Log
+import androidx.core.app.AlarmManagerCompat
+import androidx.core.app.NotificationChannelCompat
+import androidx.core.app.NotificationCompat
+import androidx.core.app.NotificationManagerCompat
+
+/**
+ * Backs the Rust `Notifications` service with [NotificationManagerCompat].
+ * This is called from native code via JNI, possibly off the main thread.
+ *
+ * Notifications due later are handed to [AlarmManager], which wakes
+ * [NotificationReceiver] to post them; like all alarms they do not survive a
+ * reboot. Taps open the app's launch activity with the notification and action
+ * identifiers as extras, which [WaterUiRootView.handleIntent] reports to Rust.
+ *
+ * The status bar icon must be a monochrome drawable, named by a
+ * `dev.waterui.notification.SMALL_ICON` meta-data entry in the app's manifest.
+ */
+object NotificationsBridge {
+    private const val TAG = "NotificationsBridge"
+    private const val CHANNEL_ID = "waterui_default"
+    private const val CHANNEL_NAME = "Notifications"
+    private const val SMALL_ICON_KEY = "dev.waterui.notification.SMALL_ICON"
+
+    private const val EXTRA_ID = "dev.waterui.notification.ID"
+    private const val EXTRA_ACTION = "dev.waterui.notification.ACTION"
+    private const val EXTRA_TITLE = "dev.waterui.notification.TITLE"
+    private const val EXTRA_BODY = "dev.waterui.notification.BODY"
+    private const val EXTRA_ACTION_IDS = "dev.waterui.notification.ACTION_IDS"
+    private const val EXTRA_ACTION_TITLES = "dev.waterui.notification.ACTION_TITLES"
+
+    private var appContext: Context? = null
+
+    /**
+     * Initialize with application context.
+     * Must be called before the service is installed into an environment.
+     */
+    fun init(context: Context) {
+        appContext = context.applicationContext
+    }
+
+    /**
+     * Schedules a notification, replacing any pending or shown notification with the same [id].
+     *
+     * @param triggerTimeMs Delivery time in milliseconds since the Unix epoch; past times deliver immediately
+     * @param actionIds Identifiers of the action buttons, parallel to [actionTitles]
+     */
+    @JvmStatic
+    fun schedule(
+        id: String,
+        title: String,
+        body: String,
+        triggerTimeMs: Long,
+        actionIds: Array<String>,
+        actionTitles: Array<String>
+    ) {
+        val context = appContext
+        if (context == null) {
+            Log.e(TAG, "NotificationsBridge not initialized - call init() first")
+            return
+        }
+        if (smallIcon(context) == 0) return
+        val intent = Intent(context, NotificationReceiver::class.java)
+            .putExtra(EXTRA_ID, id)
+            .putExtra(EXTRA_TITLE, title)
+            .putExtra(EXTRA_BODY, body)
+            .putExtra(EXTRA_ACTION_IDS, actionIds)
+            .putExtra(EXTRA_ACTION_TITLES, actionTitles)
+
+        if (triggerTimeMs <= System.currentTimeMillis()) {
+            post(context, intent)
+            return
+        }
+
+        val alarmManager = context.getSystemService(AlarmManager::class.java)
+        val operation = alarmIntent(context, id, intent)
+        val canScheduleExact = Build.VERSION.SDK_INT < Build.VERSION_CODES.S || alarmManager.canScheduleExactAlarms()
+        if (canScheduleExact) {
+            AlarmManagerCompat.setExactAndAllowWhileIdle(alarmManager, AlarmManager.RTC_WAKEUP, triggerTimeMs, operation)
+        } else {
+            AlarmManagerCompat.setAndAllowWhileIdle(alarmManager, AlarmManager.RTC_WAKEUP, triggerTimeMs, operation)
+        }
+    }
+
+    /**
+     * Cancels the pending or shown notification with [id].
+     */
+    @JvmStatic
+    fun cancel(id: String) {
+        val context = appContext ?: return
+        val intent = Intent(context, NotificationReceiver::class.java)
+        context.getSystemService(AlarmManager::class.java).cancel(alarmIntent(context, id, intent))
+        dismiss(id)
+    }
+
+    /**
+     * Returns the notification and action identifiers carried by a notification tap,
+     * or null if [intent] did not come from a notification. The action is null for
+     * a tap on the notification itself.
+     */
+    fun responseFrom(intent: Intent): Pair<String, String?>? {
+        val id = intent.getStringExtra(EXTRA_ID) ?: return null
+        return id to intent.getStringExtra(EXTRA_ACTION)
+    }
+
+    /**
+     * Strips the notification response from [intent] once it has been delivered.
+     */
+    internal fun consumeResponse(intent: Intent) {
+        intent.removeExtra(EXTRA_ID)
+        intent.removeExtra(EXTRA_ACTION)
+    }
+
+    /**
+     * Removes the shown notification with [id], leaving any pending alarm in place.
+     */
+    internal fun dismiss(id: String) {
+        val context = appContext ?: return
+        NotificationManagerCompat.from(context).cancel(id, 0)
+    }
+
+    internal fun post(context: Context, intent: Intent) {
+        val id = intent.getStringExtra(EXTRA_ID) ?: return
+        val icon = smallIcon(context)
+        if (icon == 0) return
+        val manager = NotificationManagerCompat.from(context)
+        manager.createNotificationChannel(
+            NotificationChannelCompat.Builder(CHANNEL_ID, NotificationManagerCompat.IMPORTANCE_DEFAULT)
+                .setName(CHANNEL_NAME)
+                .build()
+        )
+
+        val builder = NotificationCompat.Builder(context, CHANNEL_ID)
+            .setSmallIcon(icon)
+            .setContentTitle(intent.getStringExtra(EXTRA_TITLE))
+            .setContentText(intent.getStringExtra(EXTRA_BODY))
+            .setAutoCancel(true)
+            .setContentIntent(tapIntent(context, id, null))
+
+        val actionIds = intent.getStringArrayExtra(EXTRA_ACTION_IDS).orEmpty()
+        val actionTitles = intent.getStringArrayExtra(EXTRA_ACTION_TITLES).orEmpty()
+        for ((actionId, actionTitle) in actionIds.zip(actionTitles)) {
+            builder.addAction(0, actionTitle, tapIntent(context, id, actionId))
+        }
+
+        if (!manager.areNotificationsEnabled()) {
+            Log.w(TAG, "Notifications are disabled; dropping $id")
+            return
+        }
+        try {
+            manager.notify(id, 0, builder.build())
+        } catch (e: SecurityException) {
+            Log.e(TAG, "Missing notification permission; dropping $id", e)
+        }
+    }
+
+    /**
+     * Returns the small icon declared in the manifest, or 0 after logging how to declare one.
+     * The launcher icon is not a fallback: it may be missing, and as a mipmap the status bar
+     * renders it as a white blob.
+     */
+    private fun smallIcon(context: Context): Int {
+        val metaData = context.packageManager
+            .getApplicationInfo(context.packageName, PackageManager.GET_META_DATA)
+            .metaData
+        val icon = metaData?.getInt(SMALL_ICON_KEY) ?: 0
+        if (icon == 0) {
+            Log.e(
+                TAG,
+                "No notification icon; dropping notification. Add <meta-data android:name=\"$SMALL_ICON_KEY\" " +
+                    "android:resource=\"@drawable/...\" /> with a monochrome drawable to the <application>"
+            )
+        }
+        return icon
+    }
+
+    private fun alarmIntent(context: Context, id: String, intent: Intent): PendingIntent =
+        PendingIntent.getBroadcast(
+            context,
+            id.hashCode(),
+            intent.setAction(id),
+            PendingIntent.FLAG_UPDATE_CURRENT or PendingIntent.FLAG_IMMUTABLE
+        )
+
+    private fun tapIntent(context: Context, id: String, actionId: String?): PendingIntent? {
+        val launch = context.packageManager.getLaunchIntentForPackage(context.packageName) ?: return null
+        launch.addFlags(Intent.FLAG_ACTIVITY_SINGLE_TOP)
+            .putExtra(EXTRA_ID, id)
+            .putExtra(EXTRA_ACTION, actionId)
+        return PendingIntent.getActivity(
+            context,
+            "$id/${actionId.orEmpty()}".hashCode(),
+            launch,
+            PendingIntent.FLAG_UPDATE_CURRENT or PendingIntent.FLAG_IMMUTABLE
+        )
+    }
+}
+
+/**
+ * Posts notifications scheduled for later when their alarm fires.
+ */
+class NotificationReceiver : BroadcastReceiver() {
+    override fun onReceive(context: Context, intent: Intent) {
+        NotificationsBridge.post(context, intent)
+    }
+}
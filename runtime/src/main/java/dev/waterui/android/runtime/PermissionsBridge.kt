@@ -0,0 +1,168 @@
+package dev.waterui.android.runtime
+
+import android.Manifest
+import android.content.Context
+import android.content.pm.PackageManager
+import android.os.Build
+import android.os.Handler
+import android.os.Looper
+import android.util.Log
+import androidx.activity.ComponentActivity
+import androidx.activity.result.ActivityResultLauncher
+import androidx.activity.result.contract.ActivityResultContracts
+import androidx.core.app.NotificationManagerCompat
+import androidx.core.content.ContextCompat
+
+/**
+ * Backs the Rust `Permissions` service with Android runtime permissions.
+ * This is called from native code via JNI.
+ *
+ * Android does not report whether a permission was permanently denied, so a
+ * permission is treated as Denied once a request for it fails and the system
+ * would no longer show a rationale, which is when it stops prompting.
+ *
+ * Location counts as granted with approximate access only, and notifications
+ * follow the app's notification setting, which the user can turn off without
+ * revoking the runtime permission. Permissions this bridge does not know are
+ * reported as Denied.
+ *
+ * [WaterUiRootView] asks Rust to query the status again whenever the activity
+ * resumes, so changes made in system settings reach the status signals.
+ */
+object PermissionsBridge {
+    private const val TAG = "PermissionsBridge"
+
+    private const val PERMISSION_CAMERA = 0
+    private const val PERMISSION_MICROPHONE = 1
+    private const val PERMISSION_PHOTO_LIBRARY = 2
+    private const val PERMISSION_LOCATION = 3
+    private const val PERMISSION_NOTIFICATIONS = 4
+
+    private const val STATUS_NOT_DETERMINED = 0
+    private const val STATUS_DENIED = 1
+    private const val STATUS_GRANTED = 2
+
+    private val mainHandler = Handler(Looper.getMainLooper())
+    private val deniedPermissions = mutableSetOf<String>()
+
+    private var currentActivity: ComponentActivity? = null
+    private var requestLauncher: ActivityResultLauncher<Array<String>>? = null
+    private var pendingPermission: Int = 0
+    private var pendingCallbackData: Long = 0
+    private var pendingCallbackFn: Long = 0
+
+    /**
+     * Initialize the permission prompt for the given activity.
+     * Must be called during Activity onCreate before the activity is started.
+     */
+    fun initialize(activity: ComponentActivity) {
+        currentActivity = activity
+        requestLauncher = activity.registerForActivityResult(
+            ActivityResultContracts.RequestMultiplePermissions()
+        ) { results ->
+            for ((permission, granted) in results) {
+                if (granted) {
+                    deniedPermissions.remove(permission)
+                } else if (!activity.shouldShowRequestPermissionRationale(permission)) {
+                    deniedPermissions.add(permission)
+                }
+            }
+            val callbackFn = pendingCallbackFn
+            if (callbackFn != 0L) {
+                val callbackData = pendingCallbackData
+                pendingCallbackData = 0
+                pendingCallbackFn = 0
+                nativeCompleteRequest(callbackData, callbackFn, status(pendingPermission))
+            }
+        }
+    }
+
+    /**
+     * Returns the authorization status of [permission].
+     * Called from native code via JNI.
+     *
+     * @param permission The permission (0=Camera, 1=Microphone, 2=PhotoLibrary, 3=Location, 4=Notifications)
+     * @return The status (0=NotDetermined, 1=Denied, 2=Granted)
+     */
+    @JvmStatic
+    fun status(permission: Int): Int {
+        val context: Context = currentActivity ?: return STATUS_NOT_DETERMINED
+        val names = androidPermissions(permission) ?: return STATUS_DENIED
+        fun isGranted(name: String) =
+            ContextCompat.checkSelfPermission(context, name) == PackageManager.PERMISSION_GRANTED
+
+        if (permission == PERMISSION_NOTIFICATIONS) {
+            if (NotificationManagerCompat.from(context).areNotificationsEnabled()) return STATUS_GRANTED
+            // Before Android 13 notifications are a setting, not a prompt
+            if (names.isEmpty()) return STATUS_DENIED
+        }
+        return when {
+            permission == PERMISSION_LOCATION && names.any(::isGranted) -> STATUS_GRANTED
+            names.all(::isGranted) -> STATUS_GRANTED
+            names.any { it in deniedPermissions } -> STATUS_DENIED
+            else -> STATUS_NOT_DETERMINED
+        }
+    }
+
+    /**
+     * Prompt the user for [permission] unless it is already decided.
+     * Called from native code via JNI.
+     *
+     * @param permission The permission (0=Camera, 1=Microphone, 2=PhotoLibrary, 3=Location, 4=Notifications)
+     * @param callbackData Opaque pointer to callback data
+     * @param callbackFn Function pointer to call with the resulting status (consumed exactly once)
+     */
+    @JvmStatic
+    fun request(permission: Int, callbackData: Long, callbackFn: Long) {
+        mainHandler.post {
+            val current = status(permission)
+            val launcher = requestLauncher
+            if (current != STATUS_NOT_DETERMINED || launcher == null || pendingCallbackFn != 0L) {
+                if (launcher == null) {
+                    Log.e(TAG, "PermissionsBridge not initialized - call initialize() in Activity.onCreate()")
+                }
+                nativeCompleteRequest(callbackData, callbackFn, current)
+                return@post
+            }
+
+            pendingPermission = permission
+            pendingCallbackData = callbackData
+            pendingCallbackFn = callbackFn
+            // status() reports unknown permissions as Denied, so they never get here
+            launcher.launch(androidPermissions(permission)!!)
+        }
+    }
+
+    /**
+     * Returns the runtime permissions behind [permission], or null if it is not a known permission.
+     */
+    private fun androidPermissions(permission: Int): Array<String>? = when (permission) {
+        PERMISSION_CAMERA -> arrayOf(Manifest.permission.CAMERA)
+        PERMISSION_MICROPHONE -> arrayOf(Manifest.permission.RECORD_AUDIO)
+        PERMISSION_PHOTO_LIBRARY -> if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.TIRAMISU) {
+            arrayOf(Manifest.permission.READ_MEDIA_IMAGES, Manifest.permission.READ_MEDIA_VIDEO)
+        } else {
+            arrayOf(Manifest.permission.READ_EXTERNAL_STORAGE)
+        }
+        PERMISSION_LOCATION -> arrayOf(
+            Manifest.permission.ACCESS_FINE_LOCATION,
+            Manifest.permission.ACCESS_COARSE_LOCATION
+        )
+        PERMISSION_NOTIFICATIONS -> if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.TIRAMISU) {
+            arrayOf(Manifest.permission.POST_NOTIFICATIONS)
+        } else {
+            emptyArray()
+        }
+        else -> null
+    }
+
+    /**
+     * Native method to complete a request callback.
+     * This calls the Rust callback with the resulting status.
+     */
+    private external fun nativeCompleteRequest(
+        callbackData: Long,
+        callbackFn: Long,
+        status: Int
+    )
+}
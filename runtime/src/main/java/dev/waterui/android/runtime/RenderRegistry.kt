@@ -26,6 +26,16 @@ class RenderRegistry private constructor(
     fun withMetadata(typeId: WuiTypeId, renderer: WuiRenderer): RenderRegistry =
         RenderRegistry(entries + (typeId to renderer), metadataTypes + typeId)
 
+    /**
+     * Returns a registry extended with every renderer in [bundle].
+     * Renderers in the bundle replace existing ones for the same type.
+     */
+    fun withBundle(bundle: RendererBundle): RenderRegistry {
+        val builder = RegistryBuilder()
+        bundle.register(builder)
+        return RenderRegistry(entries + builder.components, metadataTypes + builder.metadataTypes)
+    }
+
     companion object {
         fun default(): RenderRegistry = RenderRegistry(defaultComponents, defaultMetadataTypes)
     }
@@ -39,6 +49,14 @@ fun interface WuiRenderer {
     fun createView(context: Context, node: WuiNode, env: WuiEnvironment, registry: RenderRegistry): View
 }
 
+/**
+ * A set of renderers shipped together, such as those of a component library,
+ * so an app can install them with a single [RenderRegistry.withBundle] call.
+ */
+fun interface RendererBundle {
+    fun register(builder: RegistryBuilder)
+}
+
 /**
  * Simple node descriptor that wraps the native pointer and metadata received via JNI.
  */
@@ -109,6 +127,13 @@ private val registryData: Pair<Map<WuiTypeId, WuiRenderer>, Set<WuiTypeId>> by l
         // List component
         registerWuiList()
 
+        // Lazy stacks and grids
+        registerWuiLazyStack()
+        registerWuiLazyGrid()
+
+        // Geometry
+        registerWuiGeometryReader()
+
         // Metadata components
         registerWuiSecure()
         registerWuiStandardDynamicRange()
@@ -116,9 +141,16 @@ private val registryData: Pair<Map<WuiTypeId, WuiRenderer>, Set<WuiTypeId>> by l
         registerWuiGesture()
         registerWuiLifeCycleHook()
         registerWuiOnEvent()
+        registerWuiOnPaste()
+        registerWuiOnPointer()
+        registerWuiOnSizeChange()
+        registerWuiRefreshable()
         registerWuiCursor()
         // Note: registerWuiForeground() removed - FFI doesn't expose metadata_foreground yet
         registerWuiShadow()
+        registerWuiOverlay()
+        registerWuiBackgroundView()
+        registerWuiViewId()
         registerWuiBorder()
         registerWuiClipShape()
         registerWuiContextMenu()
@@ -0,0 +1,13 @@
+package dev.waterui.android.runtime
+
+/**
+ * Receives scroll commands issued from Rust through a `WuiScrollProxy`.
+ *
+ * Methods are invoked from native code and may arrive on any thread.
+ * Offsets are in points.
+ */
+interface ScrollCommandSink {
+    fun scrollToOffset(x: Float, y: Float, animated: Boolean)
+
+    fun scrollToId(id: Int, anchor: Int, animated: Boolean)
+}
@@ -0,0 +1,68 @@
+package dev.waterui.android.runtime
+
+import android.content.Context
+import android.content.SharedPreferences
+import android.util.Base64
+import android.util.Log
+
+/**
+ * Backs the Rust `Settings` plugin with [SharedPreferences].
+ * This is called from native code via JNI.
+ *
+ * Values arrive as opaque bytes serialized in Rust and are stored Base64
+ * encoded, since SharedPreferences only holds primitive values and strings.
+ * Writes are applied asynchronously; SharedPreferences serves later reads
+ * from memory, so they observe the new value immediately.
+ */
+object SettingsBridge {
+    private const val TAG = "SettingsBridge"
+    private const val PREFS_NAME = "waterui_settings"
+
+    private var prefs: SharedPreferences? = null
+
+    /**
+     * Initialize with application context.
+     * Must be called before the storage backend is installed into an environment.
+     */
+    fun init(context: Context) {
+        prefs = context.applicationContext.getSharedPreferences(PREFS_NAME, Context.MODE_PRIVATE)
+    }
+
+    /**
+     * Returns the value stored under [key], or null if there is none.
+     */
+    @JvmStatic
+    fun read(key: String): ByteArray? {
+        val stored = prefs()?.getString(key, null) ?: return null
+        return try {
+            Base64.decode(stored, Base64.NO_WRAP)
+        } catch (e: IllegalArgumentException) {
+            Log.e(TAG, "Discarding corrupt value for $key", e)
+            null
+        }
+    }
+
+    /**
+     * Stores [value] under [key].
+     */
+    @JvmStatic
+    fun write(key: String, value: ByteArray) {
+        prefs()?.edit()?.putString(key, Base64.encodeToString(value, Base64.NO_WRAP))?.apply()
+    }
+
+    /**
+     * Removes the value stored under [key].
+     */
+    @JvmStatic
+    fun remove(key: String) {
+        prefs()?.edit()?.remove(key)?.apply()
+    }
+
+    private fun prefs(): SharedPreferences? {
+        val current = prefs
+        if (current == null) {
+            Log.e(TAG, "SettingsBridge not initialized - call init() first")
+        }
+        return current
+    }
+}
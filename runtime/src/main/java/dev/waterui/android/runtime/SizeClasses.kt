@@ -0,0 +1,19 @@
+package dev.waterui.android.runtime
+
+/**
+ * Maps window dimensions (in dp) to WaterUI size classes (matches WuiSizeClass in FFI).
+ *
+ * Breakpoints follow the Material window size classes: widths below 600dp and
+ * heights below 480dp are compact; medium and expanded windows are regular.
+ */
+object SizeClasses {
+    const val COMPACT = 0
+    const val REGULAR = 1
+
+    private const val COMPACT_WIDTH_DP = 600f
+    private const val COMPACT_HEIGHT_DP = 480f
+
+    fun horizontal(widthDp: Float): Int = if (widthDp < COMPACT_WIDTH_DP) COMPACT else REGULAR
+
+    fun vertical(heightDp: Float): Int = if (heightDp < COMPACT_HEIGHT_DP) COMPACT else REGULAR
+}
@@ -4,12 +4,16 @@ import android.graphics.Typeface
 import android.os.Build
 import android.text.SpannableStringBuilder
 import android.text.Spanned
+import android.text.TextPaint
 import android.text.style.AbsoluteSizeSpan
 import android.text.style.BackgroundColorSpan
+import android.text.style.ClickableSpan
 import android.text.style.ForegroundColorSpan
+import android.text.style.MetricAffectingSpan
 import android.text.style.StrikethroughSpan
 import android.text.style.StyleSpan
 import android.text.style.UnderlineSpan
+import android.view.View
 import java.io.Closeable
 import kotlin.math.roundToInt
 
@@ -17,7 +21,8 @@ internal fun StyledStrStruct.toModel(): WuiStyledStr {
     val chunkModels = chunks.map { chunk ->
         StyledChunk(
             text = chunk.text,
-            style = chunk.style.toModel()
+            style = chunk.style.toModel(),
+            attachmentPtr = chunk.attachmentPtr
         )
     }
     return WuiStyledStr(chunkModels)
@@ -27,19 +32,33 @@ class WuiStyledStr internal constructor(
     private val chunks: List<StyledChunk>
 ) : Closeable {
 
-    fun toCharSequence(env: WuiEnvironment): CharSequence {
+    /**
+     * Builds a spannable for display. Inline attachments and tap actions are only
+     * rendered when an [attachments] host is given, which then owns them; otherwise
+     * their placeholder text is kept and they are released with this string.
+     */
+    fun toCharSequence(env: WuiEnvironment, attachments: InlineAttachmentHost? = null): CharSequence {
         val builder = SpannableStringBuilder()
         chunks.forEach { chunk ->
             val start = builder.length
             builder.append(chunk.text)
             val end = builder.length
             if (start != end) {
-                chunk.style.applySpans(env, builder, start, end)
+                chunk.style.applySpans(env, builder, start, end, attachments)
+                if (attachments != null) {
+                    val attachmentPtr = chunk.takeAttachment()
+                    if (attachmentPtr != 0L) {
+                        builder.setSpan(attachments.span(attachmentPtr), start, end, Spanned.SPAN_EXCLUSIVE_EXCLUSIVE)
+                    }
+                }
             }
         }
         return builder
     }
 
+    /** Whether any range carries a tap handler. */
+    fun hasTapHandlers(): Boolean = chunks.any { it.style.hasTap }
+
     override fun close() {
         chunks.forEach { it.close() }
     }
@@ -47,10 +66,22 @@ class WuiStyledStr internal constructor(
 
 internal class StyledChunk(
     val text: String,
-    val style: StyledTextStyle
+    val style: StyledTextStyle,
+    private var attachmentPtr: Long
 ) : Closeable {
+    /** Hands the attachment over to the caller, which becomes responsible for it. */
+    fun takeAttachment(): Long {
+        val ptr = attachmentPtr
+        attachmentPtr = 0L
+        return ptr
+    }
+
     override fun close() {
         style.close()
+        if (attachmentPtr != 0L) {
+            NativeBindings.waterui_drop_anyview(attachmentPtr)
+            attachmentPtr = 0L
+        }
     }
 }
 
@@ -60,14 +91,28 @@ internal class StyledTextStyle(
     private val underline: Boolean,
     private val strikethrough: Boolean,
     private val foreground: WuiColor?,
-    private val background: WuiColor?
+    private val background: WuiColor?,
+    private var tapPtr: Long
 ) : Closeable {
 
-    fun applySpans(env: WuiEnvironment, builder: SpannableStringBuilder, start: Int, end: Int) {
+    val hasTap: Boolean = tapPtr != 0L
+
+    fun applySpans(
+        env: WuiEnvironment,
+        builder: SpannableStringBuilder,
+        start: Int,
+        end: Int,
+        host: InlineAttachmentHost?
+    ) {
         val resolvedFont = font.resolveOnce(env)
-        val typefaceStyle = resolveTypefaceStyle(resolvedFont.weight, italic)
-        if (typefaceStyle != Typeface.NORMAL) {
-            builder.setSpan(StyleSpan(typefaceStyle), start, end, Spanned.SPAN_EXCLUSIVE_EXCLUSIVE)
+        if (resolvedFont.family.isNotEmpty() || resolvedFont.width != FONT_WIDTH_NORMAL) {
+            val typeface = resolvedFont.copy(italic = resolvedFont.italic || italic).toTypeface(env)
+            builder.setSpan(CustomTypefaceSpan(typeface), start, end, Spanned.SPAN_EXCLUSIVE_EXCLUSIVE)
+        } else {
+            val typefaceStyle = resolveTypefaceStyle(resolvedFont.weight, resolvedFont.italic || italic)
+            if (typefaceStyle != Typeface.NORMAL) {
+                builder.setSpan(StyleSpan(typefaceStyle), start, end, Spanned.SPAN_EXCLUSIVE_EXCLUSIVE)
+            }
         }
         builder.setSpan(AbsoluteSizeSpan(resolvedFont.size.roundToInt(), true), start, end, Spanned.SPAN_EXCLUSIVE_EXCLUSIVE)
 
@@ -87,6 +132,11 @@ internal class StyledTextStyle(
         if (strikethrough) {
             builder.setSpan(StrikethroughSpan(), start, end, Spanned.SPAN_EXCLUSIVE_EXCLUSIVE)
         }
+        if (tapPtr != 0L && host != null) {
+            // The span outlives this style: the text stays on screen until it is replaced
+            builder.setSpan(host.tapSpan(tapPtr), start, end, Spanned.SPAN_EXCLUSIVE_EXCLUSIVE)
+            tapPtr = 0L
+        }
     }
 
     private fun resolveTypefaceStyle(weight: Int, italic: Boolean): Int {
@@ -105,6 +155,36 @@ internal class StyledTextStyle(
             background?.close()
         }
         font.close()
+        if (tapPtr != 0L) {
+            NativeBindings.waterui_drop_shared_action(tapPtr)
+        }
+    }
+}
+
+/**
+ * Fires a range's tap action. Keeps the surrounding text appearance unchanged;
+ * underline and color come from the style attributes instead.
+ *
+ * Owns the action; its [InlineAttachmentHost] closes it once the text is replaced.
+ * Taps after that are ignored.
+ */
+internal class TapSpan(
+    private var actionPtr: Long,
+    private val env: WuiEnvironment
+) : ClickableSpan(), Closeable {
+    override fun onClick(widget: View) {
+        if (actionPtr != 0L) {
+            NativeBindings.waterui_call_shared_action(actionPtr, env.raw())
+        }
+    }
+
+    override fun updateDrawState(ds: TextPaint) {}
+
+    override fun close() {
+        if (actionPtr != 0L) {
+            NativeBindings.waterui_drop_shared_action(actionPtr)
+            actionPtr = 0L
+        }
     }
 }
 
@@ -157,11 +237,21 @@ private fun TextStyleStruct.toModel(): StyledTextStyle {
         underline = underline,
         strikethrough = strikethrough,
         foreground = foregroundColor,
-        background = backgroundColor
+        background = backgroundColor,
+        tapPtr = tapPtr
     )
 }
 
-fun ResolvedFontStruct.toTypeface(): Typeface {
+private const val FONT_WIDTH_CONDENSED = 0
+private const val FONT_WIDTH_NORMAL = 1
+
+/**
+ * Resolves a typeface for this font: an app-registered face when its family is
+ * registered in [env], otherwise the system family of that name (or the
+ * default, condensed when requested). Missing weights and italics are
+ * synthesized by the platform.
+ */
+fun ResolvedFontStruct.toTypeface(env: WuiEnvironment): Typeface {
     val weightValue = when (weight) {
         0 -> 100
         1 -> 200
@@ -174,10 +264,34 @@ fun ResolvedFontStruct.toTypeface(): Typeface {
         8 -> 900
         else -> 400
     }
+    val base = FontRegistry.typeface(env, this) ?: when {
+        family.isNotEmpty() -> Typeface.create(family, Typeface.NORMAL)
+        width == FONT_WIDTH_CONDENSED -> Typeface.create("sans-serif-condensed", Typeface.NORMAL)
+        else -> Typeface.DEFAULT
+    }
     return if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.P) {
-        Typeface.create(Typeface.DEFAULT, weightValue, false)
+        Typeface.create(base, weightValue, italic)
     } else {
-        val style = if (weightValue >= 600) Typeface.BOLD else Typeface.NORMAL
-        Typeface.create(Typeface.DEFAULT, style)
+        val style = when {
+            weightValue >= 600 && italic -> Typeface.BOLD_ITALIC
+            weightValue >= 600 -> Typeface.BOLD
+            italic -> Typeface.ITALIC
+            else -> Typeface.NORMAL
+        }
+        Typeface.create(base, style)
+    }
+}
+
+/**
+ * Applies a complete typeface to a range, for fonts that a [StyleSpan] cannot
+ * express (custom families and widths).
+ */
+private class CustomTypefaceSpan(private val typeface: Typeface) : MetricAffectingSpan() {
+    override fun updateDrawState(paint: TextPaint) {
+        paint.typeface = typeface
+    }
+
+    override fun updateMeasureState(paint: TextPaint) {
+        paint.typeface = typeface
     }
 }
@@ -0,0 +1,93 @@
+package dev.waterui.android.runtime
+
+import android.os.Parcelable
+import android.view.View
+import android.view.ViewGroup
+import android.widget.EditText
+import androidx.recyclerview.widget.RecyclerView
+
+/**
+ * Transient native state carried across a `Dynamic` rebuild.
+ *
+ * Only views inside an explicit `.id(...)` take part, since that id is the
+ * identity Rust gives them. A view is matched by its nearest enclosing id, its
+ * path of child indices below that id, and its class. Views outside any id are
+ * not carried over. Otherwise unrelated content with the same shape would
+ * inherit the state.
+ *
+ * Captured state covers what users notice losing: scroll offsets, list
+ * positions, focus and text selection.
+ */
+internal class ViewStateSnapshot private constructor(
+    private val scrollOffsets: Map<String, Pair<Int, Int>>,
+    private val listStates: Map<String, Parcelable>,
+    private val selections: Map<String, Pair<Int, Int>>,
+    private val focusedKey: String?
+) {
+    val isEmpty: Boolean
+        get() = scrollOffsets.isEmpty() && listStates.isEmpty() && selections.isEmpty() && focusedKey == null
+
+    /**
+     * Applies the captured state to matching views under [root].
+     * Call once the new tree is attached, since scrolling needs laid-out content.
+     */
+    fun restore(root: View) {
+        forEachKeyed(root) { key, view ->
+            scrollOffsets[key]?.let { (x, y) -> view.scrollTo(x, y) }
+            if (view is RecyclerView) {
+                listStates[key]?.let { view.layoutManager?.onRestoreInstanceState(it) }
+            }
+            if (view is EditText) {
+                selections[key]?.let { (start, end) ->
+                    val length = view.text?.length ?: 0
+                    view.setSelection(start.coerceAtMost(length), end.coerceAtMost(length))
+                }
+            }
+            if (key == focusedKey) view.requestFocus()
+        }
+    }
+
+    companion object {
+        /**
+         * Captures the state of [root] and its descendants before they are removed.
+         */
+        fun capture(root: View): ViewStateSnapshot {
+            val scrollOffsets = mutableMapOf<String, Pair<Int, Int>>()
+            val listStates = mutableMapOf<String, Parcelable>()
+            val selections = mutableMapOf<String, Pair<Int, Int>>()
+            var focusedKey: String? = null
+
+            forEachKeyed(root) { key, view ->
+                if (view is RecyclerView) {
+                    view.layoutManager?.onSaveInstanceState()?.let { listStates[key] = it }
+                } else if (view.scrollX != 0 || view.scrollY != 0) {
+                    scrollOffsets[key] = view.scrollX to view.scrollY
+                }
+                if (view is EditText) {
+                    selections[key] = view.selectionStart to view.selectionEnd
+                }
+                if (view.isFocused) focusedKey = key
+            }
+            return ViewStateSnapshot(scrollOffsets, listStates, selections, focusedKey)
+        }
+
+        /**
+         * Visits the views of [root] that sit inside an explicit id, with their keys.
+         */
+        private fun forEachKeyed(root: View, action: (String, View) -> Unit) {
+            // `path` is null until the walk enters a view tagged with an id
+            fun visit(view: View, path: String?) {
+                val explicitId = view.getTag(TAG_VIEW_ID)
+                val base = if (explicitId != null) "#$explicitId" else path
+                if (base != null) action("$base:${view.javaClass.name}", view)
+                // RecyclerView children are recycled rows; the list state covers them
+                if (view is ViewGroup && view !is RecyclerView) {
+                    for (i in 0 until view.childCount) {
+                        visit(view.getChildAt(i), base?.let { "$it/$i" })
+                    }
+                }
+            }
+            visit(root, null)
+        }
+    }
+}
@@ -0,0 +1,11 @@
+package dev.waterui.android.runtime
+
+import androidx.core.content.FileProvider
+
+/**
+ * Shares clipboard images and camera captures as content URIs.
+ *
+ * A subclass so the runtime's provider does not collide at manifest merge with
+ * a host app declaring androidx [FileProvider] itself.
+ */
+class WaterUiFileProvider : FileProvider()
@@ -1,6 +1,9 @@
 package dev.waterui.android.runtime
 
+import android.app.Activity
 import android.content.Context
+import android.content.ContextWrapper
+import android.content.Intent
 import android.content.res.Configuration
 import android.graphics.Typeface
 import android.os.Build
@@ -10,10 +13,18 @@ import android.view.View
 import android.view.ViewGroup
 import android.widget.FrameLayout
 import android.widget.TextView
+import androidx.activity.BackEventCompat
+import androidx.activity.OnBackPressedCallback
+import androidx.activity.findViewTreeOnBackPressedDispatcherOwner
+import androidx.core.view.ViewCompat
+import androidx.core.view.WindowInsetsCompat
 import androidx.core.widget.TextViewCompat
+import androidx.lifecycle.Lifecycle
+import androidx.lifecycle.LifecycleEventObserver
+import androidx.lifecycle.LifecycleOwner
+import androidx.lifecycle.findViewTreeLifecycleOwner
 import com.google.android.material.color.DynamicColors
 import com.google.android.material.color.MaterialColors
-import dev.waterui.android.components.WebViewManager
 import dev.waterui.android.reactive.WuiComputed
 import dev.waterui.android.runtime.ColorSlot
 import dev.waterui.android.runtime.ReactiveColorSignal
@@ -75,6 +86,54 @@ class WaterUiRootView @JvmOverloads constructor(
      */
     private var backgroundTheme: WuiComputed<ResolvedColorStruct>? = null
 
+    /**
+     * Last safe-area and keyboard insets (in points) published into the environment.
+     */
+    private var safeAreaInsets = SafeAreaInsetsStruct.ZERO
+    private var keyboardInset = 0f
+
+    /**
+     * Intents handed to [handleIntent] before the app was created.
+     */
+    private val pendingIntents = mutableListOf<Intent>()
+
+    /**
+     * Lifecycle of the hosting activity, observed to publish the app phase and, on resume,
+     * permission changes the user made in system settings.
+     */
+    private var lifecycleOwner: LifecycleOwner? = null
+    private val lifecycleObserver = LifecycleEventObserver { source, event ->
+        app?.let { appStruct ->
+            NativeBindings.waterui_env_set_app_phase(appStruct.envPtr, AppPhase.from(source.lifecycle.currentState))
+            if (event == Lifecycle.Event.ON_RESUME) {
+                NativeBindings.waterui_env_refresh_permissions(appStruct.envPtr)
+            }
+        }
+    }
+
+    /**
+     * Forwards back gestures to Rust while it has something to go back to.
+     */
+    private val backCallback = object : OnBackPressedCallback(false) {
+        override fun handleOnBackStarted(backEvent: BackEventCompat) =
+            deliverBack(BackPhase.STARTED, backEvent.progress)
+
+        override fun handleOnBackProgressed(backEvent: BackEventCompat) =
+            deliverBack(BackPhase.PROGRESSED, backEvent.progress)
+
+        override fun handleOnBackCancelled() = deliverBack(BackPhase.CANCELLED, 0f)
+
+        override fun handleOnBackPressed() = deliverBack(BackPhase.INVOKED, 1f)
+    }
+
+    init {
+        // Insets are forwarded unconsumed so IgnoreSafeArea and scroll views still see them.
+        ViewCompat.setOnApplyWindowInsetsListener(this) { _, windowInsets ->
+            publishInsets(windowInsets)
+            windowInsets
+        }
+    }
+
     fun setRenderRegistry(renderRegistry: RenderRegistry) {
         registry = renderRegistry
         if (app != null) {
@@ -84,6 +143,41 @@ class WaterUiRootView @JvmOverloads constructor(
 
     fun getRenderRegistry(): RenderRegistry = registry
 
+    /**
+     * Delivers the URL of an `ACTION_VIEW` [intent] to the app's `on_open_url` handlers,
+     * and a notification tap to the `Notifications` response callbacks.
+     * Call from `Activity.onNewIntent` so intents arriving while the app is running reach Rust;
+     * the launch intent is delivered automatically.
+     *
+     * Once delivered, [intent] is marked handled by clearing its action and notification
+     * extras, so an activity recreated with the same intent (after rotation, say) does not
+     * replay it.
+     *
+     * @return true if the intent carried a URL or notification response
+     */
+    fun handleIntent(intent: Intent): Boolean {
+        val url = if (intent.action == Intent.ACTION_VIEW) intent.dataString else null
+        val response = NotificationsBridge.responseFrom(intent)
+        if (url == null && response == null) return false
+
+        val appStruct = app
+        if (appStruct == null) {
+            pendingIntents.add(intent)
+            return true
+        }
+        url?.let {
+            intent.action = null
+            NativeBindings.waterui_env_open_url(appStruct.envPtr, it)
+        }
+        response?.let { (notificationId, actionId) ->
+            NotificationsBridge.consumeResponse(intent)
+            // Action buttons do not dismiss the notification on their own
+            NotificationsBridge.dismiss(notificationId)
+            NativeBindings.waterui_env_deliver_notification_response(appStruct.envPtr, notificationId, actionId)
+        }
+        return true
+    }
+
     /** Forces the root tree to be rebuilt. Useful for hot reload flows. */
     fun reload() {
         if (app != null) {
@@ -96,6 +190,11 @@ class WaterUiRootView @JvmOverloads constructor(
         if (app == null) {
             initializeApp()
         }
+        lifecycleOwner = findViewTreeLifecycleOwner()?.also { it.lifecycle.addObserver(lifecycleObserver) }
+        FeedbackBridge.attach(this)
+        HotReload.register(this)
+        findViewTreeOnBackPressedDispatcherOwner()?.onBackPressedDispatcher?.addCallback(backCallback)
+        BackNavigationBridge.register(backCallback)
         renderRoot()
     }
 
@@ -145,9 +244,7 @@ class WaterUiRootView @JvmOverloads constructor(
      * 3. Call waterui_app() to create the app (transfers ownership)
      * 4. Store the returned app and use its environment
      */
-    private fun initializeApp() {
-        WebViewManager.init(context)
-
+    private fun initializeApp(isReload: Boolean = false) {
         android.util.Log.d(TAG, "initializeApp: creating environment")
 
         // Step 1: Create the init environment
@@ -156,6 +253,10 @@ class WaterUiRootView @JvmOverloads constructor(
         val initEnvPtr = NativeBindings.waterui_init()
         android.util.Log.d(TAG, "initializeApp: environment created, ptr=$initEnvPtr")
 
+        // Install the main executor and platform services (media picker, permissions, webview
+        // controller, clipboard, assets, settings, feedback, notifications, back navigation, locale)
+        WuiEnvironment.installServices(context, initEnvPtr)
+
         // Step 2: Install system theme into the environment
         val palette = MaterialThemePalette.from(context)
         val fonts = MaterialThemeFonts.from(context)
@@ -166,9 +267,14 @@ class WaterUiRootView @JvmOverloads constructor(
         themeBridge = ThemeBridgeController(initEnvPtr, palette, fonts, scheme)
         android.util.Log.d(TAG, "initializeApp: theme installed")
 
-        // Also install media picker manager and webview controller
-        NativeBindings.waterui_env_install_media_picker_manager(initEnvPtr)
-        NativeBindings.waterui_env_install_webview_controller(initEnvPtr)
+        // Install the inset signals before waterui_app() so every cloned environment shares them
+        NativeBindings.waterui_env_set_safe_area_insets(initEnvPtr, safeAreaInsets, keyboardInset)
+        val configuration = resources.configuration
+        publishSizeClasses(initEnvPtr, configuration.screenWidthDp.toFloat(), configuration.screenHeightDp.toFloat())
+        NativeBindings.waterui_env_set_dynamic_type_scale(initEnvPtr, configuration.fontScale)
+        // Without a lifecycle owner the view is hosted outside an activity; treat it as active.
+        val lifecycleState = findViewTreeLifecycleOwner()?.lifecycle?.currentState ?: Lifecycle.State.RESUMED
+        NativeBindings.waterui_env_set_app_phase(initEnvPtr, AppPhase.from(lifecycleState))
 
         // Step 3: Call waterui_app() - this TAKES OWNERSHIP of the init env
         // After this call, initEnvPtr is invalid and we must use app.envPtr
@@ -180,15 +286,91 @@ class WaterUiRootView @JvmOverloads constructor(
         app = appStruct
         renderEnv = WuiEnvironment.borrowed(appStruct.envPtr)
 
+        // Deliver the intent the activity was launched with, then any that arrived since.
+        // A hot reload restores app state instead of replaying the launch intent.
+        if (!isReload) {
+            findActivity()?.intent?.let { handleIntent(it) }
+        }
+        val pending = pendingIntents.toList()
+        pendingIntents.clear()
+        pending.forEach { handleIntent(it) }
+
         setBackgroundColor(palette.background)
+        ViewCompat.requestApplyInsets(this)
         android.util.Log.d(TAG, "initializeApp: done")
     }
 
+    override fun onSizeChanged(w: Int, h: Int, oldw: Int, oldh: Int) {
+        super.onSizeChanged(w, h, oldw, oldh)
+        val density = resources.displayMetrics.density
+        app?.let { appStruct ->
+            publishSizeClasses(appStruct.envPtr, w / density, h / density)
+        }
+    }
+
+    private fun publishSizeClasses(envPtr: Long, widthDp: Float, heightDp: Float) {
+        NativeBindings.waterui_env_set_size_classes(
+            envPtr,
+            SizeClasses.horizontal(widthDp),
+            SizeClasses.vertical(heightDp)
+        )
+    }
+
+    /**
+     * Converts window insets to points and publishes them into the app environment.
+     * The keyboard inset only counts the part of the IME not already covered by system bars.
+     */
+    private fun publishInsets(windowInsets: WindowInsetsCompat) {
+        val density = resources.displayMetrics.density
+        val bars = windowInsets.getInsets(
+            WindowInsetsCompat.Type.systemBars() or WindowInsetsCompat.Type.displayCutout()
+        )
+        val ime = windowInsets.getInsets(WindowInsetsCompat.Type.ime())
+        val rtl = layoutDirection == View.LAYOUT_DIRECTION_RTL
+
+        safeAreaInsets = SafeAreaInsetsStruct(
+            top = bars.top / density,
+            bottom = bars.bottom / density,
+            leading = (if (rtl) bars.right else bars.left) / density,
+            trailing = (if (rtl) bars.left else bars.right) / density
+        )
+        keyboardInset = (ime.bottom - bars.bottom).coerceAtLeast(0) / density
+
+        app?.let { appStruct ->
+            NativeBindings.waterui_env_set_safe_area_insets(appStruct.envPtr, safeAreaInsets, keyboardInset)
+        }
+    }
+
     override fun onDetachedFromWindow() {
         super.onDetachedFromWindow()
+
+        lifecycleOwner?.lifecycle?.removeObserver(lifecycleObserver)
+        lifecycleOwner = null
+        FeedbackBridge.detach(this)
+        HotReload.unregister(this)
+        backCallback.remove()
+        BackNavigationBridge.unregister(backCallback)
+
+        teardownApp()
+    }
+
+    /**
+     * Rebuilds the app from the currently loaded library after [HotReload] swapped it in.
+     * The previous app must have been torn down with [teardownApp] before the swap.
+     */
+    internal fun restartApp() {
+        initializeApp(isReload = true)
+        renderRoot()
+    }
+
+    /**
+     * Drops the view tree and the app environment. Everything built from the
+     * running library is released, so it is safe to swap the library afterwards.
+     */
+    internal fun teardownApp() {
         removeAllViews()
 
-        android.util.Log.d(TAG, "onDetachedFromWindow: cleaning up")
+        android.util.Log.d(TAG, "teardownApp: cleaning up")
 
         // 1. Reset the root theme controller first (it holds a reference to renderEnv)
         RootThemeController.reset()
@@ -204,14 +386,34 @@ class WaterUiRootView @JvmOverloads constructor(
         renderEnv?.close()
         renderEnv = null
 
-        // 5. Drop the app which owns the environment
+        // 5. Hand back to the system until the next app asks for it
+        BackNavigationBridge.setEnabled(false)
+
+        // 6. Drop the app which owns the environment
         app?.let { appStruct ->
-            android.util.Log.d(TAG, "onDetachedFromWindow: dropping app env")
+            android.util.Log.d(TAG, "teardownApp: dropping app env")
             NativeBindings.waterui_env_drop(appStruct.envPtr)
         }
         app = null
 
-        android.util.Log.d(TAG, "onDetachedFromWindow: cleanup done")
+        android.util.Log.d(TAG, "teardownApp: cleanup done")
+    }
+
+    private fun deliverBack(phase: Int, progress: Float) {
+        app?.let { appStruct ->
+            NativeBindings.waterui_env_deliver_back_event(appStruct.envPtr, phase, progress)
+        }
+    }
+
+    private fun findActivity(): Activity? {
+        var ctx: Context = context
+        while (ctx is ContextWrapper) {
+            if (ctx is Activity) {
+                return ctx
+            }
+            ctx = ctx.baseContext
+        }
+        return null
     }
 
     private fun renderRoot() {
@@ -275,6 +477,7 @@ class WaterUiRootView @JvmOverloads constructor(
             val systemScheme = getSystemColorScheme()
             val scheme = if (systemScheme == 1) ColorScheme.Dark else ColorScheme.Light
             themeBridge?.update(palette, fonts, scheme)
+            NativeBindings.waterui_env_set_dynamic_type_scale(it.envPtr, newConfig.fontScale)
         }
     }
 
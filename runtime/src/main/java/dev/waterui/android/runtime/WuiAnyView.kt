@@ -1,6 +1,7 @@
 package dev.waterui.android.runtime
 
 import android.content.Context
+import android.content.pm.ApplicationInfo
 import android.view.View
 import android.widget.TextView
 
@@ -10,6 +11,17 @@ import android.widget.TextView
  */
 const val TAG_STRETCH_AXIS = 0x57554901 // "WUI\x01" as int
 
+/**
+ * Tag key for the `ViewId` a view was given, used to resolve scroll targets.
+ */
+const val TAG_VIEW_ID = 0x57554903 // "WUI\x03" as int
+
+/**
+ * Types whose body has been taken during inflation. Inflation runs on the main
+ * thread only.
+ */
+private val typesWithBody = HashSet<WuiTypeId>()
+
 /**
  * Entry point that inflates an opaque `AnyView` from the Rust view tree into a
  * concrete Android [android.view.View].
@@ -48,12 +60,22 @@ fun inflateAnyView(
         return view
     }
 
+    // Taking the body consumes the pointer, so a name for the placeholder has to be
+    // read first. Only debug builds want it, and only for types not yet seen to
+    // have a body, so composite views pay for the string at most once per type.
+    val debuggable = (context.applicationInfo.flags and ApplicationInfo.FLAG_DEBUGGABLE) != 0
+    val typeName = if (debuggable && typeId !in typesWithBody) {
+        NativeBindings.waterui_view_type_name(pointer)
+    } else {
+        null
+    }
     val fallbackPtr = NativeBindings.waterui_view_body(pointer, environment.raw())
     if (fallbackPtr != 0L) {
+        typesWithBody.add(typeId)
         return inflateAnyView(context, fallbackPtr, environment, registry)
     }
 
-    return MissingComponentView(context, typeId)
+    return MissingComponentView(context, typeName ?: typeId.toString())
 }
 
 /**
@@ -66,9 +88,9 @@ fun View.getWuiStretchAxis(): StretchAxis {
 
 private class MissingComponentView(
     context: Context,
-    typeId: WuiTypeId
+    viewType: String
 ) : TextView(context) {
     init {
-        text = "Missing component for typeId=${'$'}typeId"
+        text = "Missing component for $viewType"
     }
 }
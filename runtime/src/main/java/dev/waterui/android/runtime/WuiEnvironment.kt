@@ -1,5 +1,9 @@
 package dev.waterui.android.runtime
 
+import android.content.Context
+import androidx.core.os.ConfigurationCompat
+import dev.waterui.android.components.WebViewManager
+import java.util.Locale
 import kotlinx.coroutines.CoroutineScope
 import kotlinx.coroutines.Dispatchers
 import kotlinx.coroutines.SupervisorJob
@@ -32,13 +36,53 @@ class WuiEnvironment private constructor(
         /**
          * Creates a new owned environment via waterui_init().
          * The returned environment will drop the native pointer on close.
+         *
+         * The environment is bare: no main executor or platform service is installed.
+         * Use the overload taking a [Context] for one equivalent to the root environment.
          */
         fun create(): WuiEnvironment {
+            return WuiEnvironment(NativeBindings.waterui_init(), isOwned = true)
+        }
+
+        /**
+         * Creates a new owned environment with the main executor and the same platform
+         * services as the environment [WaterUiRootView] builds. The theme, insets and
+         * other view-driven signals are not installed.
+         */
+        fun create(context: Context): WuiEnvironment {
             val envPtr = NativeBindings.waterui_init()
-            // Install media loader so Selected::load() works
+            installServices(context, envPtr)
+            return WuiEnvironment(envPtr, isOwned = true)
+        }
+
+        /**
+         * Installs the main executor first, so anything built from the environment can hop
+         * to the looper, followed by the platform services and the current locale.
+         */
+        internal fun installServices(context: Context, envPtr: Long) {
+            WebViewManager.init(context)
+            ClipboardBridge.init(context)
+            FontRegistry.init(context)
+            AssetBridge.init(context)
+            SettingsBridge.init(context)
+            FeedbackBridge.init(context)
+            NotificationsBridge.init(context)
+
+            NativeBindings.waterui_env_install_main_executor(envPtr)
             NativeBindings.waterui_env_install_media_picker_manager(envPtr)
+            NativeBindings.waterui_env_install_permissions(envPtr)
             NativeBindings.waterui_env_install_webview_controller(envPtr)
-            return WuiEnvironment(envPtr, isOwned = true)
+            NativeBindings.waterui_env_install_clipboard(envPtr)
+            NativeBindings.waterui_env_install_assets(envPtr, AssetBridge.BASE_URL)
+            NativeBindings.waterui_env_install_settings_storage(envPtr)
+            NativeBindings.waterui_env_install_feedback(envPtr)
+            NativeBindings.waterui_env_install_notifications(envPtr)
+            NativeBindings.waterui_env_install_back_navigation(envPtr)
+
+            // Formatters resolve numbers, dates and currencies against the environment locale.
+            // A locale change recreates the activity, so installing it once is enough.
+            val locale = ConfigurationCompat.getLocales(context.resources.configuration)[0] ?: Locale.getDefault()
+            NativeBindings.waterui_env_install_locale_string(envPtr, locale.toLanguageTag())
         }
 
         /**
@@ -0,0 +1,81 @@
+package dev.waterui.android.layout
+
+import dev.waterui.android.runtime.GridItemStruct
+import org.junit.Test
+import org.junit.Assert.*
+
+class GridTracksTest {
+    @Test
+    fun testFixedAndFlexibleTracks() {
+        val items = listOf(
+            GridItemStruct(GridTracks.SIZE_FIXED, 100f, 100f, 0f, 0),
+            GridItemStruct(GridTracks.SIZE_FLEXIBLE, 0f, Float.POSITIVE_INFINITY, 0f, 0),
+            GridItemStruct(GridTracks.SIZE_FLEXIBLE, 0f, 50f, 0f, 0)
+        )
+        val tracks = GridTracks.resolve(items, 300f)
+        assertEquals(3, tracks.size)
+        assertEquals(100f, tracks[0].size, 0.001f)
+        // The third track stops at its maximum and leaves the rest to the second
+        assertEquals(150f, tracks[1].size, 0.001f)
+        assertEquals(50f, tracks[2].size, 0.001f)
+    }
+
+    @Test
+    fun testFlexibleMinimumTakesFromOtherTracks() {
+        val items = listOf(
+            GridItemStruct(GridTracks.SIZE_FLEXIBLE, 200f, Float.POSITIVE_INFINITY, 0f, 0),
+            GridItemStruct(GridTracks.SIZE_FLEXIBLE, 0f, Float.POSITIVE_INFINITY, 0f, 0)
+        )
+        val tracks = GridTracks.resolve(items, 300f)
+        assertEquals(200f, tracks[0].size, 0.001f)
+        assertEquals(100f, tracks[1].size, 0.001f)
+    }
+
+    @Test
+    fun testMixedFixedFlexibleAndAdaptiveTracks() {
+        val items = listOf(
+            GridItemStruct(GridTracks.SIZE_FIXED, 50f, 50f, 10f, 0),
+            GridItemStruct(GridTracks.SIZE_FLEXIBLE, 0f, 60f, 10f, 0),
+            GridItemStruct(GridTracks.SIZE_ADAPTIVE, 40f, Float.POSITIVE_INFINITY, 10f, 0)
+        )
+        val tracks = GridTracks.resolve(items, 400f)
+        assertEquals(7, tracks.size)
+        assertEquals(50f, tracks[0].size, 0.001f)
+        assertEquals(60f, tracks[1].size, 0.001f)
+        // The adaptive item gets everything the clamped flexible track left over
+        for (track in tracks.drop(2)) {
+            assertEquals(44f, track.size, 0.001f)
+        }
+        assertEquals(400f, tracks.sumOf { (it.size + it.spacing).toDouble() }.toFloat(), 0.001f)
+    }
+
+    @Test
+    fun testPixelSpansFillTheLine() {
+        val tracks = listOf(GridTrack(50f, 0f, 0), GridTrack(50f, 0f, 0))
+        val spans = GridTracks.pixelSpans(tracks, 2f, 250)
+        assertArrayEquals(intArrayOf(100, 150), spans)
+    }
+
+    @Test
+    fun testPixelSpansRoundFromRunningEdges() {
+        val tracks = List(3) { GridTrack(33.3f, 0f, 0) }
+        val spans = GridTracks.pixelSpans(tracks, 3f, 300)
+        assertArrayEquals(intArrayOf(100, 100, 100), spans)
+    }
+
+    @Test
+    fun testAdaptiveExpandsToFit() {
+        val items = listOf(GridItemStruct(GridTracks.SIZE_ADAPTIVE, 80f, Float.POSITIVE_INFINITY, 10f, 0))
+        val tracks = GridTracks.resolve(items, 300f)
+        assertEquals(3, tracks.size)
+        assertEquals(90f, tracks[0].size, 0.001f)
+    }
+
+    @Test
+    fun testAdaptiveKeepsOneTrackWhenTooNarrow() {
+        val items = listOf(GridItemStruct(GridTracks.SIZE_ADAPTIVE, 80f, 120f, 10f, 0))
+        val tracks = GridTracks.resolve(items, 40f)
+        assertEquals(1, tracks.size)
+        assertEquals(80f, tracks[0].size, 0.001f)
+    }
+}
@@ -0,0 +1,27 @@
+package dev.waterui.android.layout
+
+import org.junit.Test
+import org.junit.Assert.*
+
+class ScrollTargetsTest {
+    @Test
+    fun testPageOffsetFollowsVelocity() {
+        assertEquals(400, ScrollTargets.pageOffset(250, 400, 1200, 1))
+        assertEquals(0, ScrollTargets.pageOffset(250, 400, 1200, -1))
+        assertEquals(400, ScrollTargets.pageOffset(250, 400, 1200, 0))
+        assertEquals(0, ScrollTargets.pageOffset(150, 400, 1200, 0))
+    }
+
+    @Test
+    fun testPageOffsetClampsToContent() {
+        assertEquals(1000, ScrollTargets.pageOffset(900, 400, 1000, 1))
+    }
+
+    @Test
+    fun testAnchoredOffset() {
+        assertEquals(500, ScrollTargets.anchoredOffset(500, 600, 400, ScrollTargets.ANCHOR_START, 2000))
+        assertEquals(350, ScrollTargets.anchoredOffset(500, 600, 400, ScrollTargets.ANCHOR_CENTER, 2000))
+        assertEquals(200, ScrollTargets.anchoredOffset(500, 600, 400, ScrollTargets.ANCHOR_END, 2000))
+        assertEquals(0, ScrollTargets.anchoredOffset(50, 100, 400, ScrollTargets.ANCHOR_CENTER, 2000))
+    }
+}
@@ -0,0 +1,23 @@
+package dev.waterui.android.runtime
+
+import androidx.lifecycle.Lifecycle
+import org.junit.Test
+import org.junit.Assert.*
+
+class AppPhaseTest {
+    @Test
+    fun testResumedIsActive() {
+        assertEquals(AppPhase.ACTIVE, AppPhase.from(Lifecycle.State.RESUMED))
+    }
+
+    @Test
+    fun testPausedIsInactive() {
+        assertEquals(AppPhase.INACTIVE, AppPhase.from(Lifecycle.State.STARTED))
+    }
+
+    @Test
+    fun testStoppedIsBackground() {
+        assertEquals(AppPhase.BACKGROUND, AppPhase.from(Lifecycle.State.CREATED))
+        assertEquals(AppPhase.BACKGROUND, AppPhase.from(Lifecycle.State.DESTROYED))
+    }
+}
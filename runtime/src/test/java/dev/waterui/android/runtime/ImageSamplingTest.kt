@@ -0,0 +1,23 @@
+package dev.waterui.android.runtime
+
+import org.junit.Test
+import org.junit.Assert.*
+
+class ImageSamplingTest {
+    @Test
+    fun testKeepsImageAtLeastTargetSize() {
+        assertEquals(4, ImageSampling.sampleSize(4000, 3000, 800, 600))
+        assertEquals(2, ImageSampling.sampleSize(4000, 3000, 1000, 800))
+    }
+
+    @Test
+    fun testSmallImagesAreNotSampled() {
+        assertEquals(1, ImageSampling.sampleSize(200, 200, 400, 400))
+    }
+
+    @Test
+    fun testUnconstrainedDimensions() {
+        assertEquals(1, ImageSampling.sampleSize(4000, 3000, 0, 0))
+        assertEquals(8, ImageSampling.sampleSize(4000, 3000, 500, 0))
+    }
+}
@@ -0,0 +1,34 @@
+package dev.waterui.android.runtime
+
+import android.view.KeyEvent
+import org.junit.Test
+import org.junit.Assert.*
+
+class KeyNamesTest {
+    @Test
+    fun testPrintableKeyUsesItsCharacter() {
+        assertEquals("a", KeyNames.name(KeyEvent.KEYCODE_A, 'a'.code))
+        assertEquals("A", KeyNames.name(KeyEvent.KEYCODE_A, 'A'.code))
+        assertEquals(" ", KeyNames.name(KeyEvent.KEYCODE_SPACE, ' '.code))
+    }
+
+    @Test
+    fun testNamedKeysWinOverControlCharacters() {
+        assertEquals("Enter", KeyNames.name(KeyEvent.KEYCODE_ENTER, '\n'.code))
+        assertEquals("Tab", KeyNames.name(KeyEvent.KEYCODE_TAB, '\t'.code))
+        assertEquals("ArrowLeft", KeyNames.name(KeyEvent.KEYCODE_DPAD_LEFT, 0))
+    }
+
+    @Test
+    fun testSystemKeysAreNotForwarded() {
+        assertNull(KeyNames.name(KeyEvent.KEYCODE_BACK, 0))
+        assertNull(KeyNames.name(KeyEvent.KEYCODE_VOLUME_UP, 0))
+    }
+
+    @Test
+    fun testModifierBits() {
+        val metaState = KeyEvent.META_SHIFT_ON or KeyEvent.META_CTRL_LEFT_ON or KeyEvent.META_CTRL_ON
+        assertEquals(KeyModifiers.SHIFT or KeyModifiers.CONTROL, KeyNames.modifiers(metaState))
+        assertEquals(0, KeyNames.modifiers(0))
+    }
+}
@@ -0,0 +1,24 @@
+package dev.waterui.android.runtime
+
+import org.junit.Test
+import org.junit.Assert.*
+
+class SizeClassesTest {
+    @Test
+    fun testPhonePortraitIsCompactWidth() {
+        assertEquals(SizeClasses.COMPACT, SizeClasses.horizontal(411f))
+        assertEquals(SizeClasses.REGULAR, SizeClasses.vertical(891f))
+    }
+
+    @Test
+    fun testPhoneLandscapeIsCompactHeight() {
+        assertEquals(SizeClasses.REGULAR, SizeClasses.horizontal(891f))
+        assertEquals(SizeClasses.COMPACT, SizeClasses.vertical(411f))
+    }
+
+    @Test
+    fun testBreakpointsAreInclusiveOfRegular() {
+        assertEquals(SizeClasses.REGULAR, SizeClasses.horizontal(600f))
+        assertEquals(SizeClasses.REGULAR, SizeClasses.vertical(480f))
+    }
+}